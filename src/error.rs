@@ -0,0 +1,31 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FractError {
+    ZeroDenominator,
+    NumeratorOverflow,
+    NumeratorUnderflow,
+    DenominatorOverflow,
+    DenominatorUnderflow,
+    InvalidFormat,
+    InvalidEntry(usize),
+    IterationLimit,
+}
+
+impl fmt::Display for FractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FractError::ZeroDenominator => write!(f, "denominator must not be zero"),
+            FractError::NumeratorOverflow => write!(f, "numerator overflowed"),
+            FractError::NumeratorUnderflow => write!(f, "numerator underflowed"),
+            FractError::DenominatorOverflow => write!(f, "denominator overflowed"),
+            FractError::DenominatorUnderflow => write!(f, "denominator underflowed"),
+            FractError::InvalidFormat => write!(f, "invalid fraction format"),
+            FractError::InvalidEntry(index) => write!(f, "invalid fraction at index {}", index),
+            FractError::IterationLimit => write!(f, "iteration limit reached"),
+        }
+    }
+}
+
+impl Error for FractError {}
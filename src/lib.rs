@@ -1,16 +1,177 @@
-use std::ops::{Add, Div, Mul, Sub};
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::ops::{Add, Div, Mul, Shl, Shr, Sub};
+use std::str::FromStr;
 
+mod error;
 mod utils;
 
-trait Fract<B, S, O> {
+pub use error::FractError;
+pub use utils::{
+    checked_gcd_u128, checked_gcd_u16, checked_gcd_u32, checked_gcd_u64, checked_gcd_u8,
+    ext_gcd_i64, lcm_all_u128, lcm_all_u16, lcm_all_u32, lcm_all_u64, lcm_all_u8,
+};
+
+pub trait Fract<B, S, O> {
     fn to_float(&self) -> O;
+    #[allow(clippy::new_ret_no_self)]
     fn new(numerator: B, denominator: B) -> S;
     fn invert(&self) -> S;
     fn expand(&self, multiplicator: B) -> S;
     fn reduce(&self) -> S;
+    fn invert_checked(&self) -> Result<S, FractError>;
+    /// Nearest fraction over `denominator`, rounding ties up.
+    fn nearest_over(&self, denominator: B) -> S;
+    fn mul_checked(&self, other: &S) -> Result<S, FractError>;
+    fn round_with(&self, mode: RoundingMode) -> B;
+    fn percentage_of(&self, whole: &S) -> S;
+    fn try_from_u128(value: u128) -> Result<S, FractError>;
+    /// Multiples of the reduced fraction up to `max_denominator`; zero denominators give none.
+    fn equivalent_forms(&self, max_denominator: B) -> Vec<S>;
+    fn cmp_exact(&self, other: &S) -> Ordering;
+    fn abs_diff_float(&self, other: &S) -> O;
+    /// Approximates `value`, clamping to `MAX/1` above the range and to `0/1` below zero.
+    fn saturating_from_float(value: O, max_denominator: B) -> S;
+    fn continued_fraction(&self) -> Vec<B>;
+    /// Rebuilds `[a0; a1, a2, ...]`; an empty slice yields `0/1`.
+    fn from_continued_fraction(coefficients: &[B]) -> S;
+    /// Path from `1/1` to the reduced fraction, `false` going left and `true` going right,
+    /// e.g. `3/5` is left, right, left. Zero and zero denominators give an empty path.
+    fn stern_brocot_path(&self) -> Vec<bool>;
+    fn add_checked(&self, other: &S) -> Result<S, FractError>;
+    fn sub_checked(&self, other: &S) -> Result<S, FractError>;
+    fn div_checked(&self, other: &S) -> Result<S, FractError>;
+    fn checked_add_assign(&mut self, other: S) -> Result<(), FractError>;
+    fn checked_sub_assign(&mut self, other: S) -> Result<(), FractError>;
+    fn checked_mul_assign(&mut self, other: S) -> Result<(), FractError>;
+    fn checked_div_assign(&mut self, other: S) -> Result<(), FractError>;
+    fn to_string_reduced(&self) -> String;
+    fn denominator_factors(&self) -> Vec<(B, u32)>;
+    /// Whether the expansion in `base` is finite. Zero denominators give `false`.
+    fn terminates_in_base(&self, base: B) -> bool;
+    fn proper_part(&self) -> S;
+    fn integer_scale(&self) -> B;
+    fn scaled_numerator(&self) -> B;
+    fn powf(&self, exp: f64) -> f64;
+    /// Raises to `exp` through `powf` and approximates the float result back with a
+    /// denominator of at most `max_denominator`, so the returned fraction is usually inexact.
+    fn powf_approx(&self, exp: f64, max_denominator: B) -> S;
+    /// Largest `1/n` not exceeding the fraction; the numerator must not be zero.
+    fn largest_unit_fraction(&self) -> S;
+    /// Greedy decomposition into distinct unit fractions, led by the whole part as `n/1` when
+    /// the fraction is improper. Denominators grow quickly, so this is `None` once one no
+    /// longer fits the backing integer, or for a zero denominator.
+    fn egyptian_fraction(&self) -> Option<Vec<S>>;
+    /// Value ordering like `cmp_exact`, or `None` when either denominator is zero.
+    fn safe_cmp(&self, other: &S) -> Option<Ordering>;
+    fn with_numerator(&self, numerator: B) -> S;
+    /// Replaces the denominator as given; passing zero produces an invalid fraction.
+    fn with_denominator(&self, denominator: B) -> S;
+    fn from_str_radix(s: &str, radix: u32) -> Result<S, FractError>;
+    /// Value in units of 1/10000, rounding half up and saturating at `u32::MAX`.
+    fn to_basis_points(&self) -> u32;
+    /// `Ok` when the value is exactly representable, otherwise `Err` carrying the rounded
+    /// value. The float is computed from the reduced form.
+    fn to_float_exact(&self) -> Result<O, O>;
+    /// Preferred accessor for `(numerator, denominator)` exactly as stored, without reducing.
+    fn components(&self) -> (B, B);
+    /// Floor of `self / other` and the remaining `self - quotient * other`, or `None` when
+    /// `other` is zero or the common denominator overflows.
+    fn div_rem_fract(&self, other: &S) -> Option<(B, S)>;
+    fn reduce_mut(&mut self);
+    /// ULPs between `to_float` and the float nearest to the exact value; zero denominators
+    /// report `0`.
+    fn float_ulp_error(&self) -> u64;
+    /// Inverse of the numerator modulo the denominator, or `None` when they are not coprime.
+    fn modular_inverse(&self) -> Option<B>;
+    fn checked_add_int(&self, value: B) -> Option<S>;
+    /// The reduced fraction, or `≈` and the closest fraction within `max_denominator` when
+    /// the reduced denominator is larger.
+    fn display_approx(&self, max_denominator: B) -> String;
+    /// Approximates `value` using at most `max_iterations` continued fraction terms, failing
+    /// with `IterationLimit` when the expansion has not finished by then.
+    fn from_float_capped(
+        value: f64,
+        max_iterations: u32,
+        max_denominator: B,
+    ) -> Result<S, FractError>;
+    /// Decimal digit at `position`, where `1` is the tenths place and `0` the units place.
+    /// Zero denominators give `0`.
+    fn decimal_digit_at(&self, position: u32) -> u8;
+    /// Reduced fraction and whether the representation changed.
+    fn reduce_checked(&self) -> (S, bool);
+    /// Checked counterpart to `expand`, `None` when either part overflows.
+    fn scale(&self, factor: B) -> Option<S>;
+    /// Decimal string rounded half up to `sig_figs` significant digits by exact long
+    /// division. Zero denominators give an empty string.
+    fn to_sig_figs(&self, sig_figs: usize) -> String;
+    /// `1/1` for values above one, otherwise the fraction unchanged.
+    fn clamp01(&self) -> S;
+    /// Length of the repeating block of the decimal expansion, `0` when it terminates or the
+    /// denominator is zero. Found as the order of ten from the factorised denominator, and
+    /// `None` only when it exceeds `u64::MAX`.
+    fn period_length(&self) -> Option<u64>;
+    fn validate(&self) -> Result<(), FractError>;
+    /// First `count` decimal digits after the point, rounding the last one half up. A carry
+    /// out of the first digit belongs to the whole part and is dropped, leaving zeros.
+    fn decimal_digits_rounded(&self, count: usize) -> Vec<u8>;
+    /// Canonical form with the smallest possible denominator, zero becoming `0/1`.
+    fn minimal(&self) -> S;
+    /// Multiplies by `2^n` like `<<`, `None` when a part overflows.
+    fn checked_shl(&self, n: u32) -> Option<S>;
+    /// Divides by `2^n` like `>>`, `None` when a part overflows.
+    fn checked_shr(&self, n: u32) -> Option<S>;
+    /// Like `reduce`, but zero keeps its denominator instead of becoming `0/1`.
+    fn reduce_keep_denominator(&self) -> S;
+    /// Mixed number such as `3 1/2`, leaving out a zero whole part or an empty fraction.
+    fn to_mixed_string(&self) -> String;
+    /// Reduced `self / other`, or `None` when `other` is zero or the quotient overflows.
+    fn ratio_to(&self, other: &S) -> Option<S>;
+    /// Inclusive range check by value; the bounds may be given in either order.
+    fn is_between(&self, low: &S, high: &S) -> bool;
+    /// Successive convergents of the continued fraction, ending at the reduced value.
+    fn convergents(&self) -> Vec<S>;
+    /// Exact ordering against `value`, without rounding the fraction to a float first.
+    fn cmp_with_float(&self, value: f64) -> Ordering;
+    /// Whole part and proper remainder, which sum to the fraction. Zero denominators give the
+    /// fraction itself and `0/1`.
+    fn integer_and_fraction(&self) -> (S, S);
+    /// Element of `allowed` closest to the fraction, preferring the first on ties. Distances
+    /// are compared exactly unless they overflow `u128`, in which case floats decide.
+    fn snap_to(&self, allowed: &[S]) -> Option<S>;
+    /// Value written in `radix` with at most `max_digits` fractional digits, truncating the
+    /// rest. Panics when `radix` is outside `2..=36`.
+    fn to_string_radix(&self, radix: u32, max_digits: usize) -> String;
+    /// Continued fraction in `[a0; a1, a2, ..]` notation.
+    fn continued_fraction_string(&self) -> String;
+    fn reciprocal_reduced(&self) -> Option<S>;
+    /// `self.numerator * other.denominator - other.numerator * self.denominator`, whose sign
+    /// gives the ordering. Saturates at the `i128` bounds when the difference does not fit.
+    fn cross_difference(&self, other: &S) -> i128;
+    /// Fraction with the smallest denominator strictly between the two values, found by
+    /// Stern-Brocot descent, or `None` when it does not fit the width. The bounds may be given
+    /// in either order; when they are equal the reduced value itself is returned.
+    fn simplest_between(&self, other: &S) -> Option<S>;
+}
+
+pub trait Widen {
+    type Wider;
+    fn widen(self) -> Self::Wider;
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RoundingMode {
+    HalfUp,
+    HalfEven,
+    HalfDown,
+    Floor,
+    Ceil,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
 pub struct Fract8 {
     pub numerator: u8,
     pub denominator: u8,
@@ -25,8 +186,8 @@ impl Fract<u8, Fract8, f32> for Fract8 {
     #[inline]
     fn new(numerator: u8, denominator: u8) -> Fract8 {
         Fract8 {
-            numerator: numerator,
-            denominator: denominator,
+            numerator,
+            denominator,
         }
     }
 
@@ -48,1168 +209,16877 @@ impl Fract<u8, Fract8, f32> for Fract8 {
 
     #[inline]
     fn reduce(&self) -> Fract8 {
-        let gcd: u8 = utils::gcd_u8(self.numerator, self.denominator);
-        Fract8 {
-            numerator: self.numerator / gcd,
-            denominator: self.denominator / gcd,
+        // already reduced forms skip the gcd loop
+        if self.numerator == 1 || self.denominator == 1 {
+            return *self;
+        }
+        if self.numerator == 0 && self.denominator != 0 {
+            return Fract8 {
+                numerator: 0,
+                denominator: 1,
+            };
+        }
+
+        match utils::checked_gcd_u8(self.numerator, self.denominator) {
+            Some(gcd) => Fract8 {
+                numerator: self.numerator / gcd,
+                denominator: self.denominator / gcd,
+            },
+            None => *self,
         }
     }
-}
 
-impl From<u8> for Fract8 {
     #[inline]
-    fn from(input: u8) -> Self {
-        Fract8 {
-            numerator: input,
-            denominator: 1,
+    fn invert_checked(&self) -> Result<Fract8, FractError> {
+        if self.numerator == 0 {
+            return Err(FractError::ZeroDenominator);
         }
-    }
-}
 
-impl Add for Fract8 {
-    type Output = Fract8;
+        Ok(self.invert())
+    }
 
     #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract8 = self;
-        let mut nrhs: Fract8 = rhs;
-
-        if self.denominator != rhs.denominator {
-            let old_denom: u8 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
-        }
+    fn nearest_over(&self, denominator: u8) -> Fract8 {
+        let whole: u8 = self.numerator / self.denominator;
+        let remainder: u8 = self.numerator % self.denominator;
+        let scaled: u16 = remainder as u16 * denominator as u16;
+        let quotient: u16 = scaled / self.denominator as u16;
+        let rest: u16 = scaled % self.denominator as u16;
+        // ties round up; comparing against the complement avoids doubling `rest`
+        let rounded: u8 = quotient as u8 + (rest >= self.denominator as u16 - rest) as u8;
 
         Fract8 {
-            numerator: nlhs.numerator + nrhs.numerator,
-            denominator: nlhs.denominator,
+            numerator: whole * denominator + rounded,
+            denominator,
         }
     }
-}
-
-impl Sub for Fract8 {
-    type Output = Fract8;
 
     #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract8 = self;
-        let mut nrhs: Fract8 = rhs;
+    fn mul_checked(&self, other: &Fract8) -> Result<Fract8, FractError> {
+        let numerator: u8 = self
+            .numerator
+            .checked_mul(other.numerator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let denominator: u8 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
 
-        if self.denominator != rhs.denominator {
-            let old_denom: u8 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
+        Ok(Fract8 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn round_with(&self, mode: RoundingMode) -> u8 {
+        let whole: u8 = self.numerator / self.denominator;
+        let remainder: u8 = self.numerator % self.denominator;
+        if remainder == 0 {
+            return whole;
         }
 
-        Fract8 {
-            numerator: nlhs.numerator - nrhs.numerator,
-            denominator: nlhs.denominator,
+        // compares the remainder against half the denominator without overflow
+        let half: Ordering = remainder.cmp(&(self.denominator - remainder));
+        let round_up: bool = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => match half {
+                Ordering::Less => false,
+                Ordering::Equal => whole % 2 == 1,
+                Ordering::Greater => true,
+            },
+        };
+
+        if round_up {
+            whole + 1
+        } else {
+            whole
         }
     }
-}
-
-impl Mul for Fract8 {
-    type Output = Fract8;
 
     #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
+    fn percentage_of(&self, whole: &Fract8) -> Fract8 {
+        let ratio: Fract8 = (*self / *whole).reduce();
+        let gcd: u8 = utils::gcd_u8(100, ratio.denominator);
         Fract8 {
-            numerator: self.numerator * rhs.numerator,
-            denominator: self.denominator * rhs.denominator,
+            numerator: ratio.numerator * (100 / gcd),
+            denominator: ratio.denominator / gcd,
         }
     }
-}
-
-impl Div for Fract8 {
-    type Output = Fract8;
 
     #[inline]
-    fn div(self, rhs: Self) -> Self::Output {
-        self * rhs.invert()
+    fn try_from_u128(value: u128) -> Result<Fract8, FractError> {
+        let numerator: u8 = u8::try_from(value).map_err(|_| FractError::NumeratorOverflow)?;
+        Ok(Fract8::from(numerator))
     }
-}
-#[cfg(test)]
-mod tests_fract8 {
-    use assert_approx_eq::assert_approx_eq;
 
-    use crate::{Fract, Fract8};
+    #[inline]
+    fn equivalent_forms(&self, max_denominator: u8) -> Vec<Fract8> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
 
-    #[test]
-    fn should_create() {
-        let expected: Fract8 = Fract8 {
-            numerator: 8,
-            denominator: 10,
-        };
+        let reduced: Fract8 = self.reduce();
+        let mut forms: Vec<Fract8> = Vec::new();
+        let mut factor: u8 = 1;
+        while let (Some(numerator), Some(denominator)) = (
+            reduced.numerator.checked_mul(factor),
+            reduced.denominator.checked_mul(factor),
+        ) {
+            if denominator > max_denominator {
+                break;
+            }
 
-        let actual: Fract8 = Fract8::new(8, 10);
+            forms.push(Fract8 {
+                numerator,
+                denominator,
+            });
+            factor = match factor.checked_add(1) {
+                Some(next) => next,
+                None => break,
+            };
+        }
 
-        assert_eq!(expected, actual)
+        forms
     }
 
-    #[test]
-    fn should_invert() {
-        let expected: Fract8 = Fract8 {
-            numerator: 10,
-            denominator: 8,
-        };
+    #[inline]
+    fn cmp_exact(&self, other: &Fract8) -> Ordering {
+        let (lhs, rhs) = self.cross_multiply(other);
 
-        let actual: Fract8 = Fract8::new(8, 10).invert();
+        lhs.cmp(&rhs)
+    }
 
-        assert_eq!(expected, actual)
+    #[inline]
+    fn abs_diff_float(&self, other: &Fract8) -> f32 {
+        (self.to_float() - other.to_float()).abs()
     }
 
-    #[test]
-    fn should_expand() {
-        let expected: Fract8 = Fract8 {
-            numerator: 80,
-            denominator: 100,
-        };
+    #[inline]
+    fn saturating_from_float(value: f32, max_denominator: u8) -> Fract8 {
+        if value.is_nan() || value <= 0.0 {
+            return Fract8::from(0);
+        }
 
-        let actual: Fract8 = Fract8::new(8, 10).expand(10);
+        if value >= u8::MAX as f32 {
+            return Fract8::from(u8::MAX);
+        }
 
-        assert_eq!(expected, actual)
+        let (numerator, denominator) = utils::approximate_f64(
+            value as f64,
+            u8::MAX as u128,
+            max_denominator.max(1) as u128,
+        );
+        Fract8 {
+            numerator: numerator as u8,
+            denominator: denominator as u8,
+        }
     }
 
-    #[test]
-    fn should_convert() {
-        let expected: f32 = 0.8;
-        let actual: f32 = Fract8::new(8, 10).to_float();
+    #[inline]
+    fn continued_fraction(&self) -> Vec<u8> {
+        let mut coefficients: Vec<u8> = Vec::new();
+        let mut numerator: u8 = self.numerator;
+        let mut denominator: u8 = self.denominator;
+        while denominator != 0 {
+            coefficients.push(numerator / denominator);
+            let remainder: u8 = numerator % denominator;
+            numerator = denominator;
+            denominator = remainder;
+        }
 
-        assert_approx_eq!(expected, actual)
+        coefficients
     }
 
-    #[test]
-    fn should_add() {
-        let expected: Fract8 = Fract8 {
-            numerator: 28,
-            denominator: 20,
+    #[inline]
+    fn from_continued_fraction(coefficients: &[u8]) -> Fract8 {
+        let (last, rest) = match coefficients.split_last() {
+            Some(split) => split,
+            None => return Fract8::from(0),
         };
 
-        let first: Fract8 = Fract8::new(1, 2);
-        let second: Fract8 = Fract8::new(9, 10);
-        let result: Fract8 = first + second;
-
-        assert_eq!(expected, result)
+        rest.iter()
+            .rev()
+            .fold(Fract8::from(*last), |value, coefficient| Fract8 {
+                numerator: coefficient * value.numerator + value.denominator,
+                denominator: value.numerator,
+            })
     }
 
-    #[test]
-    fn should_sub() {
-        let expected: Fract8 = Fract8 {
-            numerator: 22,
-            denominator: 20,
-        };
-
-        let first: Fract8 = Fract8::new(4, 2);
-        let second: Fract8 = Fract8::new(9, 10);
-        let result: Fract8 = first - second;
-
-        assert_eq!(expected, result)
-    }
+    #[inline]
+    fn stern_brocot_path(&self) -> Vec<bool> {
+        let reduced: Fract8 = self.reduce();
+        if reduced.numerator == 0 || reduced.denominator == 0 {
+            return Vec::new();
+        }
 
-    #[test]
-    fn should_mul() {
-        let expected: Fract8 = Fract8 {
-            numerator: 8,
-            denominator: 10,
-        };
+        let coefficients: Vec<u8> = reduced.continued_fraction();
+        let last: usize = coefficients.len() - 1;
+        let mut path: Vec<bool> = Vec::new();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            let steps: u8 = if index == last {
+                coefficient - 1
+            } else {
+                *coefficient
+            };
 
-        let first: Fract8 = Fract8::new(2, 5);
-        let second: Fract8 = Fract8::new(4, 2);
-        let result: Fract8 = first * second;
+            for _ in 0..steps {
+                path.push(index % 2 == 0);
+            }
+        }
 
-        assert_eq!(expected, result)
+        path
     }
 
-    #[test]
-    fn should_div() {
-        let expected: Fract8 = Fract8 {
-            numerator: 10,
-            denominator: 18,
-        };
-
-        let first: Fract8 = Fract8::new(1, 2);
-        let second: Fract8 = Fract8::new(9, 10);
-        let result: Fract8 = first / second;
+    #[inline]
+    fn add_checked(&self, other: &Fract8) -> Result<Fract8, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract8 {
+            numerator: lhs.checked_add(rhs).ok_or(FractError::NumeratorOverflow)?,
+            denominator,
+        })
+    }
 
-        assert_eq!(expected, result)
+    #[inline]
+    fn sub_checked(&self, other: &Fract8) -> Result<Fract8, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract8 {
+            numerator: lhs.checked_sub(rhs).ok_or(FractError::NumeratorUnderflow)?,
+            denominator,
+        })
     }
 
-    #[test]
-    fn should_reduce() {
-        let expected: Fract8 = Fract8 {
-            numerator: 5,
-            denominator: 9,
-        };
+    #[inline]
+    fn div_checked(&self, other: &Fract8) -> Result<Fract8, FractError> {
+        self.mul_checked(&other.invert_checked()?)
+    }
 
-        let value: Fract8 = Fract8 {
-            numerator: 10,
-            denominator: 18,
-        };
+    #[inline]
+    fn checked_add_assign(&mut self, other: Fract8) -> Result<(), FractError> {
+        *self = self.add_checked(&other)?;
+        Ok(())
+    }
 
-        assert_eq!(expected, value.reduce())
+    #[inline]
+    fn checked_sub_assign(&mut self, other: Fract8) -> Result<(), FractError> {
+        *self = self.sub_checked(&other)?;
+        Ok(())
     }
-}
 
-// Fract16
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct Fract16 {
-    pub numerator: u16,
-    pub denominator: u16,
-}
+    #[inline]
+    fn checked_mul_assign(&mut self, other: Fract8) -> Result<(), FractError> {
+        *self = self.mul_checked(&other)?;
+        Ok(())
+    }
 
-impl Fract<u16, Fract16, f32> for Fract16 {
     #[inline]
-    fn to_float(&self) -> f32 {
-        self.numerator as f32 / self.denominator as f32
+    fn checked_div_assign(&mut self, other: Fract8) -> Result<(), FractError> {
+        *self = self.div_checked(&other)?;
+        Ok(())
     }
 
     #[inline]
-    fn new(numerator: u16, denominator: u16) -> Fract16 {
-        Fract16 {
-            numerator: numerator,
-            denominator: denominator,
+    fn to_string_reduced(&self) -> String {
+        let reduced: Fract8 = self.reduce();
+        if reduced.denominator == 1 {
+            reduced.numerator.to_string()
+        } else {
+            format!("{}/{}", reduced.numerator, reduced.denominator)
         }
     }
 
     #[inline]
-    fn invert(&self) -> Fract16 {
-        Fract16 {
-            numerator: self.denominator,
-            denominator: self.numerator,
+    fn denominator_factors(&self) -> Vec<(u8, u32)> {
+        let mut remaining: u8 = self.reduce().denominator;
+        let mut factors: Vec<(u8, u32)> = Vec::new();
+        let mut divisor: u8 = 2;
+        while remaining > 1 && divisor <= remaining / divisor {
+            let mut exponent: u32 = 0;
+            while remaining.is_multiple_of(divisor) {
+                remaining /= divisor;
+                exponent += 1;
+            }
+
+            if exponent > 0 {
+                factors.push((divisor, exponent));
+            }
+            divisor += 1;
+        }
+
+        if remaining > 1 {
+            factors.push((remaining, 1));
         }
+
+        factors
     }
 
     #[inline]
-    fn expand(&self, multiplicator: u16) -> Fract16 {
-        Fract16 {
-            numerator: self.numerator * multiplicator,
-            denominator: self.denominator * multiplicator,
+    fn terminates_in_base(&self, base: u8) -> bool {
+        let mut denominator: u8 = self.reduce().denominator;
+        if denominator == 0 {
+            return false;
+        }
+        if base < 2 {
+            return denominator == 1;
+        }
+
+        // strips every prime factor shared with the base
+        loop {
+            let gcd: u8 = utils::gcd_u8(denominator, base);
+            if gcd == 1 {
+                return denominator == 1;
+            }
+            denominator /= gcd;
         }
     }
 
     #[inline]
-    fn reduce(&self) -> Fract16 {
-        let gcd: u16 = utils::gcd_u16(self.numerator, self.denominator);
-        Fract16 {
-            numerator: self.numerator / gcd,
-            denominator: self.denominator / gcd,
+    fn proper_part(&self) -> Fract8 {
+        Fract8 {
+            numerator: self.numerator % self.denominator,
+            denominator: self.denominator,
         }
+        .reduce()
     }
-}
 
-impl From<u16> for Fract16 {
     #[inline]
-    fn from(input: u16) -> Self {
-        Fract16 {
-            numerator: input,
-            denominator: 1,
-        }
+    fn integer_scale(&self) -> u8 {
+        self.reduce().denominator
     }
-}
 
-impl Add for Fract16 {
-    type Output = Fract16;
+    #[inline]
+    fn scaled_numerator(&self) -> u8 {
+        self.reduce().numerator
+    }
 
     #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract16 = self;
-        let mut nrhs: Fract16 = rhs;
+    fn powf(&self, exp: f64) -> f64 {
+        (self.numerator as f64 / self.denominator as f64).powf(exp)
+    }
 
-        if self.denominator != rhs.denominator {
-            let old_denom: u16 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
+    #[inline]
+    fn powf_approx(&self, exp: f64, max_denominator: u8) -> Fract8 {
+        Fract8::saturating_from_float(self.powf(exp) as f32, max_denominator)
+    }
+
+    #[inline]
+    fn largest_unit_fraction(&self) -> Fract8 {
+        let mut denominator: u8 = self.denominator / self.numerator;
+        if !self.denominator.is_multiple_of(self.numerator) {
+            denominator += 1;
         }
 
-        Fract16 {
-            numerator: nlhs.numerator + nrhs.numerator,
-            denominator: nlhs.denominator,
+        Fract8 {
+            numerator: 1,
+            denominator,
         }
     }
-}
-
-impl Sub for Fract16 {
-    type Output = Fract16;
 
     #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract16 = self;
-        let mut nrhs: Fract16 = rhs;
+    fn egyptian_fraction(&self) -> Option<Vec<Fract8>> {
+        if self.denominator == 0 {
+            return None;
+        }
 
-        if self.denominator != rhs.denominator {
-            let old_denom: u16 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
+        let mut units: Vec<Fract8> = Vec::new();
+        let whole: u8 = self.numerator / self.denominator;
+        if whole != 0 {
+            units.push(Fract8::from(whole));
         }
 
-        Fract16 {
-            numerator: nlhs.numerator - nrhs.numerator,
-            denominator: nlhs.denominator,
+        let mut remaining: Fract8 = self.proper_part();
+        while remaining.numerator != 0 {
+            let unit: Fract8 = remaining.largest_unit_fraction();
+            units.push(unit);
+            remaining = remaining.sub_checked(&unit).ok()?.reduce();
         }
-    }
-}
 
-impl Mul for Fract16 {
-    type Output = Fract16;
+        Some(units)
+    }
 
     #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
-        Fract16 {
-            numerator: self.numerator * rhs.numerator,
-            denominator: self.denominator * rhs.denominator,
+    fn safe_cmp(&self, other: &Fract8) -> Option<Ordering> {
+        if self.denominator == 0 || other.denominator == 0 {
+            return None;
         }
-    }
-}
 
-impl Div for Fract16 {
-    type Output = Fract16;
+        Some(self.cmp_exact(other))
+    }
 
     #[inline]
-    fn div(self, rhs: Self) -> Self::Output {
-        self * rhs.invert()
+    fn with_numerator(&self, numerator: u8) -> Fract8 {
+        Fract8 {
+            numerator,
+            denominator: self.denominator,
+        }
     }
-}
-#[cfg(test)]
-mod tests_fract16 {
-    use assert_approx_eq::assert_approx_eq;
 
-    use crate::{Fract, Fract16};
+    #[inline]
+    fn with_denominator(&self, denominator: u8) -> Fract8 {
+        Fract8 {
+            numerator: self.numerator,
+            denominator,
+        }
+    }
 
-    #[test]
-    fn should_create() {
-        let expected: Fract16 = Fract16 {
-            numerator: 8,
-            denominator: 10,
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Fract8, FractError> {
+        let (numerator, denominator) = match s.trim().split_once('/') {
+            Some((numerator, denominator)) => (numerator.trim(), denominator.trim()),
+            None => (s.trim(), "1"),
         };
 
-        let actual: Fract16 = Fract16::new(8, 10);
+        if !(2..=36).contains(&radix)
+            || !utils::is_digits_radix(numerator, radix)
+            || !utils::is_digits_radix(denominator, radix)
+        {
+            return Err(FractError::InvalidFormat);
+        }
 
-        assert_eq!(expected, actual)
+        let numerator: u8 =
+            u8::from_str_radix(numerator, radix).map_err(|_| FractError::NumeratorOverflow)?;
+        let denominator: u8 =
+            u8::from_str_radix(denominator, radix).map_err(|_| FractError::DenominatorOverflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(Fract8 {
+            numerator,
+            denominator,
+        })
     }
 
-    #[test]
-    fn should_invert() {
-        let expected: Fract16 = Fract16 {
-            numerator: 10,
-            denominator: 8,
-        };
+    #[inline]
+    fn to_basis_points(&self) -> u32 {
+        let denominator: u128 = self.denominator as u128;
+        let mut basis_points: u128 = (self.numerator as u128 / denominator).saturating_mul(10000);
+        let mut remainder: u128 = self.numerator as u128 % denominator;
+        for scale in &[1000, 100, 10, 1] {
+            let (digit, next) = utils::next_digit_u128(remainder, denominator, 10);
+            basis_points = basis_points.saturating_add(digit as u128 * scale);
+            remainder = next;
+        }
 
-        let actual: Fract16 = Fract16::new(8, 10).invert();
+        if utils::next_digit_u128(remainder, denominator, 10).0 >= 5 {
+            basis_points = basis_points.saturating_add(1);
+        }
 
-        assert_eq!(expected, actual)
+        u32::try_from(basis_points).unwrap_or(u32::MAX)
     }
 
-    #[test]
-    fn should_expand() {
-        let expected: Fract16 = Fract16 {
-            numerator: 80,
-            denominator: 100,
-        };
+    #[inline]
+    fn to_float_exact(&self) -> Result<f32, f32> {
+        let reduced: Fract8 = self.reduce();
+        let value: f32 = reduced.to_float();
+        if reduced.denominator == 0 || !reduced.denominator.is_power_of_two() {
+            return Err(value);
+        }
+        if reduced.numerator == 0 {
+            return Ok(value);
+        }
 
-        let actual: Fract16 = Fract16::new(8, 10).expand(10);
+        let significant: u8 = reduced.numerator >> reduced.numerator.trailing_zeros();
+        if u8::BITS - significant.leading_zeros() <= f32::MANTISSA_DIGITS {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
 
-        assert_eq!(expected, actual)
+    #[inline]
+    fn components(&self) -> (u8, u8) {
+        (self.numerator, self.denominator)
     }
 
-    #[test]
-    fn should_convert() {
-        let expected: f32 = 0.8;
-        let actual: f32 = Fract16::new(8, 10).to_float();
+    #[inline]
+    fn div_rem_fract(&self, other: &Fract8) -> Option<(u8, Fract8)> {
+        if other.numerator == 0 {
+            return None;
+        }
 
-        assert_approx_eq!(expected, actual)
+        let (lhs, rhs, denominator) = self
+            .reduce()
+            .expand_to_common_checked(&other.reduce())
+            .ok()?;
+
+        Some((lhs / rhs, Fract8::new(lhs % rhs, denominator).reduce()))
     }
 
-    #[test]
-    fn should_add() {
-        let expected: Fract16 = Fract16 {
-            numerator: 28,
-            denominator: 20,
-        };
+    #[inline]
+    fn reduce_mut(&mut self) {
+        *self = self.reduce();
+    }
 
-        let first: Fract16 = Fract16::new(1, 2);
-        let second: Fract16 = Fract16::new(9, 10);
-        let result: Fract16 = first + second;
+    #[inline]
+    fn float_ulp_error(&self) -> u64 {
+        if self.denominator == 0 {
+            return 0;
+        }
 
-        assert_eq!(expected, result)
+        utils::ulp_error(
+            self.to_float().to_bits() as u64,
+            f32::MANTISSA_DIGITS - 1,
+            150,
+            self.numerator as u128,
+            self.denominator as u128,
+        )
     }
 
-    #[test]
-    fn should_sub() {
-        let expected: Fract16 = Fract16 {
-            numerator: 22,
-            denominator: 20,
-        };
-
-        let first: Fract16 = Fract16::new(4, 2);
-        let second: Fract16 = Fract16::new(9, 10);
-        let result: Fract16 = first - second;
+    #[inline]
+    fn modular_inverse(&self) -> Option<u8> {
+        utils::inverse_mod_u128(self.numerator as u128, self.denominator as u128)
+            .map(|inverse| inverse as u8)
+    }
 
-        assert_eq!(expected, result)
+    #[inline]
+    fn checked_add_int(&self, value: u8) -> Option<Fract8> {
+        self.add_checked(&Fract8::from(value)).ok()
     }
 
-    #[test]
-    fn should_mul() {
-        let expected: Fract16 = Fract16 {
-            numerator: 8,
-            denominator: 10,
-        };
+    #[inline]
+    fn display_approx(&self, max_denominator: u8) -> String {
+        let reduced: Fract8 = self.reduce();
+        if reduced.denominator <= max_denominator {
+            return reduced.to_string();
+        }
 
-        let first: Fract16 = Fract16::new(2, 5);
-        let second: Fract16 = Fract16::new(4, 2);
-        let result: Fract16 = first * second;
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            reduced.numerator as u128,
+            reduced.denominator as u128,
+            u8::MAX as u128,
+            max_denominator.max(1) as u128,
+        );
 
-        assert_eq!(expected, result)
+        format!("≈{}", Fract8::new(numerator as u8, denominator as u8))
     }
 
-    #[test]
-    fn should_div() {
-        let expected: Fract16 = Fract16 {
-            numerator: 10,
-            denominator: 18,
-        };
+    #[inline]
+    fn from_float_capped(
+        value: f64,
+        max_iterations: u32,
+        max_denominator: u8,
+    ) -> Result<Fract8, FractError> {
+        if value.is_nan() {
+            return Err(FractError::InvalidFormat);
+        }
+        if value < 0.0 {
+            return Err(FractError::NumeratorUnderflow);
+        }
+        if value >= u8::MAX as f64 + 1.0 {
+            return Err(FractError::NumeratorOverflow);
+        }
 
-        let first: Fract16 = Fract16::new(1, 2);
-        let second: Fract16 = Fract16::new(9, 10);
-        let result: Fract16 = first / second;
+        let (numerator, denominator) = utils::approximate_f64_capped(
+            value,
+            u8::MAX as u128,
+            max_denominator.max(1) as u128,
+            max_iterations,
+        )
+        .map_err(|_| FractError::IterationLimit)?;
 
-        assert_eq!(expected, result)
+        Ok(Fract8::new(numerator as u8, denominator as u8))
     }
 
-    #[test]
-    fn should_reduce() {
-        let expected: Fract16 = Fract16 {
-            numerator: 5,
-            denominator: 9,
-        };
+    #[inline]
+    fn decimal_digit_at(&self, position: u32) -> u8 {
+        if self.denominator == 0 {
+            return 0;
+        }
+        if position == 0 {
+            return self.numerator / self.denominator % 10;
+        }
 
-        let value: Fract16 = Fract16 {
-            numerator: 10,
-            denominator: 18,
-        };
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        let mut digit: u32 = 0;
+        for _ in 0..position {
+            let (next_digit, next_remainder) =
+                utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digit = next_digit;
+            remainder = next_remainder;
+        }
 
-        assert_eq!(expected, value.reduce())
+        digit as u8
     }
-}
-
-// Fract32
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct Fract32 {
-    pub numerator: u32,
-    pub denominator: u32,
-}
 
-impl Fract<u32, Fract32, f32> for Fract32 {
     #[inline]
-    fn to_float(&self) -> f32 {
-        self.numerator as f32 / self.denominator as f32
+    fn reduce_checked(&self) -> (Fract8, bool) {
+        let reduced: Fract8 = self.reduce();
+
+        (reduced, reduced != *self)
     }
 
     #[inline]
-    fn new(numerator: u32, denominator: u32) -> Fract32 {
-        Fract32 {
-            numerator: numerator,
-            denominator: denominator,
-        }
+    fn scale(&self, factor: u8) -> Option<Fract8> {
+        Some(Fract8 {
+            numerator: self.numerator.checked_mul(factor)?,
+            denominator: self.denominator.checked_mul(factor)?,
+        })
     }
 
     #[inline]
-    fn invert(&self) -> Fract32 {
-        Fract32 {
-            numerator: self.denominator,
-            denominator: self.numerator,
+    fn to_sig_figs(&self, sig_figs: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
         }
+
+        utils::sig_figs_u128(self.numerator as u128, self.denominator as u128, sig_figs)
     }
 
     #[inline]
-    fn expand(&self, multiplicator: u32) -> Fract32 {
-        Fract32 {
-            numerator: self.numerator * multiplicator,
-            denominator: self.denominator * multiplicator,
+    fn clamp01(&self) -> Fract8 {
+        if self.numerator > self.denominator {
+            Fract8::new(1, 1)
+        } else {
+            *self
         }
     }
 
     #[inline]
-    fn reduce(&self) -> Fract32 {
-        let gcd: u32 = utils::gcd_u32(self.numerator, self.denominator);
-        Fract32 {
-            numerator: self.numerator / gcd,
-            denominator: self.denominator / gcd,
+    fn period_length(&self) -> Option<u64> {
+        let mut modulus: u8 = self.reduce().denominator;
+        if modulus == 0 {
+            return Some(0);
         }
+        while modulus.is_multiple_of(2) {
+            modulus /= 2;
+        }
+        while modulus.is_multiple_of(5) {
+            modulus /= 5;
+        }
+        if modulus == 1 {
+            return Some(0);
+        }
+
+        u64::try_from(utils::decimal_period_u128(modulus as u128)).ok()
     }
-}
 
-impl From<u32> for Fract32 {
     #[inline]
-    fn from(input: u32) -> Self {
-        Fract32 {
-            numerator: input,
-            denominator: 1,
+    fn validate(&self) -> Result<(), FractError> {
+        if self.denominator == 0 {
+            return Err(FractError::ZeroDenominator);
         }
-    }
-}
 
-impl Add for Fract32 {
-    type Output = Fract32;
+        Ok(())
+    }
 
     #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract32 = self;
-        let mut nrhs: Fract32 = rhs;
+    fn decimal_digits_rounded(&self, count: usize) -> Vec<u8> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
 
-        if self.denominator != rhs.denominator {
-            let old_denom: u32 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
+        let mut digits: Vec<u8> = Vec::with_capacity(count);
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        for _ in 0..count {
+            let (digit, next) = utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digits.push(digit as u8);
+            remainder = next;
         }
 
-        Fract32 {
-            numerator: nlhs.numerator + nrhs.numerator,
-            denominator: nlhs.denominator,
+        if utils::next_digit_u128(remainder, self.denominator as u128, 10).0 >= 5 {
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    break;
+                }
+            }
         }
-    }
-}
 
-impl Sub for Fract32 {
-    type Output = Fract32;
+        digits
+    }
 
     #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract32 = self;
-        let mut nrhs: Fract32 = rhs;
+    fn minimal(&self) -> Fract8 {
+        self.reduce()
+    }
 
-        if self.denominator != rhs.denominator {
-            let old_denom: u32 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
+    #[inline]
+    fn checked_shl(&self, n: u32) -> Option<Fract8> {
+        if self.numerator == 0 {
+            return Some(*self);
         }
 
-        Fract32 {
-            numerator: nlhs.numerator - nrhs.numerator,
-            denominator: nlhs.denominator,
-        }
+        let cancelled: u32 = self.denominator.trailing_zeros().min(n);
+        Some(Fract8 {
+            numerator: self
+                .numerator
+                .checked_mul(u8::checked_pow(2, n - cancelled)?)?,
+            denominator: self.denominator.checked_shr(cancelled)?,
+        })
     }
-}
-
-impl Mul for Fract32 {
-    type Output = Fract32;
 
     #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
-        Fract32 {
-            numerator: self.numerator * rhs.numerator,
-            denominator: self.denominator * rhs.denominator,
+    fn checked_shr(&self, n: u32) -> Option<Fract8> {
+        if self.numerator == 0 {
+            return Some(*self);
         }
-    }
-}
 
-impl Div for Fract32 {
-    type Output = Fract32;
+        let cancelled: u32 = self.numerator.trailing_zeros().min(n);
+        Some(Fract8 {
+            numerator: self.numerator >> cancelled,
+            denominator: self
+                .denominator
+                .checked_mul(u8::checked_pow(2, n - cancelled)?)?,
+        })
+    }
 
     #[inline]
-    fn div(self, rhs: Self) -> Self::Output {
-        self * rhs.invert()
+    fn reduce_keep_denominator(&self) -> Fract8 {
+        if self.numerator == 0 {
+            return *self;
+        }
+
+        self.reduce()
     }
-}
-#[cfg(test)]
-mod tests_fract32 {
-    use assert_approx_eq::assert_approx_eq;
 
-    use crate::{Fract, Fract32};
+    #[inline]
+    fn to_mixed_string(&self) -> String {
+        if self.denominator == 0 {
+            return self.to_string();
+        }
 
-    #[test]
-    fn should_create() {
-        let expected: Fract32 = Fract32 {
-            numerator: 8,
-            denominator: 10,
-        };
+        let whole: u8 = self.numerator / self.denominator;
+        let proper: Fract8 = self.proper_part();
+        if proper.numerator == 0 {
+            whole.to_string()
+        } else if whole == 0 {
+            proper.to_string()
+        } else {
+            format!("{} {}", whole, proper)
+        }
+    }
 
-        let actual: Fract32 = Fract32::new(8, 10);
+    #[inline]
+    fn ratio_to(&self, other: &Fract8) -> Option<Fract8> {
+        if other.numerator == 0 {
+            return None;
+        }
 
-        assert_eq!(expected, actual)
+        Fract8::product_reduced([*self, other.invert()])
     }
 
-    #[test]
-    fn should_invert() {
-        let expected: Fract32 = Fract32 {
-            numerator: 10,
-            denominator: 8,
+    #[inline]
+    fn is_between(&self, low: &Fract8, high: &Fract8) -> bool {
+        let (low, high) = if low.cmp_exact(high) == Ordering::Greater {
+            (high, low)
+        } else {
+            (low, high)
         };
 
-        let actual: Fract32 = Fract32::new(8, 10).invert();
+        self.cmp_exact(low) != Ordering::Less && self.cmp_exact(high) != Ordering::Greater
+    }
 
-        assert_eq!(expected, actual)
+    #[inline]
+    fn convergents(&self) -> Vec<Fract8> {
+        let (mut p0, mut q0, mut p1, mut q1): (u8, u8, u8, u8) = (0, 1, 1, 0);
+        self.continued_fraction()
+            .into_iter()
+            .map(|coefficient| {
+                let (p2, q2) = (coefficient * p1 + p0, coefficient * q1 + q0);
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+                Fract8::new(p2, q2)
+            })
+            .collect()
     }
 
-    #[test]
-    fn should_expand() {
-        let expected: Fract32 = Fract32 {
-            numerator: 80,
-            denominator: 100,
-        };
+    #[inline]
+    fn cmp_with_float(&self, value: f64) -> Ordering {
+        utils::cmp_ratio_f64(self.numerator as u128, self.denominator as u128, value)
+    }
 
-        let actual: Fract32 = Fract32::new(8, 10).expand(10);
+    #[inline]
+    fn integer_and_fraction(&self) -> (Fract8, Fract8) {
+        if self.denominator == 0 {
+            return (*self, Fract8::new(0, 1));
+        }
 
-        assert_eq!(expected, actual)
+        (
+            Fract8::from(self.numerator / self.denominator),
+            self.proper_part(),
+        )
     }
 
-    #[test]
-    fn should_convert() {
-        let expected: f32 = 0.8;
-        let actual: f32 = Fract32::new(8, 10).to_float();
-
-        assert_approx_eq!(expected, actual)
-    }
-
-    #[test]
-    fn should_add() {
-        let expected: Fract32 = Fract32 {
-            numerator: 28,
-            denominator: 20,
-        };
-
-        let first: Fract32 = Fract32::new(1, 2);
-        let second: Fract32 = Fract32::new(9, 10);
-        let result: Fract32 = first + second;
-
-        assert_eq!(expected, result)
-    }
-
-    #[test]
-    fn should_sub() {
-        let expected: Fract32 = Fract32 {
-            numerator: 22,
-            denominator: 20,
-        };
-
-        let first: Fract32 = Fract32::new(4, 2);
-        let second: Fract32 = Fract32::new(9, 10);
-        let result: Fract32 = first - second;
-
-        assert_eq!(expected, result)
-    }
-
-    #[test]
-    fn should_mul() {
-        let expected: Fract32 = Fract32 {
-            numerator: 8,
-            denominator: 10,
-        };
-
-        let first: Fract32 = Fract32::new(2, 5);
-        let second: Fract32 = Fract32::new(4, 2);
-        let result: Fract32 = first * second;
-
-        assert_eq!(expected, result)
-    }
-
-    #[test]
-    fn should_div() {
-        let expected: Fract32 = Fract32 {
-            numerator: 10,
-            denominator: 18,
+    #[inline]
+    fn snap_to(&self, allowed: &[Fract8]) -> Option<Fract8> {
+        let distance = |other: &Fract8| {
+            utils::distance_u128(
+                self.numerator as u128,
+                self.denominator as u128,
+                other.numerator as u128,
+                other.denominator as u128,
+            )
         };
 
-        let first: Fract32 = Fract32::new(1, 2);
-        let second: Fract32 = Fract32::new(9, 10);
-        let result: Fract32 = first / second;
+        let mut best: Option<(Fract8, Option<(u128, u128)>)> = None;
+        for candidate in allowed {
+            let candidate_distance: Option<(u128, u128)> = distance(candidate);
+            let closer: bool = match best {
+                None => true,
+                Some((current, current_distance)) => match (candidate_distance, current_distance) {
+                    (Some(lhs), Some(rhs)) => {
+                        utils::cmp_ratio_u128(lhs.0, lhs.1, rhs.0, rhs.1) == Ordering::Less
+                    }
+                    _ => self.abs_diff_float(candidate) < self.abs_diff_float(&current),
+                },
+            };
+            if closer {
+                best = Some((*candidate, candidate_distance));
+            }
+        }
 
-        assert_eq!(expected, result)
+        best.map(|(fraction, _)| fraction)
     }
 
-    #[test]
-    fn should_reduce() {
-        let expected: Fract32 = Fract32 {
-            numerator: 5,
-            denominator: 9,
-        };
-
-        let value: Fract32 = Fract32 {
-            numerator: 10,
-            denominator: 18,
-        };
+    #[inline]
+    fn to_string_radix(&self, radix: u32, max_digits: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
 
-        assert_eq!(expected, value.reduce())
+        utils::radix_string_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            radix,
+            max_digits,
+        )
     }
-}
-
-// Fract64
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct Fract64 {
-    pub numerator: u64,
-    pub denominator: u64,
-}
 
-impl Fract<u64, Fract64, f64> for Fract64 {
     #[inline]
-    fn to_float(&self) -> f64 {
-        self.numerator as f64 / self.denominator as f64
-    }
+    fn continued_fraction_string(&self) -> String {
+        let coefficients: Vec<String> = self
+            .continued_fraction()
+            .iter()
+            .map(|coefficient| coefficient.to_string())
+            .collect();
 
-    #[inline]
-    fn new(numerator: u64, denominator: u64) -> Fract64 {
-        Fract64 {
-            numerator: numerator,
-            denominator: denominator,
+        match coefficients.split_first() {
+            None => String::from("[]"),
+            Some((first, [])) => format!("[{}]", first),
+            Some((first, rest)) => format!("[{}; {}]", first, rest.join(", ")),
         }
     }
 
     #[inline]
-    fn invert(&self) -> Fract64 {
-        Fract64 {
-            numerator: self.denominator,
-            denominator: self.numerator,
+    fn reciprocal_reduced(&self) -> Option<Fract8> {
+        if self.numerator == 0 {
+            return None;
         }
+
+        Some(self.invert().reduce())
     }
 
     #[inline]
-    fn expand(&self, multiplicator: u64) -> Fract64 {
-        Fract64 {
-            numerator: self.numerator * multiplicator,
-            denominator: self.denominator * multiplicator,
-        }
+    fn cross_difference(&self, other: &Fract8) -> i128 {
+        utils::cross_difference_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            other.numerator as u128,
+            other.denominator as u128,
+        )
     }
 
     #[inline]
-    fn reduce(&self) -> Fract64 {
-        let gcd: u64 = utils::gcd_u64(self.numerator, self.denominator);
-        Fract64 {
-            numerator: self.numerator / gcd,
-            denominator: self.denominator / gcd,
-        }
+    fn simplest_between(&self, other: &Fract8) -> Option<Fract8> {
+        let (low, high) = match self.cmp_exact(other) {
+            Ordering::Equal => return Some(self.reduce()),
+            Ordering::Less => (self, other),
+            Ordering::Greater => (other, self),
+        };
+
+        let (numerator, denominator) = utils::simplest_between_u128(
+            low.numerator as u128,
+            low.denominator as u128,
+            high.numerator as u128,
+            high.denominator as u128,
+        )?;
+
+        Some(Fract8::new(
+            u8::try_from(numerator).ok()?,
+            u8::try_from(denominator).ok()?,
+        ))
     }
 }
 
-impl From<u64> for Fract64 {
+impl From<u8> for Fract8 {
     #[inline]
-    fn from(input: u64) -> Self {
-        Fract64 {
+    fn from(input: u8) -> Self {
+        Fract8 {
             numerator: input,
             denominator: 1,
         }
     }
 }
 
-impl Add for Fract64 {
-    type Output = Fract64;
+impl Add for Fract8 {
+    type Output = Fract8;
 
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract64 = self;
-        let mut nrhs: Fract64 = rhs;
+        let mut nlhs: Fract8 = self;
+        let mut nrhs: Fract8 = rhs;
 
         if self.denominator != rhs.denominator {
-            let old_denom: u64 = nlhs.denominator;
+            let old_denom: u8 = nlhs.denominator;
             nlhs = nlhs.expand(nrhs.denominator);
             nrhs = nrhs.expand(old_denom);
         }
 
-        Fract64 {
+        Fract8 {
             numerator: nlhs.numerator + nrhs.numerator,
             denominator: nlhs.denominator,
         }
     }
 }
 
-impl Sub for Fract64 {
-    type Output = Fract64;
+impl Sub for Fract8 {
+    type Output = Fract8;
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract64 = self;
-        let mut nrhs: Fract64 = rhs;
+        let mut nlhs: Fract8 = self;
+        let mut nrhs: Fract8 = rhs;
 
         if self.denominator != rhs.denominator {
-            let old_denom: u64 = nlhs.denominator;
+            let old_denom: u8 = nlhs.denominator;
             nlhs = nlhs.expand(nrhs.denominator);
             nrhs = nrhs.expand(old_denom);
         }
 
-        Fract64 {
+        Fract8 {
             numerator: nlhs.numerator - nrhs.numerator,
             denominator: nlhs.denominator,
         }
     }
 }
 
-impl Mul for Fract64 {
-    type Output = Fract64;
+impl Mul for Fract8 {
+    type Output = Fract8;
 
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        Fract64 {
-            numerator: self.numerator * rhs.numerator,
-            denominator: self.denominator * rhs.denominator,
+        // cancels common factors crosswise before multiplying to keep the products small
+        let first: u8 = utils::gcd_u8(self.numerator, rhs.denominator).max(1);
+        let second: u8 = utils::gcd_u8(rhs.numerator, self.denominator).max(1);
+
+        Fract8 {
+            numerator: (self.numerator / first) * (rhs.numerator / second),
+            denominator: (self.denominator / second) * (rhs.denominator / first),
         }
     }
 }
 
-impl Div for Fract64 {
-    type Output = Fract64;
+impl Div for Fract8 {
+    type Output = Fract8;
 
     #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: Self) -> Self::Output {
         self * rhs.invert()
     }
 }
-#[cfg(test)]
-mod tests_fract64 {
-    use assert_approx_eq::assert_approx_eq;
 
-    use crate::{Fract, Fract64};
+impl Widen for Fract8 {
+    type Wider = Fract16;
 
-    #[test]
-    fn should_create() {
-        let expected: Fract64 = Fract64 {
-            numerator: 8,
-            denominator: 10,
-        };
+    #[inline]
+    fn widen(self) -> Fract16 {
+        Fract16 {
+            numerator: self.numerator as u16,
+            denominator: self.denominator as u16,
+        }
+    }
+}
 
-        let actual: Fract64 = Fract64::new(8, 10);
+impl PartialOrd for Fract8 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-        assert_eq!(expected, actual)
+impl Ord for Fract8 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_exact(other)
+            .then(self.numerator.cmp(&other.numerator))
+            .then(self.denominator.cmp(&other.denominator))
     }
+}
 
-    #[test]
-    fn should_invert() {
-        let expected: Fract64 = Fract64 {
-            numerator: 10,
-            denominator: 8,
-        };
+impl Fract8 {
+    #[inline]
+    pub fn cross_multiply(&self, other: &Fract8) -> (u16, u16) {
+        (
+            self.numerator as u16 * other.denominator as u16,
+            other.numerator as u16 * self.denominator as u16,
+        )
+    }
 
-        let actual: Fract64 = Fract64::new(8, 10).invert();
+    #[inline]
+    fn expand_to_common_checked(&self, other: &Fract8) -> Result<(u8, u8, u8), FractError> {
+        if self.denominator == other.denominator {
+            return Ok((self.numerator, other.numerator, self.denominator));
+        }
 
-        assert_eq!(expected, actual)
+        let denominator: u8 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+        let lhs: u8 = self
+            .numerator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let rhs: u8 = other
+            .numerator
+            .checked_mul(self.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+
+        Ok((lhs, rhs, denominator))
     }
 
-    #[test]
-    fn should_expand() {
-        let expected: Fract64 = Fract64 {
-            numerator: 80,
-            denominator: 100,
-        };
+    pub fn common_denominator(fractions: &[Fract8]) -> Result<Vec<Fract8>, FractError> {
+        let mut denominator: u8 = 1;
+        for fraction in fractions {
+            if fraction.denominator == 0 {
+                return Err(FractError::ZeroDenominator);
+            }
+            denominator = utils::checked_lcm_u8(denominator, fraction.denominator)
+                .ok_or(FractError::DenominatorOverflow)?;
+        }
 
-        let actual: Fract64 = Fract64::new(8, 10).expand(10);
+        fractions
+            .iter()
+            .map(|fraction| {
+                fraction
+                    .numerator
+                    .checked_mul(denominator / fraction.denominator)
+                    .map(|numerator| Fract8::new(numerator, denominator))
+                    .ok_or(FractError::NumeratorOverflow)
+            })
+            .collect()
+    }
 
-        assert_eq!(expected, actual)
+    #[inline]
+    pub fn to_le_bytes(&self) -> [u8; 2] {
+        let mut bytes: [u8; 2] = [0; 2];
+        let (numerator, denominator) = bytes.split_at_mut(1);
+        numerator.copy_from_slice(&self.numerator.to_le_bytes());
+        denominator.copy_from_slice(&self.denominator.to_le_bytes());
+        bytes
     }
 
-    #[test]
-    fn should_convert() {
-        let expected: f64 = 0.8;
-        let actual: f64 = Fract64::new(8, 10).to_float();
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 2]) -> Fract8 {
+        let mut numerator: [u8; 1] = [0; 1];
+        let mut denominator: [u8; 1] = [0; 1];
+        numerator.copy_from_slice(&bytes[..1]);
+        denominator.copy_from_slice(&bytes[1..]);
 
-        assert_approx_eq!(expected, actual)
+        Fract8 {
+            numerator: u8::from_le_bytes(numerator),
+            denominator: u8::from_le_bytes(denominator),
+        }
     }
 
-    #[test]
-    fn should_add() {
-        let expected: Fract64 = Fract64 {
-            numerator: 28,
-            denominator: 20,
-        };
+    /// Sums over common denominators, reducing after every step; `None` once an
+    /// intermediate value overflows or a denominator is zero.
+    pub fn sum_reduced<I: IntoIterator<Item = Fract8>>(fractions: I) -> Option<Fract8> {
+        fractions
+            .into_iter()
+            .try_fold(Fract8::new(0, 1), |sum, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
 
-        let first: Fract64 = Fract64::new(1, 2);
-        let second: Fract64 = Fract64::new(9, 10);
-        let result: Fract64 = first + second;
+                let fraction: Fract8 = fraction.reduce();
+                let denominator: u8 = utils::checked_lcm_u8(sum.denominator, fraction.denominator)?;
+                let numerator: u8 = sum
+                    .numerator
+                    .checked_mul(denominator / sum.denominator)?
+                    .checked_add(
+                        fraction
+                            .numerator
+                            .checked_mul(denominator / fraction.denominator)?,
+                    )?;
 
-        assert_eq!(expected, result)
+                Some(Fract8::new(numerator, denominator).reduce())
+            })
     }
 
-    #[test]
-    fn should_sub() {
-        let expected: Fract64 = Fract64 {
-            numerator: 22,
-            denominator: 20,
-        };
+    /// Multiplies with cross-cancellation so the running product stays reduced; `None` on
+    /// overflow or a zero denominator.
+    pub fn product_reduced<I: IntoIterator<Item = Fract8>>(fractions: I) -> Option<Fract8> {
+        fractions
+            .into_iter()
+            .try_fold(Fract8::new(1, 1), |product, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
 
-        let first: Fract64 = Fract64::new(4, 2);
-        let second: Fract64 = Fract64::new(9, 10);
-        let result: Fract64 = first - second;
+                let fraction: Fract8 = fraction.reduce();
+                let first: u8 = utils::gcd_u8(product.numerator, fraction.denominator).max(1);
+                let second: u8 = utils::gcd_u8(fraction.numerator, product.denominator).max(1);
 
-        assert_eq!(expected, result)
+                Some(Fract8::new(
+                    (product.numerator / first).checked_mul(fraction.numerator / second)?,
+                    (product.denominator / second).checked_mul(fraction.denominator / first)?,
+                ))
+            })
     }
+}
 
-    #[test]
-    fn should_mul() {
-        let expected: Fract64 = Fract64 {
-            numerator: 8,
-            denominator: 10,
-        };
+impl Shl<u32> for Fract8 {
+    type Output = Fract8;
 
-        let first: Fract64 = Fract64::new(2, 5);
-        let second: Fract64 = Fract64::new(4, 2);
-        let result: Fract64 = first * second;
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
 
-        assert_eq!(expected, result)
+        // cancels factors of two in the denominator before growing the numerator
+        let cancelled: u32 = self.denominator.trailing_zeros().min(rhs);
+        Fract8 {
+            numerator: self.numerator * (1 << (rhs - cancelled)),
+            denominator: self.denominator >> cancelled,
+        }
     }
+}
 
-    #[test]
-    fn should_div() {
-        let expected: Fract64 = Fract64 {
-            numerator: 10,
-            denominator: 18,
-        };
+impl Shr<u32> for Fract8 {
+    type Output = Fract8;
 
-        let first: Fract64 = Fract64::new(1, 2);
-        let second: Fract64 = Fract64::new(9, 10);
-        let result: Fract64 = first / second;
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
 
-        assert_eq!(expected, result)
+        // cancels factors of two in the numerator before growing the denominator
+        let cancelled: u32 = self.numerator.trailing_zeros().min(rhs);
+        Fract8 {
+            numerator: self.numerator >> cancelled,
+            denominator: self.denominator * (1 << (rhs - cancelled)),
+        }
     }
+}
 
-    #[test]
-    fn should_reduce() {
-        let expected: Fract64 = Fract64 {
-            numerator: 5,
-            denominator: 9,
-        };
-
-        let value: Fract64 = Fract64 {
-            numerator: 10,
-            denominator: 18,
-        };
+impl FromStr for Fract8 {
+    type Err = FractError;
 
-        assert_eq!(expected, value.reduce())
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Fract8::from_str_radix(s, 10)
     }
 }
 
-// Fract128
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub struct Fract128 {
-    pub numerator: u128,
-    pub denominator: u128,
-}
-
-impl Fract<u128, Fract128, f64> for Fract128 {
+impl From<Fract8> for f32 {
     #[inline]
-    fn to_float(&self) -> f64 {
-        self.numerator as f64 / self.denominator as f64
+    fn from(input: Fract8) -> Self {
+        input.to_float()
     }
+}
 
-    #[inline]
-    fn new(numerator: u128, denominator: u128) -> Fract128 {
-        Fract128 {
-            numerator: numerator,
-            denominator: denominator,
+impl fmt::Display for Fract8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)?;
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)?;
+        }
+
+        if f.alternate() {
+            write!(f, " ({})", self.to_float())?;
         }
+
+        Ok(())
     }
+}
 
+impl Default for Fract8 {
     #[inline]
-    fn invert(&self) -> Fract128 {
-        Fract128 {
-            numerator: self.denominator,
-            denominator: self.numerator,
+    fn default() -> Self {
+        Fract8 {
+            numerator: 0,
+            denominator: 1,
         }
     }
+}
+
+impl Add<u8> for Fract8 {
+    type Output = Fract8;
 
     #[inline]
-    fn expand(&self, multiplicator: u128) -> Fract128 {
-        Fract128 {
-            numerator: self.numerator * multiplicator,
-            denominator: self.denominator * multiplicator,
-        }
+    fn add(self, rhs: u8) -> Self::Output {
+        self + Fract8::from(rhs)
     }
+}
+
+impl Sub<u8> for Fract8 {
+    type Output = Fract8;
 
     #[inline]
-    fn reduce(&self) -> Fract128 {
-        let gcd: u128 = utils::gcd_u128(self.numerator, self.denominator);
-        Fract128 {
-            numerator: self.numerator / gcd,
-            denominator: self.denominator / gcd,
-        }
+    fn sub(self, rhs: u8) -> Self::Output {
+        self - Fract8::from(rhs)
     }
 }
 
-impl From<u128> for Fract128 {
+impl Mul<u8> for Fract8 {
+    type Output = Fract8;
+
     #[inline]
-    fn from(input: u128) -> Self {
-        Fract128 {
-            numerator: input,
-            denominator: 1,
-        }
+    fn mul(self, rhs: u8) -> Self::Output {
+        self * Fract8::from(rhs)
     }
 }
 
-impl Add for Fract128 {
-    type Output = Fract128;
+impl Div<u8> for Fract8 {
+    type Output = Fract8;
 
     #[inline]
-    fn add(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract128 = self;
-        let mut nrhs: Fract128 = rhs;
+    fn div(self, rhs: u8) -> Self::Output {
+        self / Fract8::from(rhs)
+    }
+}
 
-        if self.denominator != rhs.denominator {
-            let old_denom: u128 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
-        }
+impl Add<Fract8> for u8 {
+    type Output = Fract8;
 
-        Fract128 {
-            numerator: nlhs.numerator + nrhs.numerator,
-            denominator: nlhs.denominator,
-        }
+    #[inline]
+    fn add(self, rhs: Fract8) -> Self::Output {
+        rhs + self
     }
 }
 
-impl Sub for Fract128 {
-    type Output = Fract128;
+impl Mul<Fract8> for u8 {
+    type Output = Fract8;
 
     #[inline]
-    fn sub(self, rhs: Self) -> Self::Output {
-        let mut nlhs: Fract128 = self;
-        let mut nrhs: Fract128 = rhs;
+    fn mul(self, rhs: Fract8) -> Self::Output {
+        rhs * self
+    }
+}
 
-        if self.denominator != rhs.denominator {
-            let old_denom: u128 = nlhs.denominator;
-            nlhs = nlhs.expand(nrhs.denominator);
-            nrhs = nrhs.expand(old_denom);
+impl From<bool> for Fract8 {
+    #[inline]
+    fn from(input: bool) -> Self {
+        Fract8 {
+            numerator: input as u8,
+            denominator: 1,
         }
+    }
+}
 
-        Fract128 {
-            numerator: nlhs.numerator - nrhs.numerator,
-            denominator: nlhs.denominator,
+impl fmt::Debug for Fract8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "Fract8 {{ numerator: {}, denominator: {}, reduced: {}, value: {} }}",
+                self.numerator,
+                self.denominator,
+                self.reduce(),
+                self.to_float()
+            )
+        } else {
+            f.debug_struct("Fract8")
+                .field("numerator", &self.numerator)
+                .field("denominator", &self.denominator)
+                .finish()
         }
     }
 }
 
-impl Mul for Fract128 {
-    type Output = Fract128;
-
-    #[inline]
-    fn mul(self, rhs: Self) -> Self::Output {
-        Fract128 {
-            numerator: self.numerator * rhs.numerator,
-            denominator: self.denominator * rhs.denominator,
-        }
+impl Hash for Fract8 {
+    // hashes the reduced value as u128 so equal values match across widths
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let reduced: Fract8 = self.reduce();
+        (reduced.numerator as u128).hash(state);
+        (reduced.denominator as u128).hash(state);
     }
 }
 
-impl Div for Fract128 {
-    type Output = Fract128;
+impl TryFrom<(i64, i64)> for Fract8 {
+    type Error = FractError;
 
     #[inline]
-    fn div(self, rhs: Self) -> Self::Output {
-        self * rhs.invert()
+    fn try_from((numerator, denominator): (i64, i64)) -> Result<Self, Self::Error> {
+        let numerator: u64 =
+            u64::try_from(numerator).map_err(|_| FractError::NumeratorUnderflow)?;
+        let denominator: u64 =
+            u64::try_from(denominator).map_err(|_| FractError::DenominatorUnderflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        let divisor: u64 = utils::gcd_u64(numerator, denominator);
+        Ok(Fract8 {
+            numerator: u8::try_from(numerator / divisor)
+                .map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u8::try_from(denominator / divisor)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
     }
 }
 #[cfg(test)]
-mod tests_fract128 {
+mod tests_fract8 {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::convert::TryFrom;
+    use std::hash::{Hash, Hasher};
+
     use assert_approx_eq::assert_approx_eq;
 
-    use crate::{Fract, Fract128};
+    use crate::{Fract, Fract16, Fract8, FractError, RoundingMode, Widen};
 
     #[test]
     fn should_create() {
-        let expected: Fract128 = Fract128 {
+        let expected: Fract8 = Fract8 {
             numerator: 8,
             denominator: 10,
         };
 
-        let actual: Fract128 = Fract128::new(8, 10);
+        let actual: Fract8 = Fract8::new(8, 10);
 
         assert_eq!(expected, actual)
     }
 
     #[test]
     fn should_invert() {
-        let expected: Fract128 = Fract128 {
+        let expected: Fract8 = Fract8 {
             numerator: 10,
             denominator: 8,
         };
 
-        let actual: Fract128 = Fract128::new(8, 10).invert();
+        let actual: Fract8 = Fract8::new(8, 10).invert();
 
         assert_eq!(expected, actual)
     }
 
     #[test]
     fn should_expand() {
-        let expected: Fract128 = Fract128 {
+        let expected: Fract8 = Fract8 {
             numerator: 80,
             denominator: 100,
         };
 
-        let actual: Fract128 = Fract128::new(8, 10).expand(10);
+        let actual: Fract8 = Fract8::new(8, 10).expand(10);
 
         assert_eq!(expected, actual)
     }
 
     #[test]
     fn should_convert() {
-        let expected: f64 = 0.8;
-        let actual: f64 = Fract128::new(8, 10).to_float();
+        let expected: f32 = 0.8;
+        let actual: f32 = Fract8::new(8, 10).to_float();
 
         assert_approx_eq!(expected, actual)
     }
 
     #[test]
     fn should_add() {
-        let expected: Fract128 = Fract128 {
+        let expected: Fract8 = Fract8 {
             numerator: 28,
             denominator: 20,
         };
 
-        let first: Fract128 = Fract128::new(1, 2);
-        let second: Fract128 = Fract128::new(9, 10);
-        let result: Fract128 = first + second;
+        let first: Fract8 = Fract8::new(1, 2);
+        let second: Fract8 = Fract8::new(9, 10);
+        let result: Fract8 = first + second;
 
         assert_eq!(expected, result)
     }
 
     #[test]
     fn should_sub() {
-        let expected: Fract128 = Fract128 {
+        let expected: Fract8 = Fract8 {
             numerator: 22,
             denominator: 20,
         };
 
-        let first: Fract128 = Fract128::new(4, 2);
-        let second: Fract128 = Fract128::new(9, 10);
-        let result: Fract128 = first - second;
+        let first: Fract8 = Fract8::new(4, 2);
+        let second: Fract8 = Fract8::new(9, 10);
+        let result: Fract8 = first - second;
 
         assert_eq!(expected, result)
     }
 
     #[test]
     fn should_mul() {
-        let expected: Fract128 = Fract128 {
-            numerator: 8,
-            denominator: 10,
+        let expected: Fract8 = Fract8 {
+            numerator: 4,
+            denominator: 5,
         };
 
-        let first: Fract128 = Fract128::new(2, 5);
-        let second: Fract128 = Fract128::new(4, 2);
-        let result: Fract128 = first * second;
+        let first: Fract8 = Fract8::new(2, 5);
+        let second: Fract8 = Fract8::new(4, 2);
+        let result: Fract8 = first * second;
 
         assert_eq!(expected, result)
     }
 
     #[test]
     fn should_div() {
-        let expected: Fract128 = Fract128 {
-            numerator: 10,
-            denominator: 18,
+        let expected: Fract8 = Fract8 {
+            numerator: 5,
+            denominator: 9,
         };
 
-        let first: Fract128 = Fract128::new(1, 2);
-        let second: Fract128 = Fract128::new(9, 10);
-        let result: Fract128 = first / second;
+        let first: Fract8 = Fract8::new(1, 2);
+        let second: Fract8 = Fract8::new(9, 10);
+        let result: Fract8 = first / second;
 
         assert_eq!(expected, result)
     }
 
     #[test]
     fn should_reduce() {
-        let expected: Fract128 = Fract128 {
+        let expected: Fract8 = Fract8 {
             numerator: 5,
             denominator: 9,
         };
 
-        let value: Fract128 = Fract128 {
+        let value: Fract8 = Fract8 {
             numerator: 10,
             denominator: 18,
         };
 
         assert_eq!(expected, value.reduce())
     }
+
+    #[test]
+    fn should_invert_checked() {
+        let expected: Fract8 = Fract8 {
+            numerator: 3,
+            denominator: 2,
+        };
+
+        let actual: Result<Fract8, FractError> = Fract8::new(2, 3).invert_checked();
+
+        assert_eq!(Ok(expected), actual)
+    }
+
+    #[test]
+    fn should_not_invert_zero() {
+        let actual: Result<Fract8, FractError> = Fract8::new(0, 5).invert_checked();
+
+        assert_eq!(Err(FractError::ZeroDenominator), actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over() {
+        let expected: Fract8 = Fract8 {
+            numerator: 8,
+            denominator: 12,
+        };
+
+        let actual: Fract8 = Fract8::new(5, 8).nearest_over(12);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_improper() {
+        let expected: Fract8 = Fract8 {
+            numerator: 7,
+            denominator: 3,
+        };
+
+        let actual: Fract8 = Fract8::new(7, 3).nearest_over(3);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_round_tie_up_in_nearest_over() {
+        let expected: Fract8 = Fract8 {
+            numerator: 1,
+            denominator: 2,
+        };
+
+        let actual: Fract8 = Fract8::new(1, 4).nearest_over(2);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_near_max() {
+        assert_eq!(
+            Fract8::new(u8::MAX - 2, u8::MAX - 1),
+            Fract8::new(u8::MAX - 1, u8::MAX).nearest_over(u8::MAX - 1)
+        )
+    }
+
+    #[test]
+    fn should_widen() {
+        let expected: Fract16 = Fract16 {
+            numerator: u8::MAX as u16,
+            denominator: 10,
+        };
+
+        let actual: Fract16 = Fract8::new(u8::MAX, 10).widen();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_mul_checked() {
+        let expected: Fract8 = Fract8 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let first: Fract8 = Fract8::new(2, 5);
+        let second: Fract8 = Fract8::new(4, 2);
+
+        assert_eq!(Ok(expected), first.mul_checked(&second))
+    }
+
+    #[test]
+    fn should_report_numerator_overflow_in_mul_checked() {
+        let first: Fract8 = Fract8::new(u8::MAX, 1);
+        let second: Fract8 = Fract8::new(2, 3);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_report_denominator_overflow_in_mul_checked() {
+        let first: Fract8 = Fract8::new(1, u8::MAX);
+        let second: Fract8 = Fract8::new(1, 2);
+
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_order_by_value() {
+        let smaller: Fract8 = Fract8::new(1, 3);
+        let larger: Fract8 = Fract8::new(2, 5);
+
+        assert!(smaller < larger);
+        assert!(larger > smaller)
+    }
+
+    #[test]
+    fn should_order_equal_values_by_representation() {
+        let reduced: Fract8 = Fract8::new(1, 2);
+        let expanded: Fract8 = Fract8::new(2, 4);
+
+        assert!(reduced < expanded);
+        assert!(reduced < Fract8::new(3, 5));
+        assert!(expanded < Fract8::new(3, 5))
+    }
+
+    #[test]
+    fn should_order_large_values() {
+        let smaller: Fract8 = Fract8::new(u8::MAX - 1, u8::MAX);
+        let larger: Fract8 = Fract8::new(u8::MAX, u8::MAX - 1);
+
+        assert!(smaller < larger);
+        assert!(smaller < Fract8::new(1, 1));
+        assert!(Fract8::new(1, 1) < larger)
+    }
+
+    #[test]
+    fn should_round_half_up() {
+        assert_eq!(3, Fract8::new(5, 2).round_with(RoundingMode::HalfUp));
+        assert_eq!(4, Fract8::new(7, 2).round_with(RoundingMode::HalfUp))
+    }
+
+    #[test]
+    fn should_round_half_even() {
+        assert_eq!(2, Fract8::new(5, 2).round_with(RoundingMode::HalfEven));
+        assert_eq!(4, Fract8::new(7, 2).round_with(RoundingMode::HalfEven))
+    }
+
+    #[test]
+    fn should_round_half_down() {
+        assert_eq!(2, Fract8::new(5, 2).round_with(RoundingMode::HalfDown));
+        assert_eq!(3, Fract8::new(8, 3).round_with(RoundingMode::HalfDown))
+    }
+
+    #[test]
+    fn should_round_floor_and_ceil() {
+        assert_eq!(2, Fract8::new(8, 3).round_with(RoundingMode::Floor));
+        assert_eq!(3, Fract8::new(7, 3).round_with(RoundingMode::Ceil));
+        assert_eq!(2, Fract8::new(4, 2).round_with(RoundingMode::Ceil))
+    }
+
+    #[test]
+    fn should_calculate_percentage_of() {
+        let expected: Fract8 = Fract8 {
+            numerator: 50,
+            denominator: 1,
+        };
+
+        let actual: Fract8 = Fract8::new(1, 4).percentage_of(&Fract8::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_percentage_of_above_hundred() {
+        let expected: Fract8 = Fract8 {
+            numerator: 150,
+            denominator: 1,
+        };
+
+        let actual: Fract8 = Fract8::new(3, 4).percentage_of(&Fract8::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_fractional_percentage_of() {
+        let expected: Fract8 = Fract8 {
+            numerator: 100,
+            denominator: 3,
+        };
+
+        let actual: Fract8 = Fract8::new(1, 3).percentage_of(&Fract8::new(1, 1));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_create_from_u128() {
+        let expected: Fract8 = Fract8 {
+            numerator: u8::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(Ok(expected), Fract8::try_from_u128(u8::MAX as u128))
+    }
+
+    #[test]
+    fn should_not_create_from_out_of_range_u128() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract8::try_from_u128(u8::MAX as u128 + 1)
+        )
+    }
+
+    #[test]
+    fn should_list_equivalent_forms() {
+        let expected: Vec<Fract8> = vec![
+            Fract8::new(1, 2),
+            Fract8::new(2, 4),
+            Fract8::new(3, 6),
+            Fract8::new(4, 8),
+        ];
+
+        assert_eq!(expected, Fract8::new(2, 4).equivalent_forms(8))
+    }
+
+    #[test]
+    fn should_find_no_equivalent_forms_for_zero_denominator() {
+        assert_eq!(
+            Vec::<Fract8>::new(),
+            Fract8::new(3, 0).equivalent_forms(u8::MAX)
+        );
+        assert_eq!(
+            Vec::<Fract8>::new(),
+            Fract8::new(0, 0).equivalent_forms(u8::MAX)
+        )
+    }
+
+    #[test]
+    fn should_not_list_equivalent_forms_below_reduced_denominator() {
+        assert_eq!(Vec::<Fract8>::new(), Fract8::new(2, 6).equivalent_forms(2))
+    }
+
+    #[test]
+    fn should_list_equivalent_forms_up_to_max() {
+        assert_eq!(
+            u8::MAX as usize,
+            Fract8::new(1, 1).equivalent_forms(u8::MAX).len()
+        )
+    }
+
+    #[test]
+    fn should_compare_exact() {
+        assert_eq!(
+            Ordering::Equal,
+            Fract8::new(1, 2).cmp_exact(&Fract8::new(2, 4))
+        );
+        assert_eq!(
+            Ordering::Less,
+            Fract8::new(1, 3).cmp_exact(&Fract8::new(1, 2))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Fract8::new(3, 4).cmp_exact(&Fract8::new(2, 3))
+        )
+    }
+
+    #[test]
+    fn should_calculate_abs_diff_float() {
+        let expected: f32 = 0.25;
+
+        assert_approx_eq!(
+            expected,
+            Fract8::new(1, 2).abs_diff_float(&Fract8::new(3, 4))
+        );
+        assert_approx_eq!(
+            expected,
+            Fract8::new(3, 4).abs_diff_float(&Fract8::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_saturate_huge_float() {
+        let expected: Fract8 = Fract8 {
+            numerator: u8::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract8::saturating_from_float(f32::MAX, 10));
+        assert_eq!(expected, Fract8::saturating_from_float(f32::INFINITY, 10))
+    }
+
+    #[test]
+    fn should_saturate_negative_float() {
+        let expected: Fract8 = Fract8 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract8::saturating_from_float(-2.5, 10));
+        assert_eq!(expected, Fract8::saturating_from_float(f32::NAN, 10))
+    }
+
+    #[test]
+    fn should_approximate_float_in_range() {
+        assert_eq!(Fract8::new(1, 10), Fract8::saturating_from_float(0.1, 100));
+        assert_eq!(
+            Fract8::new(22, 7),
+            Fract8::saturating_from_float(std::f32::consts::PI, 10)
+        );
+        assert_eq!(Fract8::new(5, 2), Fract8::saturating_from_float(2.5, 10))
+    }
+
+    #[test]
+    fn should_keep_numerator_in_range_when_approximating_float() {
+        assert_eq!(
+            Fract8::new(201, 1),
+            Fract8::saturating_from_float(200.7, 10)
+        )
+    }
+
+    #[test]
+    fn should_cross_multiply() {
+        let first: Fract8 = Fract8::new(2, 3);
+        let second: Fract8 = Fract8::new(5, 7);
+
+        assert_eq!((14, 15), first.cross_multiply(&second))
+    }
+
+    #[test]
+    fn should_cross_multiply_without_overflow() {
+        let first: Fract8 = Fract8::new(u8::MAX, 1);
+        let second: Fract8 = Fract8::new(1, u8::MAX);
+
+        assert_eq!(
+            (u8::MAX as u16 * u8::MAX as u16, 1),
+            first.cross_multiply(&second)
+        )
+    }
+
+    #[test]
+    fn should_expand_continued_fraction() {
+        assert_eq!(vec![2, 3, 1, 4], Fract8::new(43, 19).continued_fraction());
+        assert_eq!(vec![0, 2], Fract8::new(1, 2).continued_fraction())
+    }
+
+    #[test]
+    fn should_create_from_continued_fraction() {
+        let expected: Fract8 = Fract8 {
+            numerator: 43,
+            denominator: 19,
+        };
+
+        assert_eq!(expected, Fract8::from_continued_fraction(&[2, 3, 1, 4]))
+    }
+
+    #[test]
+    fn should_create_zero_from_empty_continued_fraction() {
+        let expected: Fract8 = Fract8 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract8::from_continued_fraction(&[]))
+    }
+
+    #[test]
+    fn should_find_empty_stern_brocot_path() {
+        assert_eq!(Vec::<bool>::new(), Fract8::new(1, 1).stern_brocot_path());
+        assert_eq!(Vec::<bool>::new(), Fract8::new(3, 3).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_find_stern_brocot_path() {
+        assert_eq!(
+            vec![false, true, false],
+            Fract8::new(3, 5).stern_brocot_path()
+        );
+        assert_eq!(vec![true, true], Fract8::new(3, 1).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_add_checked() {
+        let expected: Fract8 = Fract8 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract8::new(1, 2).add_checked(&Fract8::new(9, 10))
+        )
+    }
+
+    #[test]
+    fn should_sub_checked() {
+        let expected: Fract8 = Fract8 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract8::new(4, 2).sub_checked(&Fract8::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract8::new(1, 2).sub_checked(&Fract8::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_div_checked() {
+        let expected: Fract8 = Fract8 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract8::new(1, 2).div_checked(&Fract8::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract8::new(1, 2).div_checked(&Fract8::new(0, 10))
+        )
+    }
+
+    #[test]
+    fn should_checked_assign() {
+        let mut value: Fract8 = Fract8::new(1, 2);
+
+        assert_eq!(Ok(()), value.checked_add_assign(Fract8::new(1, 2)));
+        assert_eq!(Fract8::new(2, 2), value);
+        assert_eq!(Ok(()), value.checked_sub_assign(Fract8::new(1, 2)));
+        assert_eq!(Fract8::new(1, 2), value);
+        assert_eq!(Ok(()), value.checked_mul_assign(Fract8::new(3, 1)));
+        assert_eq!(Fract8::new(3, 2), value);
+        assert_eq!(Ok(()), value.checked_div_assign(Fract8::new(3, 1)));
+        assert_eq!(Fract8::new(3, 6), value)
+    }
+
+    #[test]
+    fn should_leave_value_unchanged_on_failed_assign() {
+        let mut value: Fract8 = Fract8::new(u8::MAX, 2);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_add_assign(Fract8::new(1, 2))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_mul_assign(Fract8::new(2, 1))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            value.checked_div_assign(Fract8::new(0, 1))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_sub_assign(Fract8::new(u8::MAX, 1))
+        );
+        assert_eq!(Fract8::new(u8::MAX, 2), value)
+    }
+
+    #[test]
+    fn should_format_reduced() {
+        assert_eq!("1/2", Fract8::new(4, 8).to_string_reduced());
+        assert_eq!("2", Fract8::new(6, 3).to_string_reduced())
+    }
+
+    #[test]
+    fn should_factor_denominator() {
+        assert_eq!(vec![(2, 3)], Fract8::new(3, 8).denominator_factors());
+        assert_eq!(
+            vec![(2, 1), (3, 1)],
+            Fract8::new(1, 6).denominator_factors()
+        );
+        assert_eq!(vec![(3, 2)], Fract8::new(10, 18).denominator_factors())
+    }
+
+    #[test]
+    fn should_factor_whole_denominator() {
+        assert_eq!(
+            Vec::<(u8, u32)>::new(),
+            Fract8::new(4, 2).denominator_factors()
+        )
+    }
+
+    #[test]
+    fn should_terminate_in_base() {
+        assert!(Fract8::new(3, 8).terminates_in_base(10));
+        assert!(Fract8::new(7, 20).terminates_in_base(10));
+        assert!(Fract8::new(1, 3).terminates_in_base(6));
+        assert!(Fract8::new(5, 12).terminates_in_base(6));
+        assert!(Fract8::new(6, 3).terminates_in_base(7))
+    }
+
+    #[test]
+    fn should_not_terminate_in_base() {
+        assert!(!Fract8::new(1, 3).terminates_in_base(10));
+        assert!(!Fract8::new(1, 10).terminates_in_base(2));
+        assert!(!Fract8::new(1, 5).terminates_in_base(6))
+    }
+
+    #[test]
+    fn should_not_terminate_with_zero_denominator() {
+        assert!(!Fract8::new(3, 0).terminates_in_base(10));
+        assert!(!Fract8::new(0, 0).terminates_in_base(2))
+    }
+
+    #[test]
+    fn should_shift_left() {
+        assert_eq!(Fract8::new(1, 1), Fract8::new(1, 2) << 1);
+        assert_eq!(Fract8::new(12, 1), Fract8::new(3, 4) << 4);
+        assert_eq!(Fract8::new(3, 5), Fract8::new(3, 5) << 0)
+    }
+
+    #[test]
+    fn should_shift_zero_left() {
+        assert_eq!(Fract8::new(0, 1), Fract8::new(0, 1) << u8::BITS);
+        assert_eq!(Fract8::new(0, 3), Fract8::new(0, 3) << 2)
+    }
+
+    #[test]
+    fn should_shift_right() {
+        assert_eq!(Fract8::new(1, 4), Fract8::new(1, 2) >> 1);
+        assert_eq!(Fract8::new(3, 2), Fract8::new(12, 1) >> 3);
+        assert_eq!(Fract8::new(0, 3), Fract8::new(0, 3) >> 2)
+    }
+
+    #[test]
+    fn should_parse() {
+        assert_eq!(Ok(Fract8::new(3, 4)), "3/4".parse::<Fract8>());
+        assert_eq!(Ok(Fract8::new(10, 18)), " 10 / 18 ".parse::<Fract8>());
+        assert_eq!(Ok(Fract8::new(5, 1)), "5".parse::<Fract8>())
+    }
+
+    #[test]
+    fn should_not_parse_malformed() {
+        assert_eq!(Err(FractError::InvalidFormat), "a/4".parse::<Fract8>());
+        assert_eq!(Err(FractError::InvalidFormat), "3/".parse::<Fract8>());
+        assert_eq!(Err(FractError::InvalidFormat), "-3/4".parse::<Fract8>());
+        assert_eq!(Err(FractError::InvalidFormat), "1/2/3".parse::<Fract8>());
+        assert_eq!(Err(FractError::ZeroDenominator), "3/0".parse::<Fract8>())
+    }
+
+    #[test]
+    fn should_find_proper_part() {
+        assert_eq!(Fract8::new(1, 2), Fract8::new(7, 2).proper_part());
+        assert_eq!(Fract8::new(0, 1), Fract8::new(4, 2).proper_part());
+        assert_eq!(Fract8::new(5, 9), Fract8::new(10, 18).proper_part())
+    }
+
+    #[test]
+    fn should_not_reduce_zero_over_zero() {
+        let expected: Fract8 = Fract8 {
+            numerator: 0,
+            denominator: 0,
+        };
+
+        assert_eq!(expected, Fract8::new(0, 0).reduce())
+    }
+
+    #[test]
+    fn should_find_integer_scale() {
+        assert_eq!(9, Fract8::new(10, 18).integer_scale());
+        assert_eq!(1, Fract8::new(6, 3).integer_scale())
+    }
+
+    #[test]
+    fn should_find_scaled_numerator() {
+        assert_eq!(5, Fract8::new(10, 18).scaled_numerator());
+        assert_eq!(2, Fract8::new(6, 3).scaled_numerator())
+    }
+
+    #[test]
+    fn should_convert_into_float() {
+        assert_approx_eq!(0.5, f32::from(Fract8::new(1, 2)));
+
+        let actual: f32 = Fract8::new(3, 4).into();
+        assert_approx_eq!(0.75, actual)
+    }
+
+    #[test]
+    fn should_raise_to_float_power() {
+        assert_approx_eq!(1.5, Fract8::new(9, 4).powf(0.5));
+        assert_approx_eq!(0.125, Fract8::new(1, 2).powf(3.0))
+    }
+
+    #[test]
+    fn should_approximate_float_power() {
+        assert_eq!(Fract8::new(3, 2), Fract8::new(9, 4).powf_approx(0.5, 100));
+        assert_eq!(Fract8::new(7, 5), Fract8::new(2, 1).powf_approx(0.5, 10))
+    }
+
+    #[test]
+    fn should_display() {
+        assert_eq!("3/4", format!("{}", Fract8::new(3, 4)));
+        assert_eq!("4/8", format!("{}", Fract8::new(4, 8)));
+        assert_eq!("5", format!("{}", Fract8::new(5, 1)));
+        assert_eq!("0", format!("{}", Fract8::new(0, 1)))
+    }
+
+    #[test]
+    fn should_find_largest_unit_fraction() {
+        assert_eq!(Fract8::new(1, 2), Fract8::new(3, 4).largest_unit_fraction());
+        assert_eq!(Fract8::new(1, 3), Fract8::new(2, 5).largest_unit_fraction());
+        assert_eq!(Fract8::new(1, 4), Fract8::new(2, 8).largest_unit_fraction())
+    }
+
+    #[test]
+    fn should_decompose_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract8::new(1, 2), Fract8::new(1, 4)]),
+            Fract8::new(3, 4).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract8::new(1, 2), Fract8::new(1, 3)]),
+            Fract8::new(5, 6).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract8::new(1, 7)]),
+            Fract8::new(2, 14).egyptian_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_whole_part_from_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract8::new(2, 1), Fract8::new(1, 2)]),
+            Fract8::new(5, 2).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract8::new(3, 1)]),
+            Fract8::new(6, 2).egyptian_fraction()
+        );
+        assert_eq!(Some(vec![]), Fract8::new(0, 4).egyptian_fraction());
+        assert_eq!(None, Fract8::new(1, 0).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_not_decompose_egyptian_fraction_on_overflow() {
+        assert_eq!(None, Fract8::new(5, 121).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_compare_safely() {
+        assert_eq!(
+            Some(Ordering::Less),
+            Fract8::new(1, 3).safe_cmp(&Fract8::new(1, 2))
+        );
+        assert_eq!(
+            Some(Ordering::Equal),
+            Fract8::new(2, 4).safe_cmp(&Fract8::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_compare_zero_denominator() {
+        assert_eq!(None, Fract8::new(1, 0).safe_cmp(&Fract8::new(1, 2)));
+        assert_eq!(None, Fract8::new(1, 2).safe_cmp(&Fract8::new(0, 0)))
+    }
+
+    #[test]
+    fn should_default_to_zero() {
+        assert_eq!(Fract8::new(0, 1), Fract8::default())
+    }
+
+    #[test]
+    fn should_build_with_numerator_and_denominator() {
+        let expected: Fract8 = Fract8 {
+            numerator: 3,
+            denominator: 4,
+        };
+
+        let actual: Fract8 = Fract8::default().with_numerator(3).with_denominator(4);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_build_invalid_with_zero_denominator() {
+        let actual: Fract8 = Fract8::new(3, 4).with_denominator(0);
+
+        assert_eq!(Fract8::new(3, 0), actual);
+        assert_eq!(None, actual.safe_cmp(&Fract8::new(3, 4)))
+    }
+
+    #[test]
+    fn should_parse_with_radix() {
+        let expected: Fract8 = Fract8 {
+            numerator: 255,
+            denominator: 16,
+        };
+
+        assert_eq!(Ok(expected), Fract8::from_str_radix("ff/10", 16));
+        assert_eq!(Ok(Fract8::new(5, 2)), Fract8::from_str_radix("101/10", 2))
+    }
+
+    #[test]
+    fn should_not_parse_invalid_digit_with_radix() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract8::from_str_radix("fg/10", 16)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract8::from_str_radix("12/10", 2)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract8::from_str_radix("1/1", 37)
+        )
+    }
+
+    #[test]
+    fn should_not_parse_overflow() {
+        let too_large: String = format!("{}0", u8::MAX);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            format!("{}/1", too_large).parse::<Fract8>()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            format!("1/{}", too_large).parse::<Fract8>()
+        )
+    }
+
+    #[test]
+    fn should_convert_to_basis_points() {
+        assert_eq!(5000, Fract8::new(1, 2).to_basis_points());
+        assert_eq!(2500, Fract8::new(1, 4).to_basis_points());
+        assert_eq!(3333, Fract8::new(1, 3).to_basis_points());
+        assert_eq!(6667, Fract8::new(2, 3).to_basis_points());
+        assert_eq!(25000, Fract8::new(5, 2).to_basis_points())
+    }
+
+    #[test]
+    fn should_round_half_up_to_basis_points() {
+        assert_eq!(313, Fract8::new(1, 32).to_basis_points());
+        assert_eq!(156, Fract8::new(1, 64).to_basis_points());
+        assert_eq!(1667, Fract8::new(1, 6).to_basis_points())
+    }
+
+    #[test]
+    fn should_convert_exactly_to_float() {
+        assert_eq!(Ok(0.5), Fract8::new(1, 2).to_float_exact());
+        assert_eq!(Ok(0.75), Fract8::new(6, 8).to_float_exact());
+        assert_eq!(Ok(0.0), Fract8::new(0, 3).to_float_exact())
+    }
+
+    #[test]
+    fn should_flag_inexact_float() {
+        assert_eq!(Err(1.0 / 3.0), Fract8::new(1, 3).to_float_exact());
+        assert!(Fract8::new(1, 0).to_float_exact().is_err())
+    }
+
+    #[test]
+    fn should_add_integer() {
+        assert_eq!(Fract8::new(3, 2), Fract8::new(1, 2) + 1);
+        assert_eq!(Fract8::new(3, 2), 1 + Fract8::new(1, 2))
+    }
+
+    #[test]
+    fn should_sub_integer() {
+        assert_eq!(Fract8::new(1, 2), Fract8::new(3, 2) - 1)
+    }
+
+    #[test]
+    fn should_mul_integer() {
+        assert_eq!(Fract8::new(3, 2), Fract8::new(1, 2) * 3);
+        assert_eq!(Fract8::new(3, 2), 3 * Fract8::new(1, 2))
+    }
+
+    #[test]
+    fn should_div_integer() {
+        assert_eq!(Fract8::new(1, 6), Fract8::new(1, 2) / 3)
+    }
+
+    #[test]
+    fn should_return_raw_components() {
+        assert_eq!((2, 4), Fract8::new(2, 4).components());
+        assert_eq!((3, 0), Fract8::new(3, 0).components())
+    }
+
+    #[test]
+    fn should_expand_slice_to_common_denominator() {
+        let fractions: [Fract8; 3] = [Fract8::new(1, 2), Fract8::new(1, 3), Fract8::new(5, 6)];
+        assert_eq!(
+            Ok(vec![
+                Fract8::new(3, 6),
+                Fract8::new(2, 6),
+                Fract8::new(5, 6)
+            ]),
+            Fract8::common_denominator(&fractions)
+        );
+        assert_eq!(Ok(vec![]), Fract8::common_denominator(&[]))
+    }
+
+    #[test]
+    fn should_not_overflow_common_denominator() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract8::common_denominator(&[Fract8::new(1, u8::MAX), Fract8::new(1, u8::MAX - 1)])
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract8::common_denominator(&[Fract8::new(1, 2), Fract8::new(1, 0)])
+        )
+    }
+
+    #[test]
+    fn should_div_rem_fract() {
+        assert_eq!(
+            Some((3, Fract8::new(1, 2))),
+            Fract8::new(7, 2).div_rem_fract(&Fract8::new(1, 1))
+        );
+        assert_eq!(
+            Some((10, Fract8::new(1, 6))),
+            Fract8::new(7, 2).div_rem_fract(&Fract8::new(1, 3))
+        );
+        assert_eq!(
+            Some((0, Fract8::new(1, 4))),
+            Fract8::new(1, 4).div_rem_fract(&Fract8::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_div_rem_fract_by_zero() {
+        assert_eq!(None, Fract8::new(7, 2).div_rem_fract(&Fract8::new(0, 1)))
+    }
+
+    #[test]
+    fn should_reduce_in_place() {
+        let mut fraction: Fract8 = Fract8::new(10, 18);
+        fraction.reduce_mut();
+        assert_eq!(Fract8::new(5, 9), fraction);
+
+        let mut zero: Fract8 = Fract8::new(0, 18);
+        zero.reduce_mut();
+        assert_eq!(Fract8::new(0, 1), zero)
+    }
+
+    #[test]
+    fn should_display_alternate_with_decimal() {
+        let fraction: Fract8 = Fract8::new(3, 4);
+        assert_eq!("3/4", format!("{}", fraction));
+        assert_eq!("3/4 (0.75)", format!("{:#}", fraction));
+        assert_eq!("3 (3)", format!("{:#}", Fract8::new(3, 1)))
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_exact_floats() {
+        assert_eq!(0, Fract8::new(1, 2).float_ulp_error());
+        assert_eq!(0, Fract8::new(3, 8).float_ulp_error());
+        assert_eq!(0, Fract8::new(0, 5).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_correctly_rounded_floats() {
+        assert_eq!(0, Fract8::new(1, 3).float_ulp_error());
+        assert_eq!(0, Fract8::new(2, 7).float_ulp_error())
+    }
+
+    #[test]
+    fn should_find_modular_inverse() {
+        assert_eq!(Some(5), Fract8::new(3, 7).modular_inverse());
+        assert_eq!(Some(3), Fract8::new(7, 10).modular_inverse())
+    }
+
+    #[test]
+    fn should_not_find_modular_inverse() {
+        assert_eq!(None, Fract8::new(4, 8).modular_inverse());
+        assert_eq!(None, Fract8::new(3, 0).modular_inverse())
+    }
+
+    #[test]
+    fn should_round_trip_le_bytes() {
+        let fraction: Fract8 = Fract8::new(3, 250);
+        assert_eq!(fraction, Fract8::from_le_bytes(fraction.to_le_bytes()));
+        assert_eq!(
+            Fract8::new(u8::MAX, 1),
+            Fract8::from_le_bytes(Fract8::new(u8::MAX, 1).to_le_bytes())
+        )
+    }
+
+    #[test]
+    fn should_checked_add_int() {
+        assert_eq!(
+            Some(Fract8::new(3, 2)),
+            Fract8::new(1, 2).checked_add_int(1)
+        );
+        assert_eq!(
+            Some(Fract8::new(7, 1)),
+            Fract8::new(2, 1).checked_add_int(5)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_add_int_on_overflow() {
+        assert_eq!(None, Fract8::new(u8::MAX, 1).checked_add_int(1));
+        assert_eq!(None, Fract8::new(1, 2).checked_add_int(u8::MAX / 2 + 1))
+    }
+
+    #[test]
+    fn should_display_exact_within_bound() {
+        assert_eq!("1/2", Fract8::new(1, 2).display_approx(4));
+        assert_eq!("1/2", Fract8::new(2, 4).display_approx(2))
+    }
+
+    #[test]
+    fn should_display_approx_beyond_bound() {
+        assert_eq!("≈1/2", Fract8::new(127, 255).display_approx(4));
+        assert_eq!("≈1", Fract8::new(99, 100).display_approx(3))
+    }
+
+    #[test]
+    fn should_convert_float_within_iteration_cap() {
+        assert_eq!(
+            Ok(Fract8::new(3, 4)),
+            Fract8::from_float_capped(0.75, 10, 100)
+        );
+        assert_eq!(
+            Ok(Fract8::new(22, 7)),
+            Fract8::from_float_capped(std::f64::consts::PI, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_not_convert_float_past_iteration_cap() {
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract8::from_float_capped(std::f64::consts::PI, 2, 200)
+        );
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract8::from_float_capped(0.5, 1, 10)
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract8::from_float_capped(-0.5, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_sum_reduced() {
+        let halves: Vec<Fract8> = vec![Fract8::new(1, 2); 40];
+        assert_eq!(Some(Fract8::new(20, 1)), Fract8::sum_reduced(halves));
+        assert_eq!(
+            Some(Fract8::new(1, 6)),
+            Fract8::sum_reduced(vec![Fract8::new(1, 10), Fract8::new(1, 15)])
+        );
+        assert_eq!(Some(Fract8::new(0, 1)), Fract8::sum_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_sum_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract8::sum_reduced(vec![Fract8::new(u8::MAX, 1), Fract8::new(1, 1)])
+        );
+        assert_eq!(None, Fract8::sum_reduced(vec![Fract8::new(1, 0)]))
+    }
+
+    #[test]
+    fn should_product_reduced() {
+        let pairs: Vec<Fract8> = [Fract8::new(1, 2), Fract8::new(4, 2)].repeat(50);
+        assert_eq!(Some(Fract8::new(1, 1)), Fract8::product_reduced(pairs));
+        assert_eq!(
+            Some(Fract8::new(2, 5)),
+            Fract8::product_reduced(vec![Fract8::new(4, 6), Fract8::new(3, 5)])
+        );
+        assert_eq!(Some(Fract8::new(1, 1)), Fract8::product_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_product_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract8::product_reduced(vec![Fract8::new(u8::MAX, 1), Fract8::new(2, 1)])
+        )
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_terminating_fraction() {
+        assert_eq!(1, Fract8::new(1, 8).decimal_digit_at(1));
+        assert_eq!(2, Fract8::new(1, 8).decimal_digit_at(2));
+        assert_eq!(5, Fract8::new(1, 8).decimal_digit_at(3));
+        assert_eq!(0, Fract8::new(1, 8).decimal_digit_at(4));
+        assert_eq!(2, Fract8::new(25, 2).decimal_digit_at(0))
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_repeating_fraction() {
+        assert_eq!(3, Fract8::new(1, 3).decimal_digit_at(1));
+        assert_eq!(3, Fract8::new(1, 3).decimal_digit_at(50));
+        assert_eq!(7, Fract8::new(1, 7).decimal_digit_at(6));
+        assert_eq!(1, Fract8::new(1, 7).decimal_digit_at(7))
+    }
+
+    #[test]
+    fn should_reduce_checked() {
+        assert_eq!(
+            (Fract8::new(5, 9), true),
+            Fract8::new(10, 18).reduce_checked()
+        );
+        assert_eq!(
+            (Fract8::new(5, 9), false),
+            Fract8::new(5, 9).reduce_checked()
+        );
+        assert_eq!(
+            (Fract8::new(0, 1), true),
+            Fract8::new(0, 5).reduce_checked()
+        )
+    }
+
+    #[test]
+    fn should_create_from_bool() {
+        assert_eq!(Fract8::new(1, 1), Fract8::from(true));
+        assert_eq!(Fract8::new(0, 1), Fract8::from(false))
+    }
+
+    #[test]
+    fn should_scale() {
+        assert_eq!(Some(Fract8::new(3, 6)), Fract8::new(1, 2).scale(3));
+        assert_eq!(Some(Fract8::new(0, 0)), Fract8::new(1, 2).scale(0))
+    }
+
+    #[test]
+    fn should_not_scale_on_overflow() {
+        assert_eq!(None, Fract8::new(1, u8::MAX / 2 + 1).scale(2));
+        assert_eq!(None, Fract8::new(u8::MAX, 1).scale(2))
+    }
+
+    #[test]
+    fn should_format_sig_figs() {
+        assert_eq!("0.3333", Fract8::new(1, 3).to_sig_figs(4));
+        assert_eq!("3.14", Fract8::new(22, 7).to_sig_figs(3));
+        assert_eq!("0.667", Fract8::new(2, 3).to_sig_figs(3));
+        assert_eq!("", Fract8::new(2, 0).to_sig_figs(3))
+    }
+
+    #[test]
+    fn should_debug_fields() {
+        assert_eq!(
+            "Fract8 { numerator: 10, denominator: 18 }",
+            format!("{:?}", Fract8::new(10, 18))
+        )
+    }
+
+    #[test]
+    fn should_debug_alternate_with_reduced_value() {
+        assert_eq!(
+            "Fract8 { numerator: 1, denominator: 2 }",
+            format!("{:?}", Fract8::new(1, 2))
+        );
+        assert_eq!(
+            "Fract8 { numerator: 10, denominator: 20, reduced: 1/2, value: 0.5 }",
+            format!("{:#?}", Fract8::new(10, 20))
+        )
+    }
+
+    #[test]
+    fn should_clamp_to_one() {
+        assert_eq!(Fract8::new(1, 1), Fract8::new(3, 2).clamp01());
+        assert_eq!(Fract8::new(1, 2), Fract8::new(1, 2).clamp01());
+        assert_eq!(Fract8::new(4, 4), Fract8::new(4, 4).clamp01())
+    }
+
+    #[test]
+    fn should_find_period_length() {
+        assert_eq!(Some(0), Fract8::new(1, 2).period_length());
+        assert_eq!(Some(0), Fract8::new(3, 40).period_length());
+        assert_eq!(Some(1), Fract8::new(1, 3).period_length());
+        assert_eq!(Some(6), Fract8::new(1, 7).period_length());
+        assert_eq!(Some(6), Fract8::new(1, 14).period_length());
+        assert_eq!(Some(2), Fract8::new(1, 11).period_length());
+        assert_eq!(Some(0), Fract8::new(7, 7).period_length())
+    }
+
+    #[test]
+    fn should_mul_with_cross_cancellation() {
+        assert_eq!(
+            Fract8::new(1, 1),
+            Fract8::new(u8::MAX, 3) * Fract8::new(3, u8::MAX)
+        );
+        assert_eq!(Fract8::new(2, 1), Fract8::new(100, 9) * Fract8::new(9, 50))
+    }
+
+    #[test]
+    fn should_div_with_cross_cancellation() {
+        assert_eq!(
+            Fract8::new(2, 1),
+            Fract8::new(u8::MAX, 2) / Fract8::new(u8::MAX, 4)
+        );
+        assert_eq!(Fract8::new(2, 3), Fract8::new(200, 3) / Fract8::new(100, 1));
+        assert_eq!(Fract8::new(1, 4), Fract8::new(4, 6) / Fract8::new(8, 3))
+    }
+
+    #[test]
+    fn should_validate() {
+        assert_eq!(Ok(()), Fract8::new(1, 2).validate());
+        assert_eq!(Ok(()), Fract8::new(0, 3).validate())
+    }
+
+    #[test]
+    fn should_not_validate_zero_denominator() {
+        let fraction: Fract8 = Fract8 {
+            numerator: 1,
+            denominator: 0,
+        };
+
+        assert_eq!(Err(FractError::ZeroDenominator), fraction.validate())
+    }
+
+    #[test]
+    fn should_find_rounded_decimal_digits() {
+        assert_eq!(
+            vec![3, 3, 3, 3],
+            Fract8::new(1, 3).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![6, 7], Fract8::new(2, 3).decimal_digits_rounded(2));
+        assert_eq!(
+            vec![1, 2, 5, 0],
+            Fract8::new(1, 8).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![1, 3], Fract8::new(1, 8).decimal_digits_rounded(2))
+    }
+
+    #[test]
+    fn should_drop_carry_of_rounded_decimal_digits() {
+        assert_eq!(vec![0, 0], Fract8::new(199, 200).decimal_digits_rounded(2));
+        assert_eq!(
+            Vec::<u8>::new(),
+            Fract8::new(2, 3).decimal_digits_rounded(0)
+        )
+    }
+
+    #[test]
+    fn should_reduce_fast_paths() {
+        assert_eq!(Fract8::new(1, 9), Fract8::new(1, 9).reduce());
+        assert_eq!(Fract8::new(9, 1), Fract8::new(9, 1).reduce());
+        assert_eq!(Fract8::new(0, 1), Fract8::new(0, 9).reduce());
+        assert_eq!(Fract8::new(0, 0), Fract8::new(0, 0).reduce());
+        assert_eq!(Fract8::new(1, 0), Fract8::new(1, 0).reduce())
+    }
+
+    #[test]
+    fn should_hash_consistently_across_widths() {
+        fn hash_of<T: Hash>(value: T) -> u64 {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash_of(Fract8::new(1, 2)), hash_of(Fract8::new(1, 2)));
+        assert_eq!(hash_of(Fract8::new(1, 2)), hash_of(Fract8::new(2, 4)));
+        assert_ne!(hash_of(Fract8::new(1, 2)), hash_of(Fract8::new(1, 3)))
+    }
+
+    #[test]
+    fn should_find_minimal_form() {
+        assert_eq!(Fract8::new(1, 2), Fract8::new(50, 100).minimal());
+        assert_eq!(Fract8::new(0, 1), Fract8::new(0, 7).minimal());
+        assert_eq!(Fract8::new(3, 4), Fract8::new(3, 4).minimal())
+    }
+
+    #[test]
+    fn should_checked_shl() {
+        assert_eq!(Some(Fract8::new(3, 1)), Fract8::new(3, 4).checked_shl(2));
+        assert_eq!(Some(Fract8::new(12, 1)), Fract8::new(3, 1).checked_shl(2));
+        assert_eq!(Some(Fract8::new(0, 5)), Fract8::new(0, 5).checked_shl(200))
+    }
+
+    #[test]
+    fn should_not_checked_shl_on_overflow() {
+        assert_eq!(None, Fract8::new(u8::MAX / 2 + 1, 1).checked_shl(1));
+        assert_eq!(None, Fract8::new(1, 1).checked_shl(u8::BITS))
+    }
+
+    #[test]
+    fn should_checked_shr() {
+        assert_eq!(Some(Fract8::new(3, 1)), Fract8::new(12, 1).checked_shr(2));
+        assert_eq!(Some(Fract8::new(3, 4)), Fract8::new(3, 1).checked_shr(2))
+    }
+
+    #[test]
+    fn should_not_checked_shr_on_overflow() {
+        assert_eq!(None, Fract8::new(1, u8::MAX / 2 + 1).checked_shr(1))
+    }
+
+    #[test]
+    fn should_reduce_keeping_zero_denominator() {
+        assert_eq!(
+            Fract8::new(0, 6),
+            Fract8::new(0, 6).reduce_keep_denominator()
+        );
+        assert_eq!(
+            Fract8::new(5, 9),
+            Fract8::new(10, 18).reduce_keep_denominator()
+        )
+    }
+
+    #[test]
+    fn should_format_mixed_string() {
+        assert_eq!("3 1/2", Fract8::new(7, 2).to_mixed_string());
+        assert_eq!("1/2", Fract8::new(1, 2).to_mixed_string());
+        assert_eq!("2", Fract8::new(6, 3).to_mixed_string());
+        assert_eq!("1 1/3", Fract8::new(8, 6).to_mixed_string());
+        assert_eq!("0", Fract8::new(0, 4).to_mixed_string())
+    }
+
+    #[test]
+    fn should_find_ratio() {
+        assert_eq!(
+            Some(Fract8::new(3, 2)),
+            Fract8::new(3, 4).ratio_to(&Fract8::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract8::new(1, 1)),
+            Fract8::new(6, 8).ratio_to(&Fract8::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_not_find_ratio_to_zero() {
+        assert_eq!(None, Fract8::new(3, 4).ratio_to(&Fract8::new(0, 1)))
+    }
+
+    #[test]
+    fn should_be_between() {
+        assert!(Fract8::new(1, 2).is_between(&Fract8::new(0, 1), &Fract8::new(1, 1)));
+        assert!(Fract8::new(1, 2).is_between(&Fract8::new(1, 1), &Fract8::new(0, 1)));
+        assert!(Fract8::new(2, 4).is_between(&Fract8::new(1, 2), &Fract8::new(3, 4)))
+    }
+
+    #[test]
+    fn should_not_be_between() {
+        assert!(!Fract8::new(1, 2).is_between(&Fract8::new(3, 4), &Fract8::new(1, 1)))
+    }
+
+    #[test]
+    fn should_find_convergents() {
+        let convergents: Vec<Fract8> = Fract8::new(43, 19).convergents();
+        assert_eq!(Fract8::new(2, 1), convergents[0]);
+        assert_eq!(Fract8::new(7, 3), convergents[1]);
+        assert_eq!(
+            vec![
+                Fract8::new(2, 1),
+                Fract8::new(7, 3),
+                Fract8::new(9, 4),
+                Fract8::new(43, 19)
+            ],
+            convergents
+        );
+        assert_eq!(
+            Some(&Fract8::new(43, 19)),
+            Fract8::new(86, 38).convergents().last()
+        )
+    }
+
+    #[test]
+    fn should_find_convergents_of_edge_values() {
+        assert_eq!(
+            vec![Fract8::new(0, 1), Fract8::new(1, 3)],
+            Fract8::new(1, 3).convergents()
+        );
+        assert_eq!(
+            vec![Fract8::new(u8::MAX, 1)],
+            Fract8::new(u8::MAX, 1).convergents()
+        );
+        assert_eq!(Vec::<Fract8>::new(), Fract8::new(1, 0).convergents())
+    }
+
+    #[test]
+    fn should_cmp_with_float_exactly() {
+        assert_eq!(Ordering::Equal, Fract8::new(1, 2).cmp_with_float(0.5));
+        assert_eq!(Ordering::Less, Fract8::new(1, 2).cmp_with_float(0.75));
+        assert_eq!(Ordering::Greater, Fract8::new(3, 2).cmp_with_float(1.25))
+    }
+
+    #[test]
+    fn should_cmp_with_float_next_to_one_third() {
+        let third: Fract8 = Fract8::new(1, 3);
+        let nearest: f64 = (1.0f32 / 3.0) as f64;
+        assert_eq!(nearest, third.to_float() as f64);
+        assert_eq!(Ordering::Less, third.cmp_with_float(nearest))
+    }
+
+    #[test]
+    fn should_split_integer_and_fraction() {
+        assert_eq!(
+            (Fract8::new(3, 1), Fract8::new(1, 2)),
+            Fract8::new(7, 2).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract8::new(2, 1), Fract8::new(0, 1)),
+            Fract8::new(6, 3).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract8::new(0, 1), Fract8::new(2, 3)),
+            Fract8::new(4, 6).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_zero_denominator_into_itself() {
+        assert_eq!(
+            (Fract8::new(1, 0), Fract8::new(0, 1)),
+            Fract8::new(1, 0).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_snap_to_closest() {
+        let allowed: [Fract8; 3] = [Fract8::new(1, 2), Fract8::new(3, 4), Fract8::new(1, 1)];
+        assert_eq!(
+            Some(Fract8::new(1, 2)),
+            Fract8::new(9, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract8::new(3, 4)),
+            Fract8::new(11, 16).snap_to(&allowed)
+        );
+        assert_eq!(Some(Fract8::new(1, 1)), Fract8::new(3, 2).snap_to(&allowed))
+    }
+
+    #[test]
+    fn should_snap_ties_to_first() {
+        let allowed: [Fract8; 2] = [Fract8::new(1, 2), Fract8::new(3, 4)];
+        assert_eq!(Some(Fract8::new(1, 2)), Fract8::new(5, 8).snap_to(&allowed));
+        let reversed: [Fract8; 2] = [Fract8::new(3, 4), Fract8::new(1, 2)];
+        assert_eq!(
+            Some(Fract8::new(3, 4)),
+            Fract8::new(5, 8).snap_to(&reversed)
+        )
+    }
+
+    #[test]
+    fn should_not_snap_to_empty_slice() {
+        assert_eq!(None, Fract8::new(5, 8).snap_to(&[]))
+    }
+
+    #[test]
+    fn should_try_from_signed_pair() {
+        assert_eq!(Ok(Fract8::new(2, 3)), Fract8::try_from((10i64, 15i64)));
+        assert_eq!(Ok(Fract8::new(0, 1)), Fract8::try_from((0i64, 7i64)))
+    }
+
+    #[test]
+    fn should_not_try_from_negative_pair() {
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract8::try_from((-1i64, 2i64))
+        );
+        assert_eq!(
+            Err(FractError::DenominatorUnderflow),
+            Fract8::try_from((1i64, -2i64))
+        )
+    }
+
+    #[test]
+    fn should_not_try_from_signed_zero_denominator() {
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract8::try_from((1i64, 0i64))
+        )
+    }
+
+    #[test]
+    fn should_not_try_from_oversized_signed_pair() {
+        assert_eq!(Ok(Fract8::new(1, 2)), Fract8::try_from((1000i64, 2000i64)));
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract8::try_from((1000i64, 3i64))
+        )
+    }
+
+    #[test]
+    fn should_render_in_radix() {
+        assert_eq!("0.1", Fract8::new(1, 2).to_string_radix(2, 10));
+        assert_eq!("0.1", Fract8::new(1, 3).to_string_radix(3, 10));
+        assert_eq!("0.0101", Fract8::new(1, 3).to_string_radix(2, 4));
+        assert_eq!("12", Fract8::new(10, 2).to_string_radix(3, 4))
+    }
+
+    #[test]
+    fn should_format_continued_fraction() {
+        assert_eq!(
+            "[2; 3, 1, 4]",
+            Fract8::new(43, 19).continued_fraction_string()
+        );
+        assert_eq!("[0; 2]", Fract8::new(1, 2).continued_fraction_string());
+        assert_eq!("[5]", Fract8::new(5, 1).continued_fraction_string())
+    }
+
+    #[test]
+    fn should_find_reduced_reciprocal() {
+        assert_eq!(
+            Some(Fract8::new(9, 5)),
+            Fract8::new(10, 18).reciprocal_reduced()
+        );
+        assert_eq!(
+            Some(Fract8::new(1, 3)),
+            Fract8::new(3, 1).reciprocal_reduced()
+        )
+    }
+
+    #[test]
+    fn should_not_find_reciprocal_of_zero() {
+        assert_eq!(None, Fract8::new(0, 5).reciprocal_reduced())
+    }
+
+    #[test]
+    fn should_find_cross_difference() {
+        assert_eq!(-1, Fract8::new(1, 2).cross_difference(&Fract8::new(2, 3)));
+        assert_eq!(1, Fract8::new(2, 3).cross_difference(&Fract8::new(1, 2)));
+        assert_eq!(0, Fract8::new(2, 4).cross_difference(&Fract8::new(1, 2)));
+        assert_eq!(
+            u8::MAX as i128,
+            Fract8::new(u8::MAX, 1).cross_difference(&Fract8::new(0, 1))
+        )
+    }
+
+    #[test]
+    fn should_find_simplest_between() {
+        assert_eq!(
+            Some(Fract8::new(2, 5)),
+            Fract8::new(1, 3).simplest_between(&Fract8::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract8::new(2, 5)),
+            Fract8::new(1, 2).simplest_between(&Fract8::new(1, 3))
+        );
+        assert_eq!(
+            Some(Fract8::new(3, 7)),
+            Fract8::new(5, 12).simplest_between(&Fract8::new(4, 9))
+        )
+    }
+
+    #[test]
+    fn should_find_integer_between() {
+        assert_eq!(
+            Some(Fract8::new(2, 1)),
+            Fract8::new(3, 2).simplest_between(&Fract8::new(7, 2))
+        );
+        assert_eq!(
+            Some(Fract8::new(1, 1)),
+            Fract8::new(0, 1).simplest_between(&Fract8::new(3, 1))
+        )
+    }
+
+    #[test]
+    fn should_return_reduced_value_between_equal_bounds() {
+        assert_eq!(
+            Some(Fract8::new(1, 2)),
+            Fract8::new(2, 4).simplest_between(&Fract8::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_find_simplest_between_beyond_width() {
+        assert_eq!(
+            None,
+            Fract8::new(1, u8::MAX).simplest_between(&Fract8::new(1, u8::MAX - 1))
+        );
+        assert_eq!(
+            None,
+            Fract8::new(u8::MAX - 1, u8::MAX).simplest_between(&Fract8::new(1, 1))
+        )
+    }
+
+    #[test]
+    fn should_find_nearest_over_without_overflow() {
+        assert_eq!(
+            Fract8::new(199, 200),
+            Fract8::new(200, 201).nearest_over(200)
+        )
+    }
+}
+
+// Fract16
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct Fract16 {
+    pub numerator: u16,
+    pub denominator: u16,
+}
+
+impl Fract<u16, Fract16, f32> for Fract16 {
+    #[inline]
+    fn to_float(&self) -> f32 {
+        self.numerator as f32 / self.denominator as f32
+    }
+
+    #[inline]
+    fn new(numerator: u16, denominator: u16) -> Fract16 {
+        Fract16 {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn invert(&self) -> Fract16 {
+        Fract16 {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+
+    #[inline]
+    fn expand(&self, multiplicator: u16) -> Fract16 {
+        Fract16 {
+            numerator: self.numerator * multiplicator,
+            denominator: self.denominator * multiplicator,
+        }
+    }
+
+    #[inline]
+    fn reduce(&self) -> Fract16 {
+        // already reduced forms skip the gcd loop
+        if self.numerator == 1 || self.denominator == 1 {
+            return *self;
+        }
+        if self.numerator == 0 && self.denominator != 0 {
+            return Fract16 {
+                numerator: 0,
+                denominator: 1,
+            };
+        }
+
+        match utils::checked_gcd_u16(self.numerator, self.denominator) {
+            Some(gcd) => Fract16 {
+                numerator: self.numerator / gcd,
+                denominator: self.denominator / gcd,
+            },
+            None => *self,
+        }
+    }
+
+    #[inline]
+    fn invert_checked(&self) -> Result<Fract16, FractError> {
+        if self.numerator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(self.invert())
+    }
+
+    #[inline]
+    fn nearest_over(&self, denominator: u16) -> Fract16 {
+        let whole: u16 = self.numerator / self.denominator;
+        let remainder: u16 = self.numerator % self.denominator;
+        let scaled: u32 = remainder as u32 * denominator as u32;
+        let quotient: u32 = scaled / self.denominator as u32;
+        let rest: u32 = scaled % self.denominator as u32;
+        // ties round up; comparing against the complement avoids doubling `rest`
+        let rounded: u16 = quotient as u16 + (rest >= self.denominator as u32 - rest) as u16;
+
+        Fract16 {
+            numerator: whole * denominator + rounded,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn mul_checked(&self, other: &Fract16) -> Result<Fract16, FractError> {
+        let numerator: u16 = self
+            .numerator
+            .checked_mul(other.numerator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let denominator: u16 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+
+        Ok(Fract16 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn round_with(&self, mode: RoundingMode) -> u16 {
+        let whole: u16 = self.numerator / self.denominator;
+        let remainder: u16 = self.numerator % self.denominator;
+        if remainder == 0 {
+            return whole;
+        }
+
+        // compares the remainder against half the denominator without overflow
+        let half: Ordering = remainder.cmp(&(self.denominator - remainder));
+        let round_up: bool = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => match half {
+                Ordering::Less => false,
+                Ordering::Equal => whole % 2 == 1,
+                Ordering::Greater => true,
+            },
+        };
+
+        if round_up {
+            whole + 1
+        } else {
+            whole
+        }
+    }
+
+    #[inline]
+    fn percentage_of(&self, whole: &Fract16) -> Fract16 {
+        let ratio: Fract16 = (*self / *whole).reduce();
+        let gcd: u16 = utils::gcd_u16(100, ratio.denominator);
+        Fract16 {
+            numerator: ratio.numerator * (100 / gcd),
+            denominator: ratio.denominator / gcd,
+        }
+    }
+
+    #[inline]
+    fn try_from_u128(value: u128) -> Result<Fract16, FractError> {
+        let numerator: u16 = u16::try_from(value).map_err(|_| FractError::NumeratorOverflow)?;
+        Ok(Fract16::from(numerator))
+    }
+
+    #[inline]
+    fn equivalent_forms(&self, max_denominator: u16) -> Vec<Fract16> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let reduced: Fract16 = self.reduce();
+        let mut forms: Vec<Fract16> = Vec::new();
+        let mut factor: u16 = 1;
+        while let (Some(numerator), Some(denominator)) = (
+            reduced.numerator.checked_mul(factor),
+            reduced.denominator.checked_mul(factor),
+        ) {
+            if denominator > max_denominator {
+                break;
+            }
+
+            forms.push(Fract16 {
+                numerator,
+                denominator,
+            });
+            factor = match factor.checked_add(1) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        forms
+    }
+
+    #[inline]
+    fn cmp_exact(&self, other: &Fract16) -> Ordering {
+        let (lhs, rhs) = self.cross_multiply(other);
+
+        lhs.cmp(&rhs)
+    }
+
+    #[inline]
+    fn abs_diff_float(&self, other: &Fract16) -> f32 {
+        (self.to_float() - other.to_float()).abs()
+    }
+
+    #[inline]
+    fn saturating_from_float(value: f32, max_denominator: u16) -> Fract16 {
+        if value.is_nan() || value <= 0.0 {
+            return Fract16::from(0);
+        }
+
+        if value >= u16::MAX as f32 {
+            return Fract16::from(u16::MAX);
+        }
+
+        let (numerator, denominator) = utils::approximate_f64(
+            value as f64,
+            u16::MAX as u128,
+            max_denominator.max(1) as u128,
+        );
+        Fract16 {
+            numerator: numerator as u16,
+            denominator: denominator as u16,
+        }
+    }
+
+    #[inline]
+    fn continued_fraction(&self) -> Vec<u16> {
+        let mut coefficients: Vec<u16> = Vec::new();
+        let mut numerator: u16 = self.numerator;
+        let mut denominator: u16 = self.denominator;
+        while denominator != 0 {
+            coefficients.push(numerator / denominator);
+            let remainder: u16 = numerator % denominator;
+            numerator = denominator;
+            denominator = remainder;
+        }
+
+        coefficients
+    }
+
+    #[inline]
+    fn from_continued_fraction(coefficients: &[u16]) -> Fract16 {
+        let (last, rest) = match coefficients.split_last() {
+            Some(split) => split,
+            None => return Fract16::from(0),
+        };
+
+        rest.iter()
+            .rev()
+            .fold(Fract16::from(*last), |value, coefficient| Fract16 {
+                numerator: coefficient * value.numerator + value.denominator,
+                denominator: value.numerator,
+            })
+    }
+
+    #[inline]
+    fn stern_brocot_path(&self) -> Vec<bool> {
+        let reduced: Fract16 = self.reduce();
+        if reduced.numerator == 0 || reduced.denominator == 0 {
+            return Vec::new();
+        }
+
+        let coefficients: Vec<u16> = reduced.continued_fraction();
+        let last: usize = coefficients.len() - 1;
+        let mut path: Vec<bool> = Vec::new();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            let steps: u16 = if index == last {
+                coefficient - 1
+            } else {
+                *coefficient
+            };
+
+            for _ in 0..steps {
+                path.push(index % 2 == 0);
+            }
+        }
+
+        path
+    }
+
+    #[inline]
+    fn add_checked(&self, other: &Fract16) -> Result<Fract16, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract16 {
+            numerator: lhs.checked_add(rhs).ok_or(FractError::NumeratorOverflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn sub_checked(&self, other: &Fract16) -> Result<Fract16, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract16 {
+            numerator: lhs.checked_sub(rhs).ok_or(FractError::NumeratorUnderflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn div_checked(&self, other: &Fract16) -> Result<Fract16, FractError> {
+        self.mul_checked(&other.invert_checked()?)
+    }
+
+    #[inline]
+    fn checked_add_assign(&mut self, other: Fract16) -> Result<(), FractError> {
+        *self = self.add_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_sub_assign(&mut self, other: Fract16) -> Result<(), FractError> {
+        *self = self.sub_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_mul_assign(&mut self, other: Fract16) -> Result<(), FractError> {
+        *self = self.mul_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_div_assign(&mut self, other: Fract16) -> Result<(), FractError> {
+        *self = self.div_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn to_string_reduced(&self) -> String {
+        let reduced: Fract16 = self.reduce();
+        if reduced.denominator == 1 {
+            reduced.numerator.to_string()
+        } else {
+            format!("{}/{}", reduced.numerator, reduced.denominator)
+        }
+    }
+
+    #[inline]
+    fn denominator_factors(&self) -> Vec<(u16, u32)> {
+        let mut remaining: u16 = self.reduce().denominator;
+        let mut factors: Vec<(u16, u32)> = Vec::new();
+        let mut divisor: u16 = 2;
+        while remaining > 1 && divisor <= remaining / divisor {
+            let mut exponent: u32 = 0;
+            while remaining.is_multiple_of(divisor) {
+                remaining /= divisor;
+                exponent += 1;
+            }
+
+            if exponent > 0 {
+                factors.push((divisor, exponent));
+            }
+            divisor += 1;
+        }
+
+        if remaining > 1 {
+            factors.push((remaining, 1));
+        }
+
+        factors
+    }
+
+    #[inline]
+    fn terminates_in_base(&self, base: u16) -> bool {
+        let mut denominator: u16 = self.reduce().denominator;
+        if denominator == 0 {
+            return false;
+        }
+        if base < 2 {
+            return denominator == 1;
+        }
+
+        // strips every prime factor shared with the base
+        loop {
+            let gcd: u16 = utils::gcd_u16(denominator, base);
+            if gcd == 1 {
+                return denominator == 1;
+            }
+            denominator /= gcd;
+        }
+    }
+
+    #[inline]
+    fn proper_part(&self) -> Fract16 {
+        Fract16 {
+            numerator: self.numerator % self.denominator,
+            denominator: self.denominator,
+        }
+        .reduce()
+    }
+
+    #[inline]
+    fn integer_scale(&self) -> u16 {
+        self.reduce().denominator
+    }
+
+    #[inline]
+    fn scaled_numerator(&self) -> u16 {
+        self.reduce().numerator
+    }
+
+    #[inline]
+    fn powf(&self, exp: f64) -> f64 {
+        (self.numerator as f64 / self.denominator as f64).powf(exp)
+    }
+
+    #[inline]
+    fn powf_approx(&self, exp: f64, max_denominator: u16) -> Fract16 {
+        Fract16::saturating_from_float(self.powf(exp) as f32, max_denominator)
+    }
+
+    #[inline]
+    fn largest_unit_fraction(&self) -> Fract16 {
+        let mut denominator: u16 = self.denominator / self.numerator;
+        if !self.denominator.is_multiple_of(self.numerator) {
+            denominator += 1;
+        }
+
+        Fract16 {
+            numerator: 1,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn egyptian_fraction(&self) -> Option<Vec<Fract16>> {
+        if self.denominator == 0 {
+            return None;
+        }
+
+        let mut units: Vec<Fract16> = Vec::new();
+        let whole: u16 = self.numerator / self.denominator;
+        if whole != 0 {
+            units.push(Fract16::from(whole));
+        }
+
+        let mut remaining: Fract16 = self.proper_part();
+        while remaining.numerator != 0 {
+            let unit: Fract16 = remaining.largest_unit_fraction();
+            units.push(unit);
+            remaining = remaining.sub_checked(&unit).ok()?.reduce();
+        }
+
+        Some(units)
+    }
+
+    #[inline]
+    fn safe_cmp(&self, other: &Fract16) -> Option<Ordering> {
+        if self.denominator == 0 || other.denominator == 0 {
+            return None;
+        }
+
+        Some(self.cmp_exact(other))
+    }
+
+    #[inline]
+    fn with_numerator(&self, numerator: u16) -> Fract16 {
+        Fract16 {
+            numerator,
+            denominator: self.denominator,
+        }
+    }
+
+    #[inline]
+    fn with_denominator(&self, denominator: u16) -> Fract16 {
+        Fract16 {
+            numerator: self.numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Fract16, FractError> {
+        let (numerator, denominator) = match s.trim().split_once('/') {
+            Some((numerator, denominator)) => (numerator.trim(), denominator.trim()),
+            None => (s.trim(), "1"),
+        };
+
+        if !(2..=36).contains(&radix)
+            || !utils::is_digits_radix(numerator, radix)
+            || !utils::is_digits_radix(denominator, radix)
+        {
+            return Err(FractError::InvalidFormat);
+        }
+
+        let numerator: u16 =
+            u16::from_str_radix(numerator, radix).map_err(|_| FractError::NumeratorOverflow)?;
+        let denominator: u16 =
+            u16::from_str_radix(denominator, radix).map_err(|_| FractError::DenominatorOverflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(Fract16 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn to_basis_points(&self) -> u32 {
+        let denominator: u128 = self.denominator as u128;
+        let mut basis_points: u128 = (self.numerator as u128 / denominator).saturating_mul(10000);
+        let mut remainder: u128 = self.numerator as u128 % denominator;
+        for scale in &[1000, 100, 10, 1] {
+            let (digit, next) = utils::next_digit_u128(remainder, denominator, 10);
+            basis_points = basis_points.saturating_add(digit as u128 * scale);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, denominator, 10).0 >= 5 {
+            basis_points = basis_points.saturating_add(1);
+        }
+
+        u32::try_from(basis_points).unwrap_or(u32::MAX)
+    }
+
+    #[inline]
+    fn to_float_exact(&self) -> Result<f32, f32> {
+        let reduced: Fract16 = self.reduce();
+        let value: f32 = reduced.to_float();
+        if reduced.denominator == 0 || !reduced.denominator.is_power_of_two() {
+            return Err(value);
+        }
+        if reduced.numerator == 0 {
+            return Ok(value);
+        }
+
+        let significant: u16 = reduced.numerator >> reduced.numerator.trailing_zeros();
+        if u16::BITS - significant.leading_zeros() <= f32::MANTISSA_DIGITS {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+
+    #[inline]
+    fn components(&self) -> (u16, u16) {
+        (self.numerator, self.denominator)
+    }
+
+    #[inline]
+    fn div_rem_fract(&self, other: &Fract16) -> Option<(u16, Fract16)> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        let (lhs, rhs, denominator) = self
+            .reduce()
+            .expand_to_common_checked(&other.reduce())
+            .ok()?;
+
+        Some((lhs / rhs, Fract16::new(lhs % rhs, denominator).reduce()))
+    }
+
+    #[inline]
+    fn reduce_mut(&mut self) {
+        *self = self.reduce();
+    }
+
+    #[inline]
+    fn float_ulp_error(&self) -> u64 {
+        if self.denominator == 0 {
+            return 0;
+        }
+
+        utils::ulp_error(
+            self.to_float().to_bits() as u64,
+            f32::MANTISSA_DIGITS - 1,
+            150,
+            self.numerator as u128,
+            self.denominator as u128,
+        )
+    }
+
+    #[inline]
+    fn modular_inverse(&self) -> Option<u16> {
+        utils::inverse_mod_u128(self.numerator as u128, self.denominator as u128)
+            .map(|inverse| inverse as u16)
+    }
+
+    #[inline]
+    fn checked_add_int(&self, value: u16) -> Option<Fract16> {
+        self.add_checked(&Fract16::from(value)).ok()
+    }
+
+    #[inline]
+    fn display_approx(&self, max_denominator: u16) -> String {
+        let reduced: Fract16 = self.reduce();
+        if reduced.denominator <= max_denominator {
+            return reduced.to_string();
+        }
+
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            reduced.numerator as u128,
+            reduced.denominator as u128,
+            u16::MAX as u128,
+            max_denominator.max(1) as u128,
+        );
+
+        format!("≈{}", Fract16::new(numerator as u16, denominator as u16))
+    }
+
+    #[inline]
+    fn from_float_capped(
+        value: f64,
+        max_iterations: u32,
+        max_denominator: u16,
+    ) -> Result<Fract16, FractError> {
+        if value.is_nan() {
+            return Err(FractError::InvalidFormat);
+        }
+        if value < 0.0 {
+            return Err(FractError::NumeratorUnderflow);
+        }
+        if value >= u16::MAX as f64 + 1.0 {
+            return Err(FractError::NumeratorOverflow);
+        }
+
+        let (numerator, denominator) = utils::approximate_f64_capped(
+            value,
+            u16::MAX as u128,
+            max_denominator.max(1) as u128,
+            max_iterations,
+        )
+        .map_err(|_| FractError::IterationLimit)?;
+
+        Ok(Fract16::new(numerator as u16, denominator as u16))
+    }
+
+    #[inline]
+    fn decimal_digit_at(&self, position: u32) -> u8 {
+        if self.denominator == 0 {
+            return 0;
+        }
+        if position == 0 {
+            return (self.numerator / self.denominator % 10) as u8;
+        }
+
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        let mut digit: u32 = 0;
+        for _ in 0..position {
+            let (next_digit, next_remainder) =
+                utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digit = next_digit;
+            remainder = next_remainder;
+        }
+
+        digit as u8
+    }
+
+    #[inline]
+    fn reduce_checked(&self) -> (Fract16, bool) {
+        let reduced: Fract16 = self.reduce();
+
+        (reduced, reduced != *self)
+    }
+
+    #[inline]
+    fn scale(&self, factor: u16) -> Option<Fract16> {
+        Some(Fract16 {
+            numerator: self.numerator.checked_mul(factor)?,
+            denominator: self.denominator.checked_mul(factor)?,
+        })
+    }
+
+    #[inline]
+    fn to_sig_figs(&self, sig_figs: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::sig_figs_u128(self.numerator as u128, self.denominator as u128, sig_figs)
+    }
+
+    #[inline]
+    fn clamp01(&self) -> Fract16 {
+        if self.numerator > self.denominator {
+            Fract16::new(1, 1)
+        } else {
+            *self
+        }
+    }
+
+    #[inline]
+    fn period_length(&self) -> Option<u64> {
+        let mut modulus: u16 = self.reduce().denominator;
+        if modulus == 0 {
+            return Some(0);
+        }
+        while modulus.is_multiple_of(2) {
+            modulus /= 2;
+        }
+        while modulus.is_multiple_of(5) {
+            modulus /= 5;
+        }
+        if modulus == 1 {
+            return Some(0);
+        }
+
+        u64::try_from(utils::decimal_period_u128(modulus as u128)).ok()
+    }
+
+    #[inline]
+    fn validate(&self) -> Result<(), FractError> {
+        if self.denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn decimal_digits_rounded(&self, count: usize) -> Vec<u8> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let mut digits: Vec<u8> = Vec::with_capacity(count);
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        for _ in 0..count {
+            let (digit, next) = utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digits.push(digit as u8);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, self.denominator as u128, 10).0 >= 5 {
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    break;
+                }
+            }
+        }
+
+        digits
+    }
+
+    #[inline]
+    fn minimal(&self) -> Fract16 {
+        self.reduce()
+    }
+
+    #[inline]
+    fn checked_shl(&self, n: u32) -> Option<Fract16> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.denominator.trailing_zeros().min(n);
+        Some(Fract16 {
+            numerator: self
+                .numerator
+                .checked_mul(u16::checked_pow(2, n - cancelled)?)?,
+            denominator: self.denominator.checked_shr(cancelled)?,
+        })
+    }
+
+    #[inline]
+    fn checked_shr(&self, n: u32) -> Option<Fract16> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.numerator.trailing_zeros().min(n);
+        Some(Fract16 {
+            numerator: self.numerator >> cancelled,
+            denominator: self
+                .denominator
+                .checked_mul(u16::checked_pow(2, n - cancelled)?)?,
+        })
+    }
+
+    #[inline]
+    fn reduce_keep_denominator(&self) -> Fract16 {
+        if self.numerator == 0 {
+            return *self;
+        }
+
+        self.reduce()
+    }
+
+    #[inline]
+    fn to_mixed_string(&self) -> String {
+        if self.denominator == 0 {
+            return self.to_string();
+        }
+
+        let whole: u16 = self.numerator / self.denominator;
+        let proper: Fract16 = self.proper_part();
+        if proper.numerator == 0 {
+            whole.to_string()
+        } else if whole == 0 {
+            proper.to_string()
+        } else {
+            format!("{} {}", whole, proper)
+        }
+    }
+
+    #[inline]
+    fn ratio_to(&self, other: &Fract16) -> Option<Fract16> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        Fract16::product_reduced([*self, other.invert()])
+    }
+
+    #[inline]
+    fn is_between(&self, low: &Fract16, high: &Fract16) -> bool {
+        let (low, high) = if low.cmp_exact(high) == Ordering::Greater {
+            (high, low)
+        } else {
+            (low, high)
+        };
+
+        self.cmp_exact(low) != Ordering::Less && self.cmp_exact(high) != Ordering::Greater
+    }
+
+    #[inline]
+    fn convergents(&self) -> Vec<Fract16> {
+        let (mut p0, mut q0, mut p1, mut q1): (u16, u16, u16, u16) = (0, 1, 1, 0);
+        self.continued_fraction()
+            .into_iter()
+            .map(|coefficient| {
+                let (p2, q2) = (coefficient * p1 + p0, coefficient * q1 + q0);
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+                Fract16::new(p2, q2)
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn cmp_with_float(&self, value: f64) -> Ordering {
+        utils::cmp_ratio_f64(self.numerator as u128, self.denominator as u128, value)
+    }
+
+    #[inline]
+    fn integer_and_fraction(&self) -> (Fract16, Fract16) {
+        if self.denominator == 0 {
+            return (*self, Fract16::new(0, 1));
+        }
+
+        (
+            Fract16::from(self.numerator / self.denominator),
+            self.proper_part(),
+        )
+    }
+
+    #[inline]
+    fn snap_to(&self, allowed: &[Fract16]) -> Option<Fract16> {
+        let distance = |other: &Fract16| {
+            utils::distance_u128(
+                self.numerator as u128,
+                self.denominator as u128,
+                other.numerator as u128,
+                other.denominator as u128,
+            )
+        };
+
+        let mut best: Option<(Fract16, Option<(u128, u128)>)> = None;
+        for candidate in allowed {
+            let candidate_distance: Option<(u128, u128)> = distance(candidate);
+            let closer: bool = match best {
+                None => true,
+                Some((current, current_distance)) => match (candidate_distance, current_distance) {
+                    (Some(lhs), Some(rhs)) => {
+                        utils::cmp_ratio_u128(lhs.0, lhs.1, rhs.0, rhs.1) == Ordering::Less
+                    }
+                    _ => self.abs_diff_float(candidate) < self.abs_diff_float(&current),
+                },
+            };
+            if closer {
+                best = Some((*candidate, candidate_distance));
+            }
+        }
+
+        best.map(|(fraction, _)| fraction)
+    }
+
+    #[inline]
+    fn to_string_radix(&self, radix: u32, max_digits: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::radix_string_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            radix,
+            max_digits,
+        )
+    }
+
+    #[inline]
+    fn continued_fraction_string(&self) -> String {
+        let coefficients: Vec<String> = self
+            .continued_fraction()
+            .iter()
+            .map(|coefficient| coefficient.to_string())
+            .collect();
+
+        match coefficients.split_first() {
+            None => String::from("[]"),
+            Some((first, [])) => format!("[{}]", first),
+            Some((first, rest)) => format!("[{}; {}]", first, rest.join(", ")),
+        }
+    }
+
+    #[inline]
+    fn reciprocal_reduced(&self) -> Option<Fract16> {
+        if self.numerator == 0 {
+            return None;
+        }
+
+        Some(self.invert().reduce())
+    }
+
+    #[inline]
+    fn cross_difference(&self, other: &Fract16) -> i128 {
+        utils::cross_difference_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            other.numerator as u128,
+            other.denominator as u128,
+        )
+    }
+
+    #[inline]
+    fn simplest_between(&self, other: &Fract16) -> Option<Fract16> {
+        let (low, high) = match self.cmp_exact(other) {
+            Ordering::Equal => return Some(self.reduce()),
+            Ordering::Less => (self, other),
+            Ordering::Greater => (other, self),
+        };
+
+        let (numerator, denominator) = utils::simplest_between_u128(
+            low.numerator as u128,
+            low.denominator as u128,
+            high.numerator as u128,
+            high.denominator as u128,
+        )?;
+
+        Some(Fract16::new(
+            u16::try_from(numerator).ok()?,
+            u16::try_from(denominator).ok()?,
+        ))
+    }
+}
+
+impl From<u16> for Fract16 {
+    #[inline]
+    fn from(input: u16) -> Self {
+        Fract16 {
+            numerator: input,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract16 = self;
+        let mut nrhs: Fract16 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u16 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract16 {
+            numerator: nlhs.numerator + nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Sub for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract16 = self;
+        let mut nrhs: Fract16 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u16 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract16 {
+            numerator: nlhs.numerator - nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Mul for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cancels common factors crosswise before multiplying to keep the products small
+        let first: u16 = utils::gcd_u16(self.numerator, rhs.denominator).max(1);
+        let second: u16 = utils::gcd_u16(rhs.numerator, self.denominator).max(1);
+
+        Fract16 {
+            numerator: (self.numerator / first) * (rhs.numerator / second),
+            denominator: (self.denominator / second) * (rhs.denominator / first),
+        }
+    }
+}
+
+impl Div for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.invert()
+    }
+}
+
+impl Widen for Fract16 {
+    type Wider = Fract32;
+
+    #[inline]
+    fn widen(self) -> Fract32 {
+        Fract32 {
+            numerator: self.numerator as u32,
+            denominator: self.denominator as u32,
+        }
+    }
+}
+
+impl PartialOrd for Fract16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fract16 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_exact(other)
+            .then(self.numerator.cmp(&other.numerator))
+            .then(self.denominator.cmp(&other.denominator))
+    }
+}
+
+impl Fract16 {
+    #[inline]
+    pub fn cross_multiply(&self, other: &Fract16) -> (u32, u32) {
+        (
+            self.numerator as u32 * other.denominator as u32,
+            other.numerator as u32 * self.denominator as u32,
+        )
+    }
+
+    #[inline]
+    fn expand_to_common_checked(&self, other: &Fract16) -> Result<(u16, u16, u16), FractError> {
+        if self.denominator == other.denominator {
+            return Ok((self.numerator, other.numerator, self.denominator));
+        }
+
+        let denominator: u16 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+        let lhs: u16 = self
+            .numerator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let rhs: u16 = other
+            .numerator
+            .checked_mul(self.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+
+        Ok((lhs, rhs, denominator))
+    }
+
+    #[inline]
+    pub fn to_fract8_approx(&self) -> Fract8 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            u8::MAX as u128,
+            u8::MAX as u128,
+        );
+
+        Fract8 {
+            numerator: numerator as u8,
+            denominator: denominator as u8,
+        }
+    }
+
+    pub fn common_denominator(fractions: &[Fract16]) -> Result<Vec<Fract16>, FractError> {
+        let mut denominator: u16 = 1;
+        for fraction in fractions {
+            if fraction.denominator == 0 {
+                return Err(FractError::ZeroDenominator);
+            }
+            denominator = utils::checked_lcm_u16(denominator, fraction.denominator)
+                .ok_or(FractError::DenominatorOverflow)?;
+        }
+
+        fractions
+            .iter()
+            .map(|fraction| {
+                fraction
+                    .numerator
+                    .checked_mul(denominator / fraction.denominator)
+                    .map(|numerator| Fract16::new(numerator, denominator))
+                    .ok_or(FractError::NumeratorOverflow)
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub fn reduce_into_fract8(&self) -> Result<Fract8, FractError> {
+        Fract8::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn to_le_bytes(&self) -> [u8; 4] {
+        let mut bytes: [u8; 4] = [0; 4];
+        let (numerator, denominator) = bytes.split_at_mut(2);
+        numerator.copy_from_slice(&self.numerator.to_le_bytes());
+        denominator.copy_from_slice(&self.denominator.to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 4]) -> Fract16 {
+        let mut numerator: [u8; 2] = [0; 2];
+        let mut denominator: [u8; 2] = [0; 2];
+        numerator.copy_from_slice(&bytes[..2]);
+        denominator.copy_from_slice(&bytes[2..]);
+
+        Fract16 {
+            numerator: u16::from_le_bytes(numerator),
+            denominator: u16::from_le_bytes(denominator),
+        }
+    }
+
+    /// Sums over common denominators, reducing after every step; `None` once an
+    /// intermediate value overflows or a denominator is zero.
+    pub fn sum_reduced<I: IntoIterator<Item = Fract16>>(fractions: I) -> Option<Fract16> {
+        fractions
+            .into_iter()
+            .try_fold(Fract16::new(0, 1), |sum, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract16 = fraction.reduce();
+                let denominator: u16 =
+                    utils::checked_lcm_u16(sum.denominator, fraction.denominator)?;
+                let numerator: u16 = sum
+                    .numerator
+                    .checked_mul(denominator / sum.denominator)?
+                    .checked_add(
+                        fraction
+                            .numerator
+                            .checked_mul(denominator / fraction.denominator)?,
+                    )?;
+
+                Some(Fract16::new(numerator, denominator).reduce())
+            })
+    }
+
+    /// Multiplies with cross-cancellation so the running product stays reduced; `None` on
+    /// overflow or a zero denominator.
+    pub fn product_reduced<I: IntoIterator<Item = Fract16>>(fractions: I) -> Option<Fract16> {
+        fractions
+            .into_iter()
+            .try_fold(Fract16::new(1, 1), |product, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract16 = fraction.reduce();
+                let first: u16 = utils::gcd_u16(product.numerator, fraction.denominator).max(1);
+                let second: u16 = utils::gcd_u16(fraction.numerator, product.denominator).max(1);
+
+                Some(Fract16::new(
+                    (product.numerator / first).checked_mul(fraction.numerator / second)?,
+                    (product.denominator / second).checked_mul(fraction.denominator / first)?,
+                ))
+            })
+    }
+
+    #[inline]
+    pub fn fits_in_fract8(&self) -> bool {
+        self.reduce_into_fract8().is_ok()
+    }
+}
+
+impl Shl<u32> for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the denominator before growing the numerator
+        let cancelled: u32 = self.denominator.trailing_zeros().min(rhs);
+        Fract16 {
+            numerator: self.numerator * (1 << (rhs - cancelled)),
+            denominator: self.denominator >> cancelled,
+        }
+    }
+}
+
+impl Shr<u32> for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the numerator before growing the denominator
+        let cancelled: u32 = self.numerator.trailing_zeros().min(rhs);
+        Fract16 {
+            numerator: self.numerator >> cancelled,
+            denominator: self.denominator * (1 << (rhs - cancelled)),
+        }
+    }
+}
+
+impl FromStr for Fract16 {
+    type Err = FractError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Fract16::from_str_radix(s, 10)
+    }
+}
+
+impl From<Fract16> for f32 {
+    #[inline]
+    fn from(input: Fract16) -> Self {
+        input.to_float()
+    }
+}
+
+impl fmt::Display for Fract16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)?;
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)?;
+        }
+
+        if f.alternate() {
+            write!(f, " ({})", self.to_float())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Fract16 {
+    #[inline]
+    fn default() -> Self {
+        Fract16 {
+            numerator: 0,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add<u16> for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn add(self, rhs: u16) -> Self::Output {
+        self + Fract16::from(rhs)
+    }
+}
+
+impl Sub<u16> for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn sub(self, rhs: u16) -> Self::Output {
+        self - Fract16::from(rhs)
+    }
+}
+
+impl Mul<u16> for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn mul(self, rhs: u16) -> Self::Output {
+        self * Fract16::from(rhs)
+    }
+}
+
+impl Div<u16> for Fract16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn div(self, rhs: u16) -> Self::Output {
+        self / Fract16::from(rhs)
+    }
+}
+
+impl Add<Fract16> for u16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn add(self, rhs: Fract16) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Mul<Fract16> for u16 {
+    type Output = Fract16;
+
+    #[inline]
+    fn mul(self, rhs: Fract16) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl TryFrom<Fract16> for Fract8 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract16) -> Result<Self, Self::Error> {
+        Ok(Fract8 {
+            numerator: u8::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u8::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl From<bool> for Fract16 {
+    #[inline]
+    fn from(input: bool) -> Self {
+        Fract16 {
+            numerator: input as u16,
+            denominator: 1,
+        }
+    }
+}
+
+impl fmt::Debug for Fract16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "Fract16 {{ numerator: {}, denominator: {}, reduced: {}, value: {} }}",
+                self.numerator,
+                self.denominator,
+                self.reduce(),
+                self.to_float()
+            )
+        } else {
+            f.debug_struct("Fract16")
+                .field("numerator", &self.numerator)
+                .field("denominator", &self.denominator)
+                .finish()
+        }
+    }
+}
+
+impl Hash for Fract16 {
+    // hashes the reduced value as u128 so equal values match across widths
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let reduced: Fract16 = self.reduce();
+        (reduced.numerator as u128).hash(state);
+        (reduced.denominator as u128).hash(state);
+    }
+}
+
+impl TryFrom<(i64, i64)> for Fract16 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from((numerator, denominator): (i64, i64)) -> Result<Self, Self::Error> {
+        let numerator: u64 =
+            u64::try_from(numerator).map_err(|_| FractError::NumeratorUnderflow)?;
+        let denominator: u64 =
+            u64::try_from(denominator).map_err(|_| FractError::DenominatorUnderflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        let divisor: u64 = utils::gcd_u64(numerator, denominator);
+        Ok(Fract16 {
+            numerator: u16::try_from(numerator / divisor)
+                .map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u16::try_from(denominator / divisor)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+#[cfg(test)]
+mod tests_fract16 {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::convert::TryFrom;
+    use std::hash::{Hash, Hasher};
+
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::{Fract, Fract16, Fract32, Fract8, FractError, RoundingMode, Widen};
+
+    #[test]
+    fn should_create() {
+        let expected: Fract16 = Fract16 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let actual: Fract16 = Fract16::new(8, 10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_invert() {
+        let expected: Fract16 = Fract16 {
+            numerator: 10,
+            denominator: 8,
+        };
+
+        let actual: Fract16 = Fract16::new(8, 10).invert();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_expand() {
+        let expected: Fract16 = Fract16 {
+            numerator: 80,
+            denominator: 100,
+        };
+
+        let actual: Fract16 = Fract16::new(8, 10).expand(10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_convert() {
+        let expected: f32 = 0.8;
+        let actual: f32 = Fract16::new(8, 10).to_float();
+
+        assert_approx_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_add() {
+        let expected: Fract16 = Fract16 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        let first: Fract16 = Fract16::new(1, 2);
+        let second: Fract16 = Fract16::new(9, 10);
+        let result: Fract16 = first + second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_sub() {
+        let expected: Fract16 = Fract16 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        let first: Fract16 = Fract16::new(4, 2);
+        let second: Fract16 = Fract16::new(9, 10);
+        let result: Fract16 = first - second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_mul() {
+        let expected: Fract16 = Fract16 {
+            numerator: 4,
+            denominator: 5,
+        };
+
+        let first: Fract16 = Fract16::new(2, 5);
+        let second: Fract16 = Fract16::new(4, 2);
+        let result: Fract16 = first * second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_div() {
+        let expected: Fract16 = Fract16 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let first: Fract16 = Fract16::new(1, 2);
+        let second: Fract16 = Fract16::new(9, 10);
+        let result: Fract16 = first / second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_reduce() {
+        let expected: Fract16 = Fract16 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let value: Fract16 = Fract16 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(expected, value.reduce())
+    }
+
+    #[test]
+    fn should_invert_checked() {
+        let expected: Fract16 = Fract16 {
+            numerator: 3,
+            denominator: 2,
+        };
+
+        let actual: Result<Fract16, FractError> = Fract16::new(2, 3).invert_checked();
+
+        assert_eq!(Ok(expected), actual)
+    }
+
+    #[test]
+    fn should_not_invert_zero() {
+        let actual: Result<Fract16, FractError> = Fract16::new(0, 5).invert_checked();
+
+        assert_eq!(Err(FractError::ZeroDenominator), actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over() {
+        let expected: Fract16 = Fract16 {
+            numerator: 8,
+            denominator: 12,
+        };
+
+        let actual: Fract16 = Fract16::new(5, 8).nearest_over(12);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_improper() {
+        let expected: Fract16 = Fract16 {
+            numerator: 7,
+            denominator: 3,
+        };
+
+        let actual: Fract16 = Fract16::new(7, 3).nearest_over(3);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_round_tie_up_in_nearest_over() {
+        let expected: Fract16 = Fract16 {
+            numerator: 1,
+            denominator: 2,
+        };
+
+        let actual: Fract16 = Fract16::new(1, 4).nearest_over(2);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_near_max() {
+        assert_eq!(
+            Fract16::new(u16::MAX - 2, u16::MAX - 1),
+            Fract16::new(u16::MAX - 1, u16::MAX).nearest_over(u16::MAX - 1)
+        )
+    }
+
+    #[test]
+    fn should_widen() {
+        let expected: Fract32 = Fract32 {
+            numerator: u16::MAX as u32,
+            denominator: 10,
+        };
+
+        let actual: Fract32 = Fract16::new(u16::MAX, 10).widen();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_mul_checked() {
+        let expected: Fract16 = Fract16 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let first: Fract16 = Fract16::new(2, 5);
+        let second: Fract16 = Fract16::new(4, 2);
+
+        assert_eq!(Ok(expected), first.mul_checked(&second))
+    }
+
+    #[test]
+    fn should_report_numerator_overflow_in_mul_checked() {
+        let first: Fract16 = Fract16::new(u16::MAX, 1);
+        let second: Fract16 = Fract16::new(2, 3);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_report_denominator_overflow_in_mul_checked() {
+        let first: Fract16 = Fract16::new(1, u16::MAX);
+        let second: Fract16 = Fract16::new(1, 2);
+
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_order_by_value() {
+        let smaller: Fract16 = Fract16::new(1, 3);
+        let larger: Fract16 = Fract16::new(2, 5);
+
+        assert!(smaller < larger);
+        assert!(larger > smaller)
+    }
+
+    #[test]
+    fn should_order_equal_values_by_representation() {
+        let reduced: Fract16 = Fract16::new(1, 2);
+        let expanded: Fract16 = Fract16::new(2, 4);
+
+        assert!(reduced < expanded);
+        assert!(reduced < Fract16::new(3, 5));
+        assert!(expanded < Fract16::new(3, 5))
+    }
+
+    #[test]
+    fn should_order_large_values() {
+        let smaller: Fract16 = Fract16::new(u16::MAX - 1, u16::MAX);
+        let larger: Fract16 = Fract16::new(u16::MAX, u16::MAX - 1);
+
+        assert!(smaller < larger);
+        assert!(smaller < Fract16::new(1, 1));
+        assert!(Fract16::new(1, 1) < larger)
+    }
+
+    #[test]
+    fn should_round_half_up() {
+        assert_eq!(3, Fract16::new(5, 2).round_with(RoundingMode::HalfUp));
+        assert_eq!(4, Fract16::new(7, 2).round_with(RoundingMode::HalfUp))
+    }
+
+    #[test]
+    fn should_round_half_even() {
+        assert_eq!(2, Fract16::new(5, 2).round_with(RoundingMode::HalfEven));
+        assert_eq!(4, Fract16::new(7, 2).round_with(RoundingMode::HalfEven))
+    }
+
+    #[test]
+    fn should_round_half_down() {
+        assert_eq!(2, Fract16::new(5, 2).round_with(RoundingMode::HalfDown));
+        assert_eq!(3, Fract16::new(8, 3).round_with(RoundingMode::HalfDown))
+    }
+
+    #[test]
+    fn should_round_floor_and_ceil() {
+        assert_eq!(2, Fract16::new(8, 3).round_with(RoundingMode::Floor));
+        assert_eq!(3, Fract16::new(7, 3).round_with(RoundingMode::Ceil));
+        assert_eq!(2, Fract16::new(4, 2).round_with(RoundingMode::Ceil))
+    }
+
+    #[test]
+    fn should_calculate_percentage_of() {
+        let expected: Fract16 = Fract16 {
+            numerator: 50,
+            denominator: 1,
+        };
+
+        let actual: Fract16 = Fract16::new(1, 4).percentage_of(&Fract16::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_percentage_of_above_hundred() {
+        let expected: Fract16 = Fract16 {
+            numerator: 150,
+            denominator: 1,
+        };
+
+        let actual: Fract16 = Fract16::new(3, 4).percentage_of(&Fract16::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_fractional_percentage_of() {
+        let expected: Fract16 = Fract16 {
+            numerator: 100,
+            denominator: 3,
+        };
+
+        let actual: Fract16 = Fract16::new(1, 3).percentage_of(&Fract16::new(1, 1));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_create_from_u128() {
+        let expected: Fract16 = Fract16 {
+            numerator: u16::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(Ok(expected), Fract16::try_from_u128(u16::MAX as u128))
+    }
+
+    #[test]
+    fn should_not_create_from_out_of_range_u128() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract16::try_from_u128(u16::MAX as u128 + 1)
+        )
+    }
+
+    #[test]
+    fn should_list_equivalent_forms() {
+        let expected: Vec<Fract16> = vec![
+            Fract16::new(1, 2),
+            Fract16::new(2, 4),
+            Fract16::new(3, 6),
+            Fract16::new(4, 8),
+        ];
+
+        assert_eq!(expected, Fract16::new(2, 4).equivalent_forms(8))
+    }
+
+    #[test]
+    fn should_find_no_equivalent_forms_for_zero_denominator() {
+        assert_eq!(
+            Vec::<Fract16>::new(),
+            Fract16::new(3, 0).equivalent_forms(u16::MAX)
+        );
+        assert_eq!(
+            Vec::<Fract16>::new(),
+            Fract16::new(0, 0).equivalent_forms(u16::MAX)
+        )
+    }
+
+    #[test]
+    fn should_not_list_equivalent_forms_below_reduced_denominator() {
+        assert_eq!(
+            Vec::<Fract16>::new(),
+            Fract16::new(2, 6).equivalent_forms(2)
+        )
+    }
+
+    #[test]
+    fn should_compare_exact() {
+        assert_eq!(
+            Ordering::Equal,
+            Fract16::new(1, 2).cmp_exact(&Fract16::new(2, 4))
+        );
+        assert_eq!(
+            Ordering::Less,
+            Fract16::new(1, 3).cmp_exact(&Fract16::new(1, 2))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Fract16::new(3, 4).cmp_exact(&Fract16::new(2, 3))
+        )
+    }
+
+    #[test]
+    fn should_calculate_abs_diff_float() {
+        let expected: f32 = 0.25;
+
+        assert_approx_eq!(
+            expected,
+            Fract16::new(1, 2).abs_diff_float(&Fract16::new(3, 4))
+        );
+        assert_approx_eq!(
+            expected,
+            Fract16::new(3, 4).abs_diff_float(&Fract16::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_saturate_huge_float() {
+        let expected: Fract16 = Fract16 {
+            numerator: u16::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract16::saturating_from_float(f32::MAX, 10));
+        assert_eq!(expected, Fract16::saturating_from_float(f32::INFINITY, 10))
+    }
+
+    #[test]
+    fn should_saturate_negative_float() {
+        let expected: Fract16 = Fract16 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract16::saturating_from_float(-2.5, 10));
+        assert_eq!(expected, Fract16::saturating_from_float(f32::NAN, 10))
+    }
+
+    #[test]
+    fn should_approximate_float_in_range() {
+        assert_eq!(
+            Fract16::new(1, 10),
+            Fract16::saturating_from_float(0.1, 100)
+        );
+        assert_eq!(
+            Fract16::new(22, 7),
+            Fract16::saturating_from_float(std::f32::consts::PI, 10)
+        );
+        assert_eq!(Fract16::new(5, 2), Fract16::saturating_from_float(2.5, 10))
+    }
+
+    #[test]
+    fn should_cross_multiply() {
+        let first: Fract16 = Fract16::new(2, 3);
+        let second: Fract16 = Fract16::new(5, 7);
+
+        assert_eq!((14, 15), first.cross_multiply(&second))
+    }
+
+    #[test]
+    fn should_cross_multiply_without_overflow() {
+        let first: Fract16 = Fract16::new(u16::MAX, 1);
+        let second: Fract16 = Fract16::new(1, u16::MAX);
+
+        assert_eq!(
+            (u16::MAX as u32 * u16::MAX as u32, 1),
+            first.cross_multiply(&second)
+        )
+    }
+
+    #[test]
+    fn should_expand_continued_fraction() {
+        assert_eq!(vec![2, 3, 1, 4], Fract16::new(43, 19).continued_fraction());
+        assert_eq!(vec![0, 2], Fract16::new(1, 2).continued_fraction())
+    }
+
+    #[test]
+    fn should_create_from_continued_fraction() {
+        let expected: Fract16 = Fract16 {
+            numerator: 43,
+            denominator: 19,
+        };
+
+        assert_eq!(expected, Fract16::from_continued_fraction(&[2, 3, 1, 4]))
+    }
+
+    #[test]
+    fn should_create_zero_from_empty_continued_fraction() {
+        let expected: Fract16 = Fract16 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract16::from_continued_fraction(&[]))
+    }
+
+    #[test]
+    fn should_find_empty_stern_brocot_path() {
+        assert_eq!(Vec::<bool>::new(), Fract16::new(1, 1).stern_brocot_path());
+        assert_eq!(Vec::<bool>::new(), Fract16::new(3, 3).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_find_stern_brocot_path() {
+        assert_eq!(
+            vec![false, true, false],
+            Fract16::new(3, 5).stern_brocot_path()
+        );
+        assert_eq!(vec![true, true], Fract16::new(3, 1).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_add_checked() {
+        let expected: Fract16 = Fract16 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract16::new(1, 2).add_checked(&Fract16::new(9, 10))
+        )
+    }
+
+    #[test]
+    fn should_sub_checked() {
+        let expected: Fract16 = Fract16 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract16::new(4, 2).sub_checked(&Fract16::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract16::new(1, 2).sub_checked(&Fract16::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_div_checked() {
+        let expected: Fract16 = Fract16 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract16::new(1, 2).div_checked(&Fract16::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract16::new(1, 2).div_checked(&Fract16::new(0, 10))
+        )
+    }
+
+    #[test]
+    fn should_checked_assign() {
+        let mut value: Fract16 = Fract16::new(1, 2);
+
+        assert_eq!(Ok(()), value.checked_add_assign(Fract16::new(1, 2)));
+        assert_eq!(Fract16::new(2, 2), value);
+        assert_eq!(Ok(()), value.checked_sub_assign(Fract16::new(1, 2)));
+        assert_eq!(Fract16::new(1, 2), value);
+        assert_eq!(Ok(()), value.checked_mul_assign(Fract16::new(3, 1)));
+        assert_eq!(Fract16::new(3, 2), value);
+        assert_eq!(Ok(()), value.checked_div_assign(Fract16::new(3, 1)));
+        assert_eq!(Fract16::new(3, 6), value)
+    }
+
+    #[test]
+    fn should_leave_value_unchanged_on_failed_assign() {
+        let mut value: Fract16 = Fract16::new(u16::MAX, 2);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_add_assign(Fract16::new(1, 2))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_mul_assign(Fract16::new(2, 1))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            value.checked_div_assign(Fract16::new(0, 1))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_sub_assign(Fract16::new(u16::MAX, 1))
+        );
+        assert_eq!(Fract16::new(u16::MAX, 2), value)
+    }
+
+    #[test]
+    fn should_format_reduced() {
+        assert_eq!("1/2", Fract16::new(4, 8).to_string_reduced());
+        assert_eq!("2", Fract16::new(6, 3).to_string_reduced())
+    }
+
+    #[test]
+    fn should_factor_denominator() {
+        assert_eq!(vec![(2, 3)], Fract16::new(3, 8).denominator_factors());
+        assert_eq!(
+            vec![(2, 1), (3, 1)],
+            Fract16::new(1, 6).denominator_factors()
+        );
+        assert_eq!(vec![(3, 2)], Fract16::new(10, 18).denominator_factors())
+    }
+
+    #[test]
+    fn should_factor_whole_denominator() {
+        assert_eq!(
+            Vec::<(u16, u32)>::new(),
+            Fract16::new(4, 2).denominator_factors()
+        )
+    }
+
+    #[test]
+    fn should_terminate_in_base() {
+        assert!(Fract16::new(3, 8).terminates_in_base(10));
+        assert!(Fract16::new(7, 20).terminates_in_base(10));
+        assert!(Fract16::new(1, 3).terminates_in_base(6));
+        assert!(Fract16::new(5, 12).terminates_in_base(6));
+        assert!(Fract16::new(6, 3).terminates_in_base(7))
+    }
+
+    #[test]
+    fn should_not_terminate_in_base() {
+        assert!(!Fract16::new(1, 3).terminates_in_base(10));
+        assert!(!Fract16::new(1, 10).terminates_in_base(2));
+        assert!(!Fract16::new(1, 5).terminates_in_base(6))
+    }
+
+    #[test]
+    fn should_not_terminate_with_zero_denominator() {
+        assert!(!Fract16::new(3, 0).terminates_in_base(10));
+        assert!(!Fract16::new(0, 0).terminates_in_base(2))
+    }
+
+    #[test]
+    fn should_shift_left() {
+        assert_eq!(Fract16::new(1, 1), Fract16::new(1, 2) << 1);
+        assert_eq!(Fract16::new(12, 1), Fract16::new(3, 4) << 4);
+        assert_eq!(Fract16::new(3, 5), Fract16::new(3, 5) << 0)
+    }
+
+    #[test]
+    fn should_shift_zero_left() {
+        assert_eq!(Fract16::new(0, 1), Fract16::new(0, 1) << u16::BITS);
+        assert_eq!(Fract16::new(0, 3), Fract16::new(0, 3) << 2)
+    }
+
+    #[test]
+    fn should_shift_right() {
+        assert_eq!(Fract16::new(1, 4), Fract16::new(1, 2) >> 1);
+        assert_eq!(Fract16::new(3, 2), Fract16::new(12, 1) >> 3);
+        assert_eq!(Fract16::new(0, 3), Fract16::new(0, 3) >> 2)
+    }
+
+    #[test]
+    fn should_parse() {
+        assert_eq!(Ok(Fract16::new(3, 4)), "3/4".parse::<Fract16>());
+        assert_eq!(Ok(Fract16::new(10, 18)), " 10 / 18 ".parse::<Fract16>());
+        assert_eq!(Ok(Fract16::new(5, 1)), "5".parse::<Fract16>())
+    }
+
+    #[test]
+    fn should_not_parse_malformed() {
+        assert_eq!(Err(FractError::InvalidFormat), "a/4".parse::<Fract16>());
+        assert_eq!(Err(FractError::InvalidFormat), "3/".parse::<Fract16>());
+        assert_eq!(Err(FractError::InvalidFormat), "-3/4".parse::<Fract16>());
+        assert_eq!(Err(FractError::InvalidFormat), "1/2/3".parse::<Fract16>());
+        assert_eq!(Err(FractError::ZeroDenominator), "3/0".parse::<Fract16>())
+    }
+
+    #[test]
+    fn should_find_proper_part() {
+        assert_eq!(Fract16::new(1, 2), Fract16::new(7, 2).proper_part());
+        assert_eq!(Fract16::new(0, 1), Fract16::new(4, 2).proper_part());
+        assert_eq!(Fract16::new(5, 9), Fract16::new(10, 18).proper_part())
+    }
+
+    #[test]
+    fn should_not_reduce_zero_over_zero() {
+        let expected: Fract16 = Fract16 {
+            numerator: 0,
+            denominator: 0,
+        };
+
+        assert_eq!(expected, Fract16::new(0, 0).reduce())
+    }
+
+    #[test]
+    fn should_find_integer_scale() {
+        assert_eq!(9, Fract16::new(10, 18).integer_scale());
+        assert_eq!(1, Fract16::new(6, 3).integer_scale())
+    }
+
+    #[test]
+    fn should_find_scaled_numerator() {
+        assert_eq!(5, Fract16::new(10, 18).scaled_numerator());
+        assert_eq!(2, Fract16::new(6, 3).scaled_numerator())
+    }
+
+    #[test]
+    fn should_convert_into_float() {
+        assert_approx_eq!(0.5, f32::from(Fract16::new(1, 2)));
+
+        let actual: f32 = Fract16::new(3, 4).into();
+        assert_approx_eq!(0.75, actual)
+    }
+
+    #[test]
+    fn should_raise_to_float_power() {
+        assert_approx_eq!(1.5, Fract16::new(9, 4).powf(0.5));
+        assert_approx_eq!(0.125, Fract16::new(1, 2).powf(3.0))
+    }
+
+    #[test]
+    fn should_approximate_float_power() {
+        assert_eq!(Fract16::new(3, 2), Fract16::new(9, 4).powf_approx(0.5, 100));
+        assert_eq!(Fract16::new(7, 5), Fract16::new(2, 1).powf_approx(0.5, 10))
+    }
+
+    #[test]
+    fn should_approximate_to_fract8() {
+        assert_eq!(
+            Fract8::new(u8::MAX, 2),
+            Fract16::new(u8::MAX as u16 * 2, 4).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(1, 1),
+            Fract16::new(u16::MAX, u16::MAX - 1).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(u8::MAX, 1),
+            Fract16::new(u16::MAX, 1).to_fract8_approx()
+        )
+    }
+
+    #[test]
+    fn should_display() {
+        assert_eq!("3/4", format!("{}", Fract16::new(3, 4)));
+        assert_eq!("4/8", format!("{}", Fract16::new(4, 8)));
+        assert_eq!("5", format!("{}", Fract16::new(5, 1)));
+        assert_eq!("0", format!("{}", Fract16::new(0, 1)))
+    }
+
+    #[test]
+    fn should_find_largest_unit_fraction() {
+        assert_eq!(
+            Fract16::new(1, 2),
+            Fract16::new(3, 4).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract16::new(1, 3),
+            Fract16::new(2, 5).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract16::new(1, 4),
+            Fract16::new(2, 8).largest_unit_fraction()
+        )
+    }
+
+    #[test]
+    fn should_decompose_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract16::new(1, 2), Fract16::new(1, 4)]),
+            Fract16::new(3, 4).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract16::new(1, 2), Fract16::new(1, 3)]),
+            Fract16::new(5, 6).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract16::new(1, 7)]),
+            Fract16::new(2, 14).egyptian_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_whole_part_from_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract16::new(2, 1), Fract16::new(1, 2)]),
+            Fract16::new(5, 2).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract16::new(3, 1)]),
+            Fract16::new(6, 2).egyptian_fraction()
+        );
+        assert_eq!(Some(vec![]), Fract16::new(0, 4).egyptian_fraction());
+        assert_eq!(None, Fract16::new(1, 0).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_not_decompose_egyptian_fraction_on_overflow() {
+        assert_eq!(None, Fract16::new(5, 121).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_compare_safely() {
+        assert_eq!(
+            Some(Ordering::Less),
+            Fract16::new(1, 3).safe_cmp(&Fract16::new(1, 2))
+        );
+        assert_eq!(
+            Some(Ordering::Equal),
+            Fract16::new(2, 4).safe_cmp(&Fract16::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_compare_zero_denominator() {
+        assert_eq!(None, Fract16::new(1, 0).safe_cmp(&Fract16::new(1, 2)));
+        assert_eq!(None, Fract16::new(1, 2).safe_cmp(&Fract16::new(0, 0)))
+    }
+
+    #[test]
+    fn should_default_to_zero() {
+        assert_eq!(Fract16::new(0, 1), Fract16::default())
+    }
+
+    #[test]
+    fn should_build_with_numerator_and_denominator() {
+        let expected: Fract16 = Fract16 {
+            numerator: 3,
+            denominator: 4,
+        };
+
+        let actual: Fract16 = Fract16::default().with_numerator(3).with_denominator(4);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_build_invalid_with_zero_denominator() {
+        let actual: Fract16 = Fract16::new(3, 4).with_denominator(0);
+
+        assert_eq!(Fract16::new(3, 0), actual);
+        assert_eq!(None, actual.safe_cmp(&Fract16::new(3, 4)))
+    }
+
+    #[test]
+    fn should_parse_with_radix() {
+        let expected: Fract16 = Fract16 {
+            numerator: 255,
+            denominator: 16,
+        };
+
+        assert_eq!(Ok(expected), Fract16::from_str_radix("ff/10", 16));
+        assert_eq!(Ok(Fract16::new(5, 2)), Fract16::from_str_radix("101/10", 2))
+    }
+
+    #[test]
+    fn should_not_parse_invalid_digit_with_radix() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract16::from_str_radix("fg/10", 16)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract16::from_str_radix("12/10", 2)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract16::from_str_radix("1/1", 37)
+        )
+    }
+
+    #[test]
+    fn should_not_parse_overflow() {
+        let too_large: String = format!("{}0", u16::MAX);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            format!("{}/1", too_large).parse::<Fract16>()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            format!("1/{}", too_large).parse::<Fract16>()
+        )
+    }
+
+    #[test]
+    fn should_convert_to_basis_points() {
+        assert_eq!(5000, Fract16::new(1, 2).to_basis_points());
+        assert_eq!(2500, Fract16::new(1, 4).to_basis_points());
+        assert_eq!(3333, Fract16::new(1, 3).to_basis_points());
+        assert_eq!(6667, Fract16::new(2, 3).to_basis_points());
+        assert_eq!(25000, Fract16::new(5, 2).to_basis_points())
+    }
+
+    #[test]
+    fn should_round_half_up_to_basis_points() {
+        assert_eq!(313, Fract16::new(1, 32).to_basis_points());
+        assert_eq!(156, Fract16::new(1, 64).to_basis_points());
+        assert_eq!(1667, Fract16::new(1, 6).to_basis_points())
+    }
+
+    #[test]
+    fn should_convert_exactly_to_float() {
+        assert_eq!(Ok(0.5), Fract16::new(1, 2).to_float_exact());
+        assert_eq!(Ok(0.75), Fract16::new(6, 8).to_float_exact());
+        assert_eq!(Ok(0.0), Fract16::new(0, 3).to_float_exact())
+    }
+
+    #[test]
+    fn should_flag_inexact_float() {
+        assert_eq!(Err(1.0 / 3.0), Fract16::new(1, 3).to_float_exact());
+        assert!(Fract16::new(1, 0).to_float_exact().is_err())
+    }
+
+    #[test]
+    fn should_add_integer() {
+        assert_eq!(Fract16::new(3, 2), Fract16::new(1, 2) + 1);
+        assert_eq!(Fract16::new(3, 2), 1 + Fract16::new(1, 2))
+    }
+
+    #[test]
+    fn should_sub_integer() {
+        assert_eq!(Fract16::new(1, 2), Fract16::new(3, 2) - 1)
+    }
+
+    #[test]
+    fn should_mul_integer() {
+        assert_eq!(Fract16::new(3, 2), Fract16::new(1, 2) * 3);
+        assert_eq!(Fract16::new(3, 2), 3 * Fract16::new(1, 2))
+    }
+
+    #[test]
+    fn should_div_integer() {
+        assert_eq!(Fract16::new(1, 6), Fract16::new(1, 2) / 3)
+    }
+
+    #[test]
+    fn should_return_raw_components() {
+        assert_eq!((2, 4), Fract16::new(2, 4).components());
+        assert_eq!((3, 0), Fract16::new(3, 0).components())
+    }
+
+    #[test]
+    fn should_expand_slice_to_common_denominator() {
+        let fractions: [Fract16; 3] = [Fract16::new(1, 2), Fract16::new(1, 3), Fract16::new(5, 6)];
+        assert_eq!(
+            Ok(vec![
+                Fract16::new(3, 6),
+                Fract16::new(2, 6),
+                Fract16::new(5, 6)
+            ]),
+            Fract16::common_denominator(&fractions)
+        );
+        assert_eq!(Ok(vec![]), Fract16::common_denominator(&[]))
+    }
+
+    #[test]
+    fn should_not_overflow_common_denominator() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract16::common_denominator(&[
+                Fract16::new(1, u16::MAX),
+                Fract16::new(1, u16::MAX - 1)
+            ])
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract16::common_denominator(&[Fract16::new(1, 2), Fract16::new(1, 0)])
+        )
+    }
+
+    #[test]
+    fn should_not_overflow_common_numerator() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract16::common_denominator(&[Fract16::new(1, 256), Fract16::new(1, 257)])
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract16::common_denominator(&[Fract16::new(300, 256), Fract16::new(1, 255)])
+        )
+    }
+
+    #[test]
+    fn should_div_rem_fract() {
+        assert_eq!(
+            Some((3, Fract16::new(1, 2))),
+            Fract16::new(7, 2).div_rem_fract(&Fract16::new(1, 1))
+        );
+        assert_eq!(
+            Some((10, Fract16::new(1, 6))),
+            Fract16::new(7, 2).div_rem_fract(&Fract16::new(1, 3))
+        );
+        assert_eq!(
+            Some((0, Fract16::new(1, 4))),
+            Fract16::new(1, 4).div_rem_fract(&Fract16::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_div_rem_fract_by_zero() {
+        assert_eq!(None, Fract16::new(7, 2).div_rem_fract(&Fract16::new(0, 1)))
+    }
+
+    #[test]
+    fn should_reduce_in_place() {
+        let mut fraction: Fract16 = Fract16::new(10, 18);
+        fraction.reduce_mut();
+        assert_eq!(Fract16::new(5, 9), fraction);
+
+        let mut zero: Fract16 = Fract16::new(0, 18);
+        zero.reduce_mut();
+        assert_eq!(Fract16::new(0, 1), zero)
+    }
+
+    #[test]
+    fn should_display_alternate_with_decimal() {
+        let fraction: Fract16 = Fract16::new(3, 4);
+        assert_eq!("3/4", format!("{}", fraction));
+        assert_eq!("3/4 (0.75)", format!("{:#}", fraction));
+        assert_eq!("3 (3)", format!("{:#}", Fract16::new(3, 1)))
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_exact_floats() {
+        assert_eq!(0, Fract16::new(1, 2).float_ulp_error());
+        assert_eq!(0, Fract16::new(3, 8).float_ulp_error());
+        assert_eq!(0, Fract16::new(0, 5).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_correctly_rounded_floats() {
+        assert_eq!(0, Fract16::new(1, 3).float_ulp_error());
+        assert_eq!(0, Fract16::new(2, 7).float_ulp_error())
+    }
+
+    #[test]
+    fn should_reduce_into_fract8() {
+        assert_eq!(
+            Ok(Fract8::new(1, 2)),
+            Fract16::new(100, 200).reduce_into_fract8()
+        );
+        assert_eq!(
+            Ok(Fract8::new(u8::MAX, 1)),
+            Fract16::new(u8::MAX as u16 * 2, 2).reduce_into_fract8()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract8() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract16::new(u8::MAX as u16 + 1, 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract16::new(1, u8::MAX as u16 + 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract8::try_from(Fract16::new(2, u8::MAX as u16 * 2))
+        )
+    }
+
+    #[test]
+    fn should_find_modular_inverse() {
+        assert_eq!(Some(5), Fract16::new(3, 7).modular_inverse());
+        assert_eq!(Some(3), Fract16::new(7, 10).modular_inverse())
+    }
+
+    #[test]
+    fn should_not_find_modular_inverse() {
+        assert_eq!(None, Fract16::new(4, 8).modular_inverse());
+        assert_eq!(None, Fract16::new(3, 0).modular_inverse())
+    }
+
+    #[test]
+    fn should_round_trip_le_bytes() {
+        let fraction: Fract16 = Fract16::new(3, 250);
+        assert_eq!(fraction, Fract16::from_le_bytes(fraction.to_le_bytes()));
+        assert_eq!(
+            Fract16::new(u16::MAX, 1),
+            Fract16::from_le_bytes(Fract16::new(u16::MAX, 1).to_le_bytes())
+        )
+    }
+
+    #[test]
+    fn should_encode_le_bytes() {
+        assert_eq!([0x02, 0x01, 0x07, 0x00], Fract16::new(258, 7).to_le_bytes());
+        assert_eq!(
+            Fract16::new(258, 7),
+            Fract16::from_le_bytes([0x02, 0x01, 0x07, 0x00])
+        )
+    }
+
+    #[test]
+    fn should_checked_add_int() {
+        assert_eq!(
+            Some(Fract16::new(3, 2)),
+            Fract16::new(1, 2).checked_add_int(1)
+        );
+        assert_eq!(
+            Some(Fract16::new(7, 1)),
+            Fract16::new(2, 1).checked_add_int(5)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_add_int_on_overflow() {
+        assert_eq!(None, Fract16::new(u16::MAX, 1).checked_add_int(1));
+        assert_eq!(None, Fract16::new(1, 2).checked_add_int(u16::MAX / 2 + 1))
+    }
+
+    #[test]
+    fn should_display_exact_within_bound() {
+        assert_eq!("1/2", Fract16::new(1, 2).display_approx(4));
+        assert_eq!("1/2", Fract16::new(2, 4).display_approx(2))
+    }
+
+    #[test]
+    fn should_display_approx_beyond_bound() {
+        assert_eq!("≈1/2", Fract16::new(127, 255).display_approx(4));
+        assert_eq!("≈1", Fract16::new(99, 100).display_approx(3))
+    }
+
+    #[test]
+    fn should_convert_float_within_iteration_cap() {
+        assert_eq!(
+            Ok(Fract16::new(3, 4)),
+            Fract16::from_float_capped(0.75, 10, 100)
+        );
+        assert_eq!(
+            Ok(Fract16::new(22, 7)),
+            Fract16::from_float_capped(std::f64::consts::PI, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_not_convert_float_past_iteration_cap() {
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract16::from_float_capped(std::f64::consts::PI, 2, 200)
+        );
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract16::from_float_capped(0.5, 1, 10)
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract16::from_float_capped(-0.5, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_sum_reduced() {
+        let halves: Vec<Fract16> = vec![Fract16::new(1, 2); 40];
+        assert_eq!(Some(Fract16::new(20, 1)), Fract16::sum_reduced(halves));
+        assert_eq!(
+            Some(Fract16::new(1, 6)),
+            Fract16::sum_reduced(vec![Fract16::new(1, 10), Fract16::new(1, 15)])
+        );
+        assert_eq!(Some(Fract16::new(0, 1)), Fract16::sum_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_sum_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract16::sum_reduced(vec![Fract16::new(u16::MAX, 1), Fract16::new(1, 1)])
+        );
+        assert_eq!(None, Fract16::sum_reduced(vec![Fract16::new(1, 0)]))
+    }
+
+    #[test]
+    fn should_product_reduced() {
+        let pairs: Vec<Fract16> = [Fract16::new(1, 2), Fract16::new(4, 2)].repeat(50);
+        assert_eq!(Some(Fract16::new(1, 1)), Fract16::product_reduced(pairs));
+        assert_eq!(
+            Some(Fract16::new(2, 5)),
+            Fract16::product_reduced(vec![Fract16::new(4, 6), Fract16::new(3, 5)])
+        );
+        assert_eq!(Some(Fract16::new(1, 1)), Fract16::product_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_product_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract16::product_reduced(vec![Fract16::new(u16::MAX, 1), Fract16::new(2, 1)])
+        )
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_terminating_fraction() {
+        assert_eq!(1, Fract16::new(1, 8).decimal_digit_at(1));
+        assert_eq!(2, Fract16::new(1, 8).decimal_digit_at(2));
+        assert_eq!(5, Fract16::new(1, 8).decimal_digit_at(3));
+        assert_eq!(0, Fract16::new(1, 8).decimal_digit_at(4));
+        assert_eq!(2, Fract16::new(25, 2).decimal_digit_at(0))
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_repeating_fraction() {
+        assert_eq!(3, Fract16::new(1, 3).decimal_digit_at(1));
+        assert_eq!(3, Fract16::new(1, 3).decimal_digit_at(50));
+        assert_eq!(7, Fract16::new(1, 7).decimal_digit_at(6));
+        assert_eq!(1, Fract16::new(1, 7).decimal_digit_at(7))
+    }
+
+    #[test]
+    fn should_reduce_checked() {
+        assert_eq!(
+            (Fract16::new(5, 9), true),
+            Fract16::new(10, 18).reduce_checked()
+        );
+        assert_eq!(
+            (Fract16::new(5, 9), false),
+            Fract16::new(5, 9).reduce_checked()
+        );
+        assert_eq!(
+            (Fract16::new(0, 1), true),
+            Fract16::new(0, 5).reduce_checked()
+        )
+    }
+
+    #[test]
+    fn should_create_from_bool() {
+        assert_eq!(Fract16::new(1, 1), Fract16::from(true));
+        assert_eq!(Fract16::new(0, 1), Fract16::from(false))
+    }
+
+    #[test]
+    fn should_scale() {
+        assert_eq!(Some(Fract16::new(3, 6)), Fract16::new(1, 2).scale(3));
+        assert_eq!(Some(Fract16::new(0, 0)), Fract16::new(1, 2).scale(0))
+    }
+
+    #[test]
+    fn should_not_scale_on_overflow() {
+        assert_eq!(None, Fract16::new(1, u16::MAX / 2 + 1).scale(2));
+        assert_eq!(None, Fract16::new(u16::MAX, 1).scale(2))
+    }
+
+    #[test]
+    fn should_format_sig_figs() {
+        assert_eq!("0.3333", Fract16::new(1, 3).to_sig_figs(4));
+        assert_eq!("3.14", Fract16::new(22, 7).to_sig_figs(3));
+        assert_eq!("0.667", Fract16::new(2, 3).to_sig_figs(3));
+        assert_eq!("", Fract16::new(2, 0).to_sig_figs(3))
+    }
+
+    #[test]
+    fn should_debug_fields() {
+        assert_eq!(
+            "Fract16 { numerator: 10, denominator: 18 }",
+            format!("{:?}", Fract16::new(10, 18))
+        )
+    }
+
+    #[test]
+    fn should_debug_alternate_with_reduced_value() {
+        assert_eq!(
+            "Fract16 { numerator: 1, denominator: 2 }",
+            format!("{:?}", Fract16::new(1, 2))
+        );
+        assert_eq!(
+            "Fract16 { numerator: 10, denominator: 20, reduced: 1/2, value: 0.5 }",
+            format!("{:#?}", Fract16::new(10, 20))
+        )
+    }
+
+    #[test]
+    fn should_clamp_to_one() {
+        assert_eq!(Fract16::new(1, 1), Fract16::new(3, 2).clamp01());
+        assert_eq!(Fract16::new(1, 2), Fract16::new(1, 2).clamp01());
+        assert_eq!(Fract16::new(4, 4), Fract16::new(4, 4).clamp01())
+    }
+
+    #[test]
+    fn should_find_period_length() {
+        assert_eq!(Some(0), Fract16::new(1, 2).period_length());
+        assert_eq!(Some(0), Fract16::new(3, 40).period_length());
+        assert_eq!(Some(1), Fract16::new(1, 3).period_length());
+        assert_eq!(Some(6), Fract16::new(1, 7).period_length());
+        assert_eq!(Some(6), Fract16::new(1, 14).period_length());
+        assert_eq!(Some(2), Fract16::new(1, 11).period_length());
+        assert_eq!(Some(0), Fract16::new(7, 7).period_length())
+    }
+
+    #[test]
+    fn should_fit_in_fract8() {
+        assert!(Fract16::new(100, 200).fits_in_fract8());
+        assert!(Fract16::new(u8::MAX as u16 * 2, 2).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract8() {
+        assert!(!Fract16::new(u8::MAX as u16 + 1, 1).fits_in_fract8());
+        assert!(!Fract16::new(1, u8::MAX as u16 + 1).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_mul_with_cross_cancellation() {
+        assert_eq!(
+            Fract16::new(1, 1),
+            Fract16::new(u16::MAX, 3) * Fract16::new(3, u16::MAX)
+        );
+        assert_eq!(
+            Fract16::new(2, 1),
+            Fract16::new(100, 9) * Fract16::new(9, 50)
+        )
+    }
+
+    #[test]
+    fn should_div_with_cross_cancellation() {
+        assert_eq!(
+            Fract16::new(2, 1),
+            Fract16::new(u16::MAX, 2) / Fract16::new(u16::MAX, 4)
+        );
+        assert_eq!(
+            Fract16::new(2, 3),
+            Fract16::new(200, 3) / Fract16::new(100, 1)
+        );
+        assert_eq!(Fract16::new(1, 4), Fract16::new(4, 6) / Fract16::new(8, 3))
+    }
+
+    #[test]
+    fn should_validate() {
+        assert_eq!(Ok(()), Fract16::new(1, 2).validate());
+        assert_eq!(Ok(()), Fract16::new(0, 3).validate())
+    }
+
+    #[test]
+    fn should_not_validate_zero_denominator() {
+        let fraction: Fract16 = Fract16 {
+            numerator: 1,
+            denominator: 0,
+        };
+
+        assert_eq!(Err(FractError::ZeroDenominator), fraction.validate())
+    }
+
+    #[test]
+    fn should_find_rounded_decimal_digits() {
+        assert_eq!(
+            vec![3, 3, 3, 3],
+            Fract16::new(1, 3).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![6, 7], Fract16::new(2, 3).decimal_digits_rounded(2));
+        assert_eq!(
+            vec![1, 2, 5, 0],
+            Fract16::new(1, 8).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![1, 3], Fract16::new(1, 8).decimal_digits_rounded(2))
+    }
+
+    #[test]
+    fn should_drop_carry_of_rounded_decimal_digits() {
+        assert_eq!(vec![0, 0], Fract16::new(199, 200).decimal_digits_rounded(2));
+        assert_eq!(
+            Vec::<u8>::new(),
+            Fract16::new(2, 3).decimal_digits_rounded(0)
+        )
+    }
+
+    #[test]
+    fn should_reduce_fast_paths() {
+        assert_eq!(Fract16::new(1, 9), Fract16::new(1, 9).reduce());
+        assert_eq!(Fract16::new(9, 1), Fract16::new(9, 1).reduce());
+        assert_eq!(Fract16::new(0, 1), Fract16::new(0, 9).reduce());
+        assert_eq!(Fract16::new(0, 0), Fract16::new(0, 0).reduce());
+        assert_eq!(Fract16::new(1, 0), Fract16::new(1, 0).reduce())
+    }
+
+    #[test]
+    fn should_hash_consistently_across_widths() {
+        fn hash_of<T: Hash>(value: T) -> u64 {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash_of(Fract8::new(1, 2)), hash_of(Fract16::new(1, 2)));
+        assert_eq!(hash_of(Fract16::new(1, 2)), hash_of(Fract16::new(2, 4)));
+        assert_ne!(hash_of(Fract16::new(1, 2)), hash_of(Fract16::new(1, 3)))
+    }
+
+    #[test]
+    fn should_find_minimal_form() {
+        assert_eq!(Fract16::new(1, 2), Fract16::new(50, 100).minimal());
+        assert_eq!(Fract16::new(0, 1), Fract16::new(0, 7).minimal());
+        assert_eq!(Fract16::new(3, 4), Fract16::new(3, 4).minimal())
+    }
+
+    #[test]
+    fn should_checked_shl() {
+        assert_eq!(Some(Fract16::new(3, 1)), Fract16::new(3, 4).checked_shl(2));
+        assert_eq!(Some(Fract16::new(12, 1)), Fract16::new(3, 1).checked_shl(2));
+        assert_eq!(
+            Some(Fract16::new(0, 5)),
+            Fract16::new(0, 5).checked_shl(200)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_shl_on_overflow() {
+        assert_eq!(None, Fract16::new(u16::MAX / 2 + 1, 1).checked_shl(1));
+        assert_eq!(None, Fract16::new(1, 1).checked_shl(u16::BITS))
+    }
+
+    #[test]
+    fn should_checked_shr() {
+        assert_eq!(Some(Fract16::new(3, 1)), Fract16::new(12, 1).checked_shr(2));
+        assert_eq!(Some(Fract16::new(3, 4)), Fract16::new(3, 1).checked_shr(2))
+    }
+
+    #[test]
+    fn should_not_checked_shr_on_overflow() {
+        assert_eq!(None, Fract16::new(1, u16::MAX / 2 + 1).checked_shr(1))
+    }
+
+    #[test]
+    fn should_reduce_keeping_zero_denominator() {
+        assert_eq!(
+            Fract16::new(0, 6),
+            Fract16::new(0, 6).reduce_keep_denominator()
+        );
+        assert_eq!(
+            Fract16::new(5, 9),
+            Fract16::new(10, 18).reduce_keep_denominator()
+        )
+    }
+
+    #[test]
+    fn should_format_mixed_string() {
+        assert_eq!("3 1/2", Fract16::new(7, 2).to_mixed_string());
+        assert_eq!("1/2", Fract16::new(1, 2).to_mixed_string());
+        assert_eq!("2", Fract16::new(6, 3).to_mixed_string());
+        assert_eq!("1 1/3", Fract16::new(8, 6).to_mixed_string());
+        assert_eq!("0", Fract16::new(0, 4).to_mixed_string())
+    }
+
+    #[test]
+    fn should_find_ratio() {
+        assert_eq!(
+            Some(Fract16::new(3, 2)),
+            Fract16::new(3, 4).ratio_to(&Fract16::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract16::new(1, 1)),
+            Fract16::new(6, 8).ratio_to(&Fract16::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_not_find_ratio_to_zero() {
+        assert_eq!(None, Fract16::new(3, 4).ratio_to(&Fract16::new(0, 1)))
+    }
+
+    #[test]
+    fn should_be_between() {
+        assert!(Fract16::new(1, 2).is_between(&Fract16::new(0, 1), &Fract16::new(1, 1)));
+        assert!(Fract16::new(1, 2).is_between(&Fract16::new(1, 1), &Fract16::new(0, 1)));
+        assert!(Fract16::new(2, 4).is_between(&Fract16::new(1, 2), &Fract16::new(3, 4)))
+    }
+
+    #[test]
+    fn should_not_be_between() {
+        assert!(!Fract16::new(1, 2).is_between(&Fract16::new(3, 4), &Fract16::new(1, 1)))
+    }
+
+    #[test]
+    fn should_find_convergents() {
+        let convergents: Vec<Fract16> = Fract16::new(43, 19).convergents();
+        assert_eq!(Fract16::new(2, 1), convergents[0]);
+        assert_eq!(Fract16::new(7, 3), convergents[1]);
+        assert_eq!(
+            vec![
+                Fract16::new(2, 1),
+                Fract16::new(7, 3),
+                Fract16::new(9, 4),
+                Fract16::new(43, 19)
+            ],
+            convergents
+        );
+        assert_eq!(
+            Some(&Fract16::new(43, 19)),
+            Fract16::new(86, 38).convergents().last()
+        )
+    }
+
+    #[test]
+    fn should_find_convergents_of_edge_values() {
+        assert_eq!(
+            vec![Fract16::new(0, 1), Fract16::new(1, 3)],
+            Fract16::new(1, 3).convergents()
+        );
+        assert_eq!(
+            vec![Fract16::new(u16::MAX, 1)],
+            Fract16::new(u16::MAX, 1).convergents()
+        );
+        assert_eq!(Vec::<Fract16>::new(), Fract16::new(1, 0).convergents())
+    }
+
+    #[test]
+    fn should_cmp_with_float_exactly() {
+        assert_eq!(Ordering::Equal, Fract16::new(1, 2).cmp_with_float(0.5));
+        assert_eq!(Ordering::Less, Fract16::new(1, 2).cmp_with_float(0.75));
+        assert_eq!(Ordering::Greater, Fract16::new(3, 2).cmp_with_float(1.25))
+    }
+
+    #[test]
+    fn should_cmp_with_float_next_to_one_third() {
+        let third: Fract16 = Fract16::new(1, 3);
+        let nearest: f64 = (1.0f32 / 3.0) as f64;
+        assert_eq!(nearest, third.to_float() as f64);
+        assert_eq!(Ordering::Less, third.cmp_with_float(nearest))
+    }
+
+    #[test]
+    fn should_split_integer_and_fraction() {
+        assert_eq!(
+            (Fract16::new(3, 1), Fract16::new(1, 2)),
+            Fract16::new(7, 2).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract16::new(2, 1), Fract16::new(0, 1)),
+            Fract16::new(6, 3).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract16::new(0, 1), Fract16::new(2, 3)),
+            Fract16::new(4, 6).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_zero_denominator_into_itself() {
+        assert_eq!(
+            (Fract16::new(1, 0), Fract16::new(0, 1)),
+            Fract16::new(1, 0).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_snap_to_closest() {
+        let allowed: [Fract16; 3] = [Fract16::new(1, 2), Fract16::new(3, 4), Fract16::new(1, 1)];
+        assert_eq!(
+            Some(Fract16::new(1, 2)),
+            Fract16::new(9, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract16::new(3, 4)),
+            Fract16::new(11, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract16::new(1, 1)),
+            Fract16::new(3, 2).snap_to(&allowed)
+        )
+    }
+
+    #[test]
+    fn should_snap_ties_to_first() {
+        let allowed: [Fract16; 2] = [Fract16::new(1, 2), Fract16::new(3, 4)];
+        assert_eq!(
+            Some(Fract16::new(1, 2)),
+            Fract16::new(5, 8).snap_to(&allowed)
+        );
+        let reversed: [Fract16; 2] = [Fract16::new(3, 4), Fract16::new(1, 2)];
+        assert_eq!(
+            Some(Fract16::new(3, 4)),
+            Fract16::new(5, 8).snap_to(&reversed)
+        )
+    }
+
+    #[test]
+    fn should_not_snap_to_empty_slice() {
+        assert_eq!(None, Fract16::new(5, 8).snap_to(&[]))
+    }
+
+    #[test]
+    fn should_try_from_signed_pair() {
+        assert_eq!(Ok(Fract16::new(2, 3)), Fract16::try_from((10i64, 15i64)));
+        assert_eq!(Ok(Fract16::new(0, 1)), Fract16::try_from((0i64, 7i64)))
+    }
+
+    #[test]
+    fn should_not_try_from_negative_pair() {
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract16::try_from((-1i64, 2i64))
+        );
+        assert_eq!(
+            Err(FractError::DenominatorUnderflow),
+            Fract16::try_from((1i64, -2i64))
+        )
+    }
+
+    #[test]
+    fn should_not_try_from_signed_zero_denominator() {
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract16::try_from((1i64, 0i64))
+        )
+    }
+
+    #[test]
+    fn should_render_in_radix() {
+        assert_eq!("0.1", Fract16::new(1, 2).to_string_radix(2, 10));
+        assert_eq!("0.1", Fract16::new(1, 3).to_string_radix(3, 10));
+        assert_eq!("0.0101", Fract16::new(1, 3).to_string_radix(2, 4));
+        assert_eq!("12", Fract16::new(10, 2).to_string_radix(3, 4))
+    }
+
+    #[test]
+    fn should_format_continued_fraction() {
+        assert_eq!(
+            "[2; 3, 1, 4]",
+            Fract16::new(43, 19).continued_fraction_string()
+        );
+        assert_eq!("[0; 2]", Fract16::new(1, 2).continued_fraction_string());
+        assert_eq!("[5]", Fract16::new(5, 1).continued_fraction_string())
+    }
+
+    #[test]
+    fn should_find_reduced_reciprocal() {
+        assert_eq!(
+            Some(Fract16::new(9, 5)),
+            Fract16::new(10, 18).reciprocal_reduced()
+        );
+        assert_eq!(
+            Some(Fract16::new(1, 3)),
+            Fract16::new(3, 1).reciprocal_reduced()
+        )
+    }
+
+    #[test]
+    fn should_not_find_reciprocal_of_zero() {
+        assert_eq!(None, Fract16::new(0, 5).reciprocal_reduced())
+    }
+
+    #[test]
+    fn should_find_cross_difference() {
+        assert_eq!(-1, Fract16::new(1, 2).cross_difference(&Fract16::new(2, 3)));
+        assert_eq!(1, Fract16::new(2, 3).cross_difference(&Fract16::new(1, 2)));
+        assert_eq!(0, Fract16::new(2, 4).cross_difference(&Fract16::new(1, 2)));
+        assert_eq!(
+            u16::MAX as i128,
+            Fract16::new(u16::MAX, 1).cross_difference(&Fract16::new(0, 1))
+        )
+    }
+
+    #[test]
+    fn should_find_simplest_between() {
+        assert_eq!(
+            Some(Fract16::new(2, 5)),
+            Fract16::new(1, 3).simplest_between(&Fract16::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract16::new(2, 5)),
+            Fract16::new(1, 2).simplest_between(&Fract16::new(1, 3))
+        );
+        assert_eq!(
+            Some(Fract16::new(3, 7)),
+            Fract16::new(5, 12).simplest_between(&Fract16::new(4, 9))
+        )
+    }
+
+    #[test]
+    fn should_find_integer_between() {
+        assert_eq!(
+            Some(Fract16::new(2, 1)),
+            Fract16::new(3, 2).simplest_between(&Fract16::new(7, 2))
+        );
+        assert_eq!(
+            Some(Fract16::new(1, 1)),
+            Fract16::new(0, 1).simplest_between(&Fract16::new(3, 1))
+        )
+    }
+
+    #[test]
+    fn should_return_reduced_value_between_equal_bounds() {
+        assert_eq!(
+            Some(Fract16::new(1, 2)),
+            Fract16::new(2, 4).simplest_between(&Fract16::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_find_simplest_between_beyond_width() {
+        assert_eq!(
+            None,
+            Fract16::new(1, u16::MAX).simplest_between(&Fract16::new(1, u16::MAX - 1))
+        );
+        assert_eq!(
+            None,
+            Fract16::new(u16::MAX - 1, u16::MAX).simplest_between(&Fract16::new(1, 1))
+        )
+    }
+}
+
+// Fract32
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct Fract32 {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl Fract<u32, Fract32, f32> for Fract32 {
+    #[inline]
+    fn to_float(&self) -> f32 {
+        self.numerator as f32 / self.denominator as f32
+    }
+
+    #[inline]
+    fn new(numerator: u32, denominator: u32) -> Fract32 {
+        Fract32 {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn invert(&self) -> Fract32 {
+        Fract32 {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+
+    #[inline]
+    fn expand(&self, multiplicator: u32) -> Fract32 {
+        Fract32 {
+            numerator: self.numerator * multiplicator,
+            denominator: self.denominator * multiplicator,
+        }
+    }
+
+    #[inline]
+    fn reduce(&self) -> Fract32 {
+        // already reduced forms skip the gcd loop
+        if self.numerator == 1 || self.denominator == 1 {
+            return *self;
+        }
+        if self.numerator == 0 && self.denominator != 0 {
+            return Fract32 {
+                numerator: 0,
+                denominator: 1,
+            };
+        }
+
+        match utils::checked_gcd_u32(self.numerator, self.denominator) {
+            Some(gcd) => Fract32 {
+                numerator: self.numerator / gcd,
+                denominator: self.denominator / gcd,
+            },
+            None => *self,
+        }
+    }
+
+    #[inline]
+    fn invert_checked(&self) -> Result<Fract32, FractError> {
+        if self.numerator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(self.invert())
+    }
+
+    #[inline]
+    fn nearest_over(&self, denominator: u32) -> Fract32 {
+        let whole: u32 = self.numerator / self.denominator;
+        let remainder: u32 = self.numerator % self.denominator;
+        let scaled: u64 = remainder as u64 * denominator as u64;
+        let quotient: u64 = scaled / self.denominator as u64;
+        let rest: u64 = scaled % self.denominator as u64;
+        // ties round up; comparing against the complement avoids doubling `rest`
+        let rounded: u32 = quotient as u32 + (rest >= self.denominator as u64 - rest) as u32;
+
+        Fract32 {
+            numerator: whole * denominator + rounded,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn mul_checked(&self, other: &Fract32) -> Result<Fract32, FractError> {
+        let numerator: u32 = self
+            .numerator
+            .checked_mul(other.numerator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let denominator: u32 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+
+        Ok(Fract32 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn round_with(&self, mode: RoundingMode) -> u32 {
+        let whole: u32 = self.numerator / self.denominator;
+        let remainder: u32 = self.numerator % self.denominator;
+        if remainder == 0 {
+            return whole;
+        }
+
+        // compares the remainder against half the denominator without overflow
+        let half: Ordering = remainder.cmp(&(self.denominator - remainder));
+        let round_up: bool = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => match half {
+                Ordering::Less => false,
+                Ordering::Equal => whole % 2 == 1,
+                Ordering::Greater => true,
+            },
+        };
+
+        if round_up {
+            whole + 1
+        } else {
+            whole
+        }
+    }
+
+    #[inline]
+    fn percentage_of(&self, whole: &Fract32) -> Fract32 {
+        let ratio: Fract32 = (*self / *whole).reduce();
+        let gcd: u32 = utils::gcd_u32(100, ratio.denominator);
+        Fract32 {
+            numerator: ratio.numerator * (100 / gcd),
+            denominator: ratio.denominator / gcd,
+        }
+    }
+
+    #[inline]
+    fn try_from_u128(value: u128) -> Result<Fract32, FractError> {
+        let numerator: u32 = u32::try_from(value).map_err(|_| FractError::NumeratorOverflow)?;
+        Ok(Fract32::from(numerator))
+    }
+
+    #[inline]
+    fn equivalent_forms(&self, max_denominator: u32) -> Vec<Fract32> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let reduced: Fract32 = self.reduce();
+        let mut forms: Vec<Fract32> = Vec::new();
+        let mut factor: u32 = 1;
+        while let (Some(numerator), Some(denominator)) = (
+            reduced.numerator.checked_mul(factor),
+            reduced.denominator.checked_mul(factor),
+        ) {
+            if denominator > max_denominator {
+                break;
+            }
+
+            forms.push(Fract32 {
+                numerator,
+                denominator,
+            });
+            factor = match factor.checked_add(1) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        forms
+    }
+
+    #[inline]
+    fn cmp_exact(&self, other: &Fract32) -> Ordering {
+        let (lhs, rhs) = self.cross_multiply(other);
+
+        lhs.cmp(&rhs)
+    }
+
+    #[inline]
+    fn abs_diff_float(&self, other: &Fract32) -> f32 {
+        (self.to_float() - other.to_float()).abs()
+    }
+
+    #[inline]
+    fn saturating_from_float(value: f32, max_denominator: u32) -> Fract32 {
+        if value.is_nan() || value <= 0.0 {
+            return Fract32::from(0);
+        }
+
+        if value >= u32::MAX as f32 {
+            return Fract32::from(u32::MAX);
+        }
+
+        let (numerator, denominator) = utils::approximate_f64(
+            value as f64,
+            u32::MAX as u128,
+            max_denominator.max(1) as u128,
+        );
+        Fract32 {
+            numerator: numerator as u32,
+            denominator: denominator as u32,
+        }
+    }
+
+    #[inline]
+    fn continued_fraction(&self) -> Vec<u32> {
+        let mut coefficients: Vec<u32> = Vec::new();
+        let mut numerator: u32 = self.numerator;
+        let mut denominator: u32 = self.denominator;
+        while denominator != 0 {
+            coefficients.push(numerator / denominator);
+            let remainder: u32 = numerator % denominator;
+            numerator = denominator;
+            denominator = remainder;
+        }
+
+        coefficients
+    }
+
+    #[inline]
+    fn from_continued_fraction(coefficients: &[u32]) -> Fract32 {
+        let (last, rest) = match coefficients.split_last() {
+            Some(split) => split,
+            None => return Fract32::from(0),
+        };
+
+        rest.iter()
+            .rev()
+            .fold(Fract32::from(*last), |value, coefficient| Fract32 {
+                numerator: coefficient * value.numerator + value.denominator,
+                denominator: value.numerator,
+            })
+    }
+
+    #[inline]
+    fn stern_brocot_path(&self) -> Vec<bool> {
+        let reduced: Fract32 = self.reduce();
+        if reduced.numerator == 0 || reduced.denominator == 0 {
+            return Vec::new();
+        }
+
+        let coefficients: Vec<u32> = reduced.continued_fraction();
+        let last: usize = coefficients.len() - 1;
+        let mut path: Vec<bool> = Vec::new();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            let steps: u32 = if index == last {
+                coefficient - 1
+            } else {
+                *coefficient
+            };
+
+            for _ in 0..steps {
+                path.push(index % 2 == 0);
+            }
+        }
+
+        path
+    }
+
+    #[inline]
+    fn add_checked(&self, other: &Fract32) -> Result<Fract32, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract32 {
+            numerator: lhs.checked_add(rhs).ok_or(FractError::NumeratorOverflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn sub_checked(&self, other: &Fract32) -> Result<Fract32, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract32 {
+            numerator: lhs.checked_sub(rhs).ok_or(FractError::NumeratorUnderflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn div_checked(&self, other: &Fract32) -> Result<Fract32, FractError> {
+        self.mul_checked(&other.invert_checked()?)
+    }
+
+    #[inline]
+    fn checked_add_assign(&mut self, other: Fract32) -> Result<(), FractError> {
+        *self = self.add_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_sub_assign(&mut self, other: Fract32) -> Result<(), FractError> {
+        *self = self.sub_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_mul_assign(&mut self, other: Fract32) -> Result<(), FractError> {
+        *self = self.mul_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_div_assign(&mut self, other: Fract32) -> Result<(), FractError> {
+        *self = self.div_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn to_string_reduced(&self) -> String {
+        let reduced: Fract32 = self.reduce();
+        if reduced.denominator == 1 {
+            reduced.numerator.to_string()
+        } else {
+            format!("{}/{}", reduced.numerator, reduced.denominator)
+        }
+    }
+
+    #[inline]
+    fn denominator_factors(&self) -> Vec<(u32, u32)> {
+        let mut remaining: u32 = self.reduce().denominator;
+        let mut factors: Vec<(u32, u32)> = Vec::new();
+        let mut divisor: u32 = 2;
+        while remaining > 1 && divisor <= remaining / divisor {
+            let mut exponent: u32 = 0;
+            while remaining.is_multiple_of(divisor) {
+                remaining /= divisor;
+                exponent += 1;
+            }
+
+            if exponent > 0 {
+                factors.push((divisor, exponent));
+            }
+            divisor += 1;
+        }
+
+        if remaining > 1 {
+            factors.push((remaining, 1));
+        }
+
+        factors
+    }
+
+    #[inline]
+    fn terminates_in_base(&self, base: u32) -> bool {
+        let mut denominator: u32 = self.reduce().denominator;
+        if denominator == 0 {
+            return false;
+        }
+        if base < 2 {
+            return denominator == 1;
+        }
+
+        // strips every prime factor shared with the base
+        loop {
+            let gcd: u32 = utils::gcd_u32(denominator, base);
+            if gcd == 1 {
+                return denominator == 1;
+            }
+            denominator /= gcd;
+        }
+    }
+
+    #[inline]
+    fn proper_part(&self) -> Fract32 {
+        Fract32 {
+            numerator: self.numerator % self.denominator,
+            denominator: self.denominator,
+        }
+        .reduce()
+    }
+
+    #[inline]
+    fn integer_scale(&self) -> u32 {
+        self.reduce().denominator
+    }
+
+    #[inline]
+    fn scaled_numerator(&self) -> u32 {
+        self.reduce().numerator
+    }
+
+    #[inline]
+    fn powf(&self, exp: f64) -> f64 {
+        (self.numerator as f64 / self.denominator as f64).powf(exp)
+    }
+
+    #[inline]
+    fn powf_approx(&self, exp: f64, max_denominator: u32) -> Fract32 {
+        Fract32::saturating_from_float(self.powf(exp) as f32, max_denominator)
+    }
+
+    #[inline]
+    fn largest_unit_fraction(&self) -> Fract32 {
+        let mut denominator: u32 = self.denominator / self.numerator;
+        if !self.denominator.is_multiple_of(self.numerator) {
+            denominator += 1;
+        }
+
+        Fract32 {
+            numerator: 1,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn egyptian_fraction(&self) -> Option<Vec<Fract32>> {
+        if self.denominator == 0 {
+            return None;
+        }
+
+        let mut units: Vec<Fract32> = Vec::new();
+        let whole: u32 = self.numerator / self.denominator;
+        if whole != 0 {
+            units.push(Fract32::from(whole));
+        }
+
+        let mut remaining: Fract32 = self.proper_part();
+        while remaining.numerator != 0 {
+            let unit: Fract32 = remaining.largest_unit_fraction();
+            units.push(unit);
+            remaining = remaining.sub_checked(&unit).ok()?.reduce();
+        }
+
+        Some(units)
+    }
+
+    #[inline]
+    fn safe_cmp(&self, other: &Fract32) -> Option<Ordering> {
+        if self.denominator == 0 || other.denominator == 0 {
+            return None;
+        }
+
+        Some(self.cmp_exact(other))
+    }
+
+    #[inline]
+    fn with_numerator(&self, numerator: u32) -> Fract32 {
+        Fract32 {
+            numerator,
+            denominator: self.denominator,
+        }
+    }
+
+    #[inline]
+    fn with_denominator(&self, denominator: u32) -> Fract32 {
+        Fract32 {
+            numerator: self.numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Fract32, FractError> {
+        let (numerator, denominator) = match s.trim().split_once('/') {
+            Some((numerator, denominator)) => (numerator.trim(), denominator.trim()),
+            None => (s.trim(), "1"),
+        };
+
+        if !(2..=36).contains(&radix)
+            || !utils::is_digits_radix(numerator, radix)
+            || !utils::is_digits_radix(denominator, radix)
+        {
+            return Err(FractError::InvalidFormat);
+        }
+
+        let numerator: u32 =
+            u32::from_str_radix(numerator, radix).map_err(|_| FractError::NumeratorOverflow)?;
+        let denominator: u32 =
+            u32::from_str_radix(denominator, radix).map_err(|_| FractError::DenominatorOverflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(Fract32 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn to_basis_points(&self) -> u32 {
+        let denominator: u128 = self.denominator as u128;
+        let mut basis_points: u128 = (self.numerator as u128 / denominator).saturating_mul(10000);
+        let mut remainder: u128 = self.numerator as u128 % denominator;
+        for scale in &[1000, 100, 10, 1] {
+            let (digit, next) = utils::next_digit_u128(remainder, denominator, 10);
+            basis_points = basis_points.saturating_add(digit as u128 * scale);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, denominator, 10).0 >= 5 {
+            basis_points = basis_points.saturating_add(1);
+        }
+
+        u32::try_from(basis_points).unwrap_or(u32::MAX)
+    }
+
+    #[inline]
+    fn to_float_exact(&self) -> Result<f32, f32> {
+        let reduced: Fract32 = self.reduce();
+        let value: f32 = reduced.to_float();
+        if reduced.denominator == 0 || !reduced.denominator.is_power_of_two() {
+            return Err(value);
+        }
+        if reduced.numerator == 0 {
+            return Ok(value);
+        }
+
+        let significant: u32 = reduced.numerator >> reduced.numerator.trailing_zeros();
+        if u32::BITS - significant.leading_zeros() <= f32::MANTISSA_DIGITS {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+
+    #[inline]
+    fn components(&self) -> (u32, u32) {
+        (self.numerator, self.denominator)
+    }
+
+    #[inline]
+    fn div_rem_fract(&self, other: &Fract32) -> Option<(u32, Fract32)> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        let (lhs, rhs, denominator) = self
+            .reduce()
+            .expand_to_common_checked(&other.reduce())
+            .ok()?;
+
+        Some((lhs / rhs, Fract32::new(lhs % rhs, denominator).reduce()))
+    }
+
+    #[inline]
+    fn reduce_mut(&mut self) {
+        *self = self.reduce();
+    }
+
+    #[inline]
+    fn float_ulp_error(&self) -> u64 {
+        if self.denominator == 0 {
+            return 0;
+        }
+
+        utils::ulp_error(
+            self.to_float().to_bits() as u64,
+            f32::MANTISSA_DIGITS - 1,
+            150,
+            self.numerator as u128,
+            self.denominator as u128,
+        )
+    }
+
+    #[inline]
+    fn modular_inverse(&self) -> Option<u32> {
+        utils::inverse_mod_u128(self.numerator as u128, self.denominator as u128)
+            .map(|inverse| inverse as u32)
+    }
+
+    #[inline]
+    fn checked_add_int(&self, value: u32) -> Option<Fract32> {
+        self.add_checked(&Fract32::from(value)).ok()
+    }
+
+    #[inline]
+    fn display_approx(&self, max_denominator: u32) -> String {
+        let reduced: Fract32 = self.reduce();
+        if reduced.denominator <= max_denominator {
+            return reduced.to_string();
+        }
+
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            reduced.numerator as u128,
+            reduced.denominator as u128,
+            u32::MAX as u128,
+            max_denominator.max(1) as u128,
+        );
+
+        format!("≈{}", Fract32::new(numerator as u32, denominator as u32))
+    }
+
+    #[inline]
+    fn from_float_capped(
+        value: f64,
+        max_iterations: u32,
+        max_denominator: u32,
+    ) -> Result<Fract32, FractError> {
+        if value.is_nan() {
+            return Err(FractError::InvalidFormat);
+        }
+        if value < 0.0 {
+            return Err(FractError::NumeratorUnderflow);
+        }
+        if value >= u32::MAX as f64 + 1.0 {
+            return Err(FractError::NumeratorOverflow);
+        }
+
+        let (numerator, denominator) = utils::approximate_f64_capped(
+            value,
+            u32::MAX as u128,
+            max_denominator.max(1) as u128,
+            max_iterations,
+        )
+        .map_err(|_| FractError::IterationLimit)?;
+
+        Ok(Fract32::new(numerator as u32, denominator as u32))
+    }
+
+    #[inline]
+    fn decimal_digit_at(&self, position: u32) -> u8 {
+        if self.denominator == 0 {
+            return 0;
+        }
+        if position == 0 {
+            return (self.numerator / self.denominator % 10) as u8;
+        }
+
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        let mut digit: u32 = 0;
+        for _ in 0..position {
+            let (next_digit, next_remainder) =
+                utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digit = next_digit;
+            remainder = next_remainder;
+        }
+
+        digit as u8
+    }
+
+    #[inline]
+    fn reduce_checked(&self) -> (Fract32, bool) {
+        let reduced: Fract32 = self.reduce();
+
+        (reduced, reduced != *self)
+    }
+
+    #[inline]
+    fn scale(&self, factor: u32) -> Option<Fract32> {
+        Some(Fract32 {
+            numerator: self.numerator.checked_mul(factor)?,
+            denominator: self.denominator.checked_mul(factor)?,
+        })
+    }
+
+    #[inline]
+    fn to_sig_figs(&self, sig_figs: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::sig_figs_u128(self.numerator as u128, self.denominator as u128, sig_figs)
+    }
+
+    #[inline]
+    fn clamp01(&self) -> Fract32 {
+        if self.numerator > self.denominator {
+            Fract32::new(1, 1)
+        } else {
+            *self
+        }
+    }
+
+    #[inline]
+    fn period_length(&self) -> Option<u64> {
+        let mut modulus: u32 = self.reduce().denominator;
+        if modulus == 0 {
+            return Some(0);
+        }
+        while modulus.is_multiple_of(2) {
+            modulus /= 2;
+        }
+        while modulus.is_multiple_of(5) {
+            modulus /= 5;
+        }
+        if modulus == 1 {
+            return Some(0);
+        }
+
+        u64::try_from(utils::decimal_period_u128(modulus as u128)).ok()
+    }
+
+    #[inline]
+    fn validate(&self) -> Result<(), FractError> {
+        if self.denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn decimal_digits_rounded(&self, count: usize) -> Vec<u8> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let mut digits: Vec<u8> = Vec::with_capacity(count);
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        for _ in 0..count {
+            let (digit, next) = utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digits.push(digit as u8);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, self.denominator as u128, 10).0 >= 5 {
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    break;
+                }
+            }
+        }
+
+        digits
+    }
+
+    #[inline]
+    fn minimal(&self) -> Fract32 {
+        self.reduce()
+    }
+
+    #[inline]
+    fn checked_shl(&self, n: u32) -> Option<Fract32> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.denominator.trailing_zeros().min(n);
+        Some(Fract32 {
+            numerator: self
+                .numerator
+                .checked_mul(u32::checked_pow(2, n - cancelled)?)?,
+            denominator: self.denominator.checked_shr(cancelled)?,
+        })
+    }
+
+    #[inline]
+    fn checked_shr(&self, n: u32) -> Option<Fract32> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.numerator.trailing_zeros().min(n);
+        Some(Fract32 {
+            numerator: self.numerator >> cancelled,
+            denominator: self
+                .denominator
+                .checked_mul(u32::checked_pow(2, n - cancelled)?)?,
+        })
+    }
+
+    #[inline]
+    fn reduce_keep_denominator(&self) -> Fract32 {
+        if self.numerator == 0 {
+            return *self;
+        }
+
+        self.reduce()
+    }
+
+    #[inline]
+    fn to_mixed_string(&self) -> String {
+        if self.denominator == 0 {
+            return self.to_string();
+        }
+
+        let whole: u32 = self.numerator / self.denominator;
+        let proper: Fract32 = self.proper_part();
+        if proper.numerator == 0 {
+            whole.to_string()
+        } else if whole == 0 {
+            proper.to_string()
+        } else {
+            format!("{} {}", whole, proper)
+        }
+    }
+
+    #[inline]
+    fn ratio_to(&self, other: &Fract32) -> Option<Fract32> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        Fract32::product_reduced([*self, other.invert()])
+    }
+
+    #[inline]
+    fn is_between(&self, low: &Fract32, high: &Fract32) -> bool {
+        let (low, high) = if low.cmp_exact(high) == Ordering::Greater {
+            (high, low)
+        } else {
+            (low, high)
+        };
+
+        self.cmp_exact(low) != Ordering::Less && self.cmp_exact(high) != Ordering::Greater
+    }
+
+    #[inline]
+    fn convergents(&self) -> Vec<Fract32> {
+        let (mut p0, mut q0, mut p1, mut q1): (u32, u32, u32, u32) = (0, 1, 1, 0);
+        self.continued_fraction()
+            .into_iter()
+            .map(|coefficient| {
+                let (p2, q2) = (coefficient * p1 + p0, coefficient * q1 + q0);
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+                Fract32::new(p2, q2)
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn cmp_with_float(&self, value: f64) -> Ordering {
+        utils::cmp_ratio_f64(self.numerator as u128, self.denominator as u128, value)
+    }
+
+    #[inline]
+    fn integer_and_fraction(&self) -> (Fract32, Fract32) {
+        if self.denominator == 0 {
+            return (*self, Fract32::new(0, 1));
+        }
+
+        (
+            Fract32::from(self.numerator / self.denominator),
+            self.proper_part(),
+        )
+    }
+
+    #[inline]
+    fn snap_to(&self, allowed: &[Fract32]) -> Option<Fract32> {
+        let distance = |other: &Fract32| {
+            utils::distance_u128(
+                self.numerator as u128,
+                self.denominator as u128,
+                other.numerator as u128,
+                other.denominator as u128,
+            )
+        };
+
+        let mut best: Option<(Fract32, Option<(u128, u128)>)> = None;
+        for candidate in allowed {
+            let candidate_distance: Option<(u128, u128)> = distance(candidate);
+            let closer: bool = match best {
+                None => true,
+                Some((current, current_distance)) => match (candidate_distance, current_distance) {
+                    (Some(lhs), Some(rhs)) => {
+                        utils::cmp_ratio_u128(lhs.0, lhs.1, rhs.0, rhs.1) == Ordering::Less
+                    }
+                    _ => self.abs_diff_float(candidate) < self.abs_diff_float(&current),
+                },
+            };
+            if closer {
+                best = Some((*candidate, candidate_distance));
+            }
+        }
+
+        best.map(|(fraction, _)| fraction)
+    }
+
+    #[inline]
+    fn to_string_radix(&self, radix: u32, max_digits: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::radix_string_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            radix,
+            max_digits,
+        )
+    }
+
+    #[inline]
+    fn continued_fraction_string(&self) -> String {
+        let coefficients: Vec<String> = self
+            .continued_fraction()
+            .iter()
+            .map(|coefficient| coefficient.to_string())
+            .collect();
+
+        match coefficients.split_first() {
+            None => String::from("[]"),
+            Some((first, [])) => format!("[{}]", first),
+            Some((first, rest)) => format!("[{}; {}]", first, rest.join(", ")),
+        }
+    }
+
+    #[inline]
+    fn reciprocal_reduced(&self) -> Option<Fract32> {
+        if self.numerator == 0 {
+            return None;
+        }
+
+        Some(self.invert().reduce())
+    }
+
+    #[inline]
+    fn cross_difference(&self, other: &Fract32) -> i128 {
+        utils::cross_difference_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            other.numerator as u128,
+            other.denominator as u128,
+        )
+    }
+
+    #[inline]
+    fn simplest_between(&self, other: &Fract32) -> Option<Fract32> {
+        let (low, high) = match self.cmp_exact(other) {
+            Ordering::Equal => return Some(self.reduce()),
+            Ordering::Less => (self, other),
+            Ordering::Greater => (other, self),
+        };
+
+        let (numerator, denominator) = utils::simplest_between_u128(
+            low.numerator as u128,
+            low.denominator as u128,
+            high.numerator as u128,
+            high.denominator as u128,
+        )?;
+
+        Some(Fract32::new(
+            u32::try_from(numerator).ok()?,
+            u32::try_from(denominator).ok()?,
+        ))
+    }
+}
+
+impl From<u32> for Fract32 {
+    #[inline]
+    fn from(input: u32) -> Self {
+        Fract32 {
+            numerator: input,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract32 = self;
+        let mut nrhs: Fract32 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u32 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract32 {
+            numerator: nlhs.numerator + nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Sub for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract32 = self;
+        let mut nrhs: Fract32 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u32 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract32 {
+            numerator: nlhs.numerator - nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Mul for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cancels common factors crosswise before multiplying to keep the products small
+        let first: u32 = utils::gcd_u32(self.numerator, rhs.denominator).max(1);
+        let second: u32 = utils::gcd_u32(rhs.numerator, self.denominator).max(1);
+
+        Fract32 {
+            numerator: (self.numerator / first) * (rhs.numerator / second),
+            denominator: (self.denominator / second) * (rhs.denominator / first),
+        }
+    }
+}
+
+impl Div for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.invert()
+    }
+}
+
+impl Widen for Fract32 {
+    type Wider = Fract64;
+
+    #[inline]
+    fn widen(self) -> Fract64 {
+        Fract64 {
+            numerator: self.numerator as u64,
+            denominator: self.denominator as u64,
+        }
+    }
+}
+
+impl PartialOrd for Fract32 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fract32 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_exact(other)
+            .then(self.numerator.cmp(&other.numerator))
+            .then(self.denominator.cmp(&other.denominator))
+    }
+}
+
+/// Expands both to their least common denominator, failing like `Fract32::common_denominator`.
+pub fn to_common_denominator(a: Fract32, b: Fract32) -> Result<(Fract32, Fract32), FractError> {
+    let expanded: Vec<Fract32> = Fract32::common_denominator(&[a, b])?;
+
+    Ok((expanded[0], expanded[1]))
+}
+
+impl Fract32 {
+    #[inline]
+    pub fn cross_multiply(&self, other: &Fract32) -> (u64, u64) {
+        (
+            self.numerator as u64 * other.denominator as u64,
+            other.numerator as u64 * self.denominator as u64,
+        )
+    }
+
+    #[inline]
+    fn expand_to_common_checked(&self, other: &Fract32) -> Result<(u32, u32, u32), FractError> {
+        if self.denominator == other.denominator {
+            return Ok((self.numerator, other.numerator, self.denominator));
+        }
+
+        let denominator: u32 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+        let lhs: u32 = self
+            .numerator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let rhs: u32 = other
+            .numerator
+            .checked_mul(self.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+
+        Ok((lhs, rhs, denominator))
+    }
+
+    #[inline]
+    pub fn to_fract8_approx(&self) -> Fract8 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            u8::MAX as u128,
+            u8::MAX as u128,
+        );
+
+        Fract8 {
+            numerator: numerator as u8,
+            denominator: denominator as u8,
+        }
+    }
+
+    #[inline]
+    pub fn to_fract16_approx(&self) -> Fract16 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            u16::MAX as u128,
+            u16::MAX as u128,
+        );
+
+        Fract16 {
+            numerator: numerator as u16,
+            denominator: denominator as u16,
+        }
+    }
+
+    pub fn common_denominator(fractions: &[Fract32]) -> Result<Vec<Fract32>, FractError> {
+        let mut denominator: u32 = 1;
+        for fraction in fractions {
+            if fraction.denominator == 0 {
+                return Err(FractError::ZeroDenominator);
+            }
+            denominator = utils::checked_lcm_u32(denominator, fraction.denominator)
+                .ok_or(FractError::DenominatorOverflow)?;
+        }
+
+        fractions
+            .iter()
+            .map(|fraction| {
+                fraction
+                    .numerator
+                    .checked_mul(denominator / fraction.denominator)
+                    .map(|numerator| Fract32::new(numerator, denominator))
+                    .ok_or(FractError::NumeratorOverflow)
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub fn reduce_into_fract8(&self) -> Result<Fract8, FractError> {
+        Fract8::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn reduce_into_fract16(&self) -> Result<Fract16, FractError> {
+        Fract16::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        let mut bytes: [u8; 8] = [0; 8];
+        let (numerator, denominator) = bytes.split_at_mut(4);
+        numerator.copy_from_slice(&self.numerator.to_le_bytes());
+        denominator.copy_from_slice(&self.denominator.to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Fract32 {
+        let mut numerator: [u8; 4] = [0; 4];
+        let mut denominator: [u8; 4] = [0; 4];
+        numerator.copy_from_slice(&bytes[..4]);
+        denominator.copy_from_slice(&bytes[4..]);
+
+        Fract32 {
+            numerator: u32::from_le_bytes(numerator),
+            denominator: u32::from_le_bytes(denominator),
+        }
+    }
+
+    /// Sums over common denominators, reducing after every step; `None` once an
+    /// intermediate value overflows or a denominator is zero.
+    pub fn sum_reduced<I: IntoIterator<Item = Fract32>>(fractions: I) -> Option<Fract32> {
+        fractions
+            .into_iter()
+            .try_fold(Fract32::new(0, 1), |sum, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract32 = fraction.reduce();
+                let denominator: u32 =
+                    utils::checked_lcm_u32(sum.denominator, fraction.denominator)?;
+                let numerator: u32 = sum
+                    .numerator
+                    .checked_mul(denominator / sum.denominator)?
+                    .checked_add(
+                        fraction
+                            .numerator
+                            .checked_mul(denominator / fraction.denominator)?,
+                    )?;
+
+                Some(Fract32::new(numerator, denominator).reduce())
+            })
+    }
+
+    /// Multiplies with cross-cancellation so the running product stays reduced; `None` on
+    /// overflow or a zero denominator.
+    pub fn product_reduced<I: IntoIterator<Item = Fract32>>(fractions: I) -> Option<Fract32> {
+        fractions
+            .into_iter()
+            .try_fold(Fract32::new(1, 1), |product, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract32 = fraction.reduce();
+                let first: u32 = utils::gcd_u32(product.numerator, fraction.denominator).max(1);
+                let second: u32 = utils::gcd_u32(fraction.numerator, product.denominator).max(1);
+
+                Some(Fract32::new(
+                    (product.numerator / first).checked_mul(fraction.numerator / second)?,
+                    (product.denominator / second).checked_mul(fraction.denominator / first)?,
+                ))
+            })
+    }
+
+    #[inline]
+    pub fn fits_in_fract8(&self) -> bool {
+        self.reduce_into_fract8().is_ok()
+    }
+
+    #[inline]
+    pub fn fits_in_fract16(&self) -> bool {
+        self.reduce_into_fract16().is_ok()
+    }
+}
+
+pub fn reduce_all(fractions: &mut [Fract32]) {
+    for fraction in fractions.iter_mut() {
+        *fraction = fraction.reduce();
+    }
+}
+
+impl Shl<u32> for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the denominator before growing the numerator
+        let cancelled: u32 = self.denominator.trailing_zeros().min(rhs);
+        Fract32 {
+            numerator: self.numerator * (1 << (rhs - cancelled)),
+            denominator: self.denominator >> cancelled,
+        }
+    }
+}
+
+impl Shr<u32> for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the numerator before growing the denominator
+        let cancelled: u32 = self.numerator.trailing_zeros().min(rhs);
+        Fract32 {
+            numerator: self.numerator >> cancelled,
+            denominator: self.denominator * (1 << (rhs - cancelled)),
+        }
+    }
+}
+
+impl FromStr for Fract32 {
+    type Err = FractError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Fract32::from_str_radix(s, 10)
+    }
+}
+
+pub fn parse_list(s: &str) -> Result<Vec<Fract32>, FractError> {
+    if s.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    s.split(',')
+        .enumerate()
+        .map(|(index, entry)| entry.parse().map_err(|_| FractError::InvalidEntry(index)))
+        .collect()
+}
+
+pub fn dedup_by_value(fractions: &mut Vec<Fract32>) {
+    fractions.sort_by(|a, b| a.cmp_exact(b));
+    fractions.dedup_by(|a, b| a.cmp_exact(b) == Ordering::Equal);
+    reduce_all(fractions);
+}
+
+impl From<Fract32> for f32 {
+    #[inline]
+    fn from(input: Fract32) -> Self {
+        input.to_float()
+    }
+}
+
+impl fmt::Display for Fract32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)?;
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)?;
+        }
+
+        if f.alternate() {
+            write!(f, " ({})", self.to_float())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Fract32 {
+    #[inline]
+    fn default() -> Self {
+        Fract32 {
+            numerator: 0,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add<u32> for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn add(self, rhs: u32) -> Self::Output {
+        self + Fract32::from(rhs)
+    }
+}
+
+impl Sub<u32> for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn sub(self, rhs: u32) -> Self::Output {
+        self - Fract32::from(rhs)
+    }
+}
+
+impl Mul<u32> for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn mul(self, rhs: u32) -> Self::Output {
+        self * Fract32::from(rhs)
+    }
+}
+
+impl Div<u32> for Fract32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn div(self, rhs: u32) -> Self::Output {
+        self / Fract32::from(rhs)
+    }
+}
+
+impl Add<Fract32> for u32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn add(self, rhs: Fract32) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Mul<Fract32> for u32 {
+    type Output = Fract32;
+
+    #[inline]
+    fn mul(self, rhs: Fract32) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl TryFrom<Fract32> for Fract8 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract32) -> Result<Self, Self::Error> {
+        Ok(Fract8 {
+            numerator: u8::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u8::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl TryFrom<Fract32> for Fract16 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract32) -> Result<Self, Self::Error> {
+        Ok(Fract16 {
+            numerator: u16::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u16::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+pub fn common_factor(fractions: &[Fract32]) -> u32 {
+    fractions.iter().fold(0, |gcd, fraction| {
+        utils::gcd_u32(
+            utils::gcd_u32(gcd, fraction.numerator),
+            fraction.denominator,
+        )
+    })
+}
+
+impl From<bool> for Fract32 {
+    #[inline]
+    fn from(input: bool) -> Self {
+        Fract32 {
+            numerator: input as u32,
+            denominator: 1,
+        }
+    }
+}
+
+impl fmt::Debug for Fract32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "Fract32 {{ numerator: {}, denominator: {}, reduced: {}, value: {} }}",
+                self.numerator,
+                self.denominator,
+                self.reduce(),
+                self.to_float()
+            )
+        } else {
+            f.debug_struct("Fract32")
+                .field("numerator", &self.numerator)
+                .field("denominator", &self.denominator)
+                .finish()
+        }
+    }
+}
+
+/// Least common denominator and the matching numerators, failing like
+/// `Fract32::common_denominator`. An empty slice gives a denominator of `1`.
+pub fn align_numerators(fractions: &[Fract32]) -> Result<(u32, Vec<u32>), FractError> {
+    let expanded: Vec<Fract32> = Fract32::common_denominator(fractions)?;
+    let denominator: u32 = expanded.first().map_or(1, |fraction| fraction.denominator);
+
+    Ok((
+        denominator,
+        expanded.iter().map(|fraction| fraction.numerator).collect(),
+    ))
+}
+
+impl Hash for Fract32 {
+    // hashes the reduced value as u128 so equal values match across widths
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let reduced: Fract32 = self.reduce();
+        (reduced.numerator as u128).hash(state);
+        (reduced.denominator as u128).hash(state);
+    }
+}
+
+impl TryFrom<(i64, i64)> for Fract32 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from((numerator, denominator): (i64, i64)) -> Result<Self, Self::Error> {
+        let numerator: u64 =
+            u64::try_from(numerator).map_err(|_| FractError::NumeratorUnderflow)?;
+        let denominator: u64 =
+            u64::try_from(denominator).map_err(|_| FractError::DenominatorUnderflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        let divisor: u64 = utils::gcd_u64(numerator, denominator);
+        Ok(Fract32 {
+            numerator: u32::try_from(numerator / divisor)
+                .map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u32::try_from(denominator / divisor)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+#[cfg(test)]
+mod tests_fract32 {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::convert::TryFrom;
+    use std::hash::{Hash, Hasher};
+
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::{
+        align_numerators, common_factor, dedup_by_value, parse_list, reduce_all,
+        to_common_denominator, Fract, Fract16, Fract32, Fract64, Fract8, FractError, RoundingMode,
+        Widen,
+    };
+
+    #[test]
+    fn should_create() {
+        let expected: Fract32 = Fract32 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let actual: Fract32 = Fract32::new(8, 10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_invert() {
+        let expected: Fract32 = Fract32 {
+            numerator: 10,
+            denominator: 8,
+        };
+
+        let actual: Fract32 = Fract32::new(8, 10).invert();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_expand() {
+        let expected: Fract32 = Fract32 {
+            numerator: 80,
+            denominator: 100,
+        };
+
+        let actual: Fract32 = Fract32::new(8, 10).expand(10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_convert() {
+        let expected: f32 = 0.8;
+        let actual: f32 = Fract32::new(8, 10).to_float();
+
+        assert_approx_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_add() {
+        let expected: Fract32 = Fract32 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        let first: Fract32 = Fract32::new(1, 2);
+        let second: Fract32 = Fract32::new(9, 10);
+        let result: Fract32 = first + second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_sub() {
+        let expected: Fract32 = Fract32 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        let first: Fract32 = Fract32::new(4, 2);
+        let second: Fract32 = Fract32::new(9, 10);
+        let result: Fract32 = first - second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_mul() {
+        let expected: Fract32 = Fract32 {
+            numerator: 4,
+            denominator: 5,
+        };
+
+        let first: Fract32 = Fract32::new(2, 5);
+        let second: Fract32 = Fract32::new(4, 2);
+        let result: Fract32 = first * second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_div() {
+        let expected: Fract32 = Fract32 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let first: Fract32 = Fract32::new(1, 2);
+        let second: Fract32 = Fract32::new(9, 10);
+        let result: Fract32 = first / second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_reduce() {
+        let expected: Fract32 = Fract32 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let value: Fract32 = Fract32 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(expected, value.reduce())
+    }
+
+    #[test]
+    fn should_invert_checked() {
+        let expected: Fract32 = Fract32 {
+            numerator: 3,
+            denominator: 2,
+        };
+
+        let actual: Result<Fract32, FractError> = Fract32::new(2, 3).invert_checked();
+
+        assert_eq!(Ok(expected), actual)
+    }
+
+    #[test]
+    fn should_not_invert_zero() {
+        let actual: Result<Fract32, FractError> = Fract32::new(0, 5).invert_checked();
+
+        assert_eq!(Err(FractError::ZeroDenominator), actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over() {
+        let expected: Fract32 = Fract32 {
+            numerator: 8,
+            denominator: 12,
+        };
+
+        let actual: Fract32 = Fract32::new(5, 8).nearest_over(12);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_improper() {
+        let expected: Fract32 = Fract32 {
+            numerator: 7,
+            denominator: 3,
+        };
+
+        let actual: Fract32 = Fract32::new(7, 3).nearest_over(3);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_round_tie_up_in_nearest_over() {
+        let expected: Fract32 = Fract32 {
+            numerator: 1,
+            denominator: 2,
+        };
+
+        let actual: Fract32 = Fract32::new(1, 4).nearest_over(2);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_near_max() {
+        assert_eq!(
+            Fract32::new(u32::MAX - 2, u32::MAX - 1),
+            Fract32::new(u32::MAX - 1, u32::MAX).nearest_over(u32::MAX - 1)
+        )
+    }
+
+    #[test]
+    fn should_widen() {
+        let expected: Fract64 = Fract64 {
+            numerator: u32::MAX as u64,
+            denominator: 10,
+        };
+
+        let actual: Fract64 = Fract32::new(u32::MAX, 10).widen();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_mul_checked() {
+        let expected: Fract32 = Fract32 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let first: Fract32 = Fract32::new(2, 5);
+        let second: Fract32 = Fract32::new(4, 2);
+
+        assert_eq!(Ok(expected), first.mul_checked(&second))
+    }
+
+    #[test]
+    fn should_report_numerator_overflow_in_mul_checked() {
+        let first: Fract32 = Fract32::new(u32::MAX, 1);
+        let second: Fract32 = Fract32::new(2, 3);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_report_denominator_overflow_in_mul_checked() {
+        let first: Fract32 = Fract32::new(1, u32::MAX);
+        let second: Fract32 = Fract32::new(1, 2);
+
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_order_by_value() {
+        let smaller: Fract32 = Fract32::new(1, 3);
+        let larger: Fract32 = Fract32::new(2, 5);
+
+        assert!(smaller < larger);
+        assert!(larger > smaller)
+    }
+
+    #[test]
+    fn should_order_equal_values_by_representation() {
+        let reduced: Fract32 = Fract32::new(1, 2);
+        let expanded: Fract32 = Fract32::new(2, 4);
+
+        assert!(reduced < expanded);
+        assert!(reduced < Fract32::new(3, 5));
+        assert!(expanded < Fract32::new(3, 5))
+    }
+
+    #[test]
+    fn should_order_large_values() {
+        let smaller: Fract32 = Fract32::new(u32::MAX - 1, u32::MAX);
+        let larger: Fract32 = Fract32::new(u32::MAX, u32::MAX - 1);
+
+        assert!(smaller < larger);
+        assert!(smaller < Fract32::new(1, 1));
+        assert!(Fract32::new(1, 1) < larger)
+    }
+
+    #[test]
+    fn should_expand_to_common_denominator() {
+        let expected: Result<(Fract32, Fract32), FractError> =
+            Ok((Fract32::new(3, 6), Fract32::new(2, 6)));
+
+        let actual: Result<(Fract32, Fract32), FractError> =
+            to_common_denominator(Fract32::new(1, 2), Fract32::new(1, 3));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_keep_equal_denominators_in_common_denominator() {
+        let expected: Result<(Fract32, Fract32), FractError> =
+            Ok((Fract32::new(2, 4), Fract32::new(3, 4)));
+
+        let actual: Result<(Fract32, Fract32), FractError> =
+            to_common_denominator(Fract32::new(2, 4), Fract32::new(3, 4));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_not_expand_to_common_denominator_on_overflow_or_zero() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            to_common_denominator(Fract32::new(1, 65536), Fract32::new(1, 65537))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            to_common_denominator(Fract32::new(1, 0), Fract32::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_round_half_up() {
+        assert_eq!(3, Fract32::new(5, 2).round_with(RoundingMode::HalfUp));
+        assert_eq!(4, Fract32::new(7, 2).round_with(RoundingMode::HalfUp))
+    }
+
+    #[test]
+    fn should_round_half_even() {
+        assert_eq!(2, Fract32::new(5, 2).round_with(RoundingMode::HalfEven));
+        assert_eq!(4, Fract32::new(7, 2).round_with(RoundingMode::HalfEven))
+    }
+
+    #[test]
+    fn should_round_half_down() {
+        assert_eq!(2, Fract32::new(5, 2).round_with(RoundingMode::HalfDown));
+        assert_eq!(3, Fract32::new(8, 3).round_with(RoundingMode::HalfDown))
+    }
+
+    #[test]
+    fn should_round_floor_and_ceil() {
+        assert_eq!(2, Fract32::new(8, 3).round_with(RoundingMode::Floor));
+        assert_eq!(3, Fract32::new(7, 3).round_with(RoundingMode::Ceil));
+        assert_eq!(2, Fract32::new(4, 2).round_with(RoundingMode::Ceil))
+    }
+
+    #[test]
+    fn should_calculate_percentage_of() {
+        let expected: Fract32 = Fract32 {
+            numerator: 50,
+            denominator: 1,
+        };
+
+        let actual: Fract32 = Fract32::new(1, 4).percentage_of(&Fract32::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_percentage_of_above_hundred() {
+        let expected: Fract32 = Fract32 {
+            numerator: 150,
+            denominator: 1,
+        };
+
+        let actual: Fract32 = Fract32::new(3, 4).percentage_of(&Fract32::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_fractional_percentage_of() {
+        let expected: Fract32 = Fract32 {
+            numerator: 100,
+            denominator: 3,
+        };
+
+        let actual: Fract32 = Fract32::new(1, 3).percentage_of(&Fract32::new(1, 1));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_create_from_u128() {
+        let expected: Fract32 = Fract32 {
+            numerator: u32::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(Ok(expected), Fract32::try_from_u128(u32::MAX as u128))
+    }
+
+    #[test]
+    fn should_not_create_from_out_of_range_u128() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract32::try_from_u128(u32::MAX as u128 + 1)
+        )
+    }
+
+    #[test]
+    fn should_list_equivalent_forms() {
+        let expected: Vec<Fract32> = vec![
+            Fract32::new(1, 2),
+            Fract32::new(2, 4),
+            Fract32::new(3, 6),
+            Fract32::new(4, 8),
+        ];
+
+        assert_eq!(expected, Fract32::new(2, 4).equivalent_forms(8))
+    }
+
+    #[test]
+    fn should_find_no_equivalent_forms_for_zero_denominator() {
+        assert_eq!(
+            Vec::<Fract32>::new(),
+            Fract32::new(3, 0).equivalent_forms(u32::MAX)
+        );
+        assert_eq!(
+            Vec::<Fract32>::new(),
+            Fract32::new(0, 0).equivalent_forms(u32::MAX)
+        )
+    }
+
+    #[test]
+    fn should_not_list_equivalent_forms_below_reduced_denominator() {
+        assert_eq!(
+            Vec::<Fract32>::new(),
+            Fract32::new(2, 6).equivalent_forms(2)
+        )
+    }
+
+    #[test]
+    fn should_compare_exact() {
+        assert_eq!(
+            Ordering::Equal,
+            Fract32::new(1, 2).cmp_exact(&Fract32::new(2, 4))
+        );
+        assert_eq!(
+            Ordering::Less,
+            Fract32::new(1, 3).cmp_exact(&Fract32::new(1, 2))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Fract32::new(3, 4).cmp_exact(&Fract32::new(2, 3))
+        )
+    }
+
+    #[test]
+    fn should_compare_exact_below_float_precision() {
+        let first: Fract32 = Fract32::new(u32::MAX - 1, u32::MAX);
+        let second: Fract32 = Fract32::new(u32::MAX - 2, u32::MAX - 1);
+
+        assert_eq!(first.to_float(), second.to_float());
+        assert_eq!(Ordering::Greater, first.cmp_exact(&second))
+    }
+
+    #[test]
+    fn should_calculate_abs_diff_float() {
+        let expected: f32 = 0.25;
+
+        assert_approx_eq!(
+            expected,
+            Fract32::new(1, 2).abs_diff_float(&Fract32::new(3, 4))
+        );
+        assert_approx_eq!(
+            expected,
+            Fract32::new(3, 4).abs_diff_float(&Fract32::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_saturate_huge_float() {
+        let expected: Fract32 = Fract32 {
+            numerator: u32::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract32::saturating_from_float(f32::MAX, 10));
+        assert_eq!(expected, Fract32::saturating_from_float(f32::INFINITY, 10))
+    }
+
+    #[test]
+    fn should_saturate_negative_float() {
+        let expected: Fract32 = Fract32 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract32::saturating_from_float(-2.5, 10));
+        assert_eq!(expected, Fract32::saturating_from_float(f32::NAN, 10))
+    }
+
+    #[test]
+    fn should_approximate_float_in_range() {
+        assert_eq!(
+            Fract32::new(1, 10),
+            Fract32::saturating_from_float(0.1, 100)
+        );
+        assert_eq!(
+            Fract32::new(22, 7),
+            Fract32::saturating_from_float(std::f32::consts::PI, 10)
+        );
+        assert_eq!(Fract32::new(5, 2), Fract32::saturating_from_float(2.5, 10))
+    }
+
+    #[test]
+    fn should_cross_multiply() {
+        let first: Fract32 = Fract32::new(2, 3);
+        let second: Fract32 = Fract32::new(5, 7);
+
+        assert_eq!((14, 15), first.cross_multiply(&second))
+    }
+
+    #[test]
+    fn should_cross_multiply_without_overflow() {
+        let first: Fract32 = Fract32::new(u32::MAX, 1);
+        let second: Fract32 = Fract32::new(1, u32::MAX);
+
+        assert_eq!(
+            (u32::MAX as u64 * u32::MAX as u64, 1),
+            first.cross_multiply(&second)
+        )
+    }
+
+    #[test]
+    fn should_expand_continued_fraction() {
+        assert_eq!(vec![2, 3, 1, 4], Fract32::new(43, 19).continued_fraction());
+        assert_eq!(vec![0, 2], Fract32::new(1, 2).continued_fraction())
+    }
+
+    #[test]
+    fn should_create_from_continued_fraction() {
+        let expected: Fract32 = Fract32 {
+            numerator: 43,
+            denominator: 19,
+        };
+
+        assert_eq!(expected, Fract32::from_continued_fraction(&[2, 3, 1, 4]))
+    }
+
+    #[test]
+    fn should_create_zero_from_empty_continued_fraction() {
+        let expected: Fract32 = Fract32 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract32::from_continued_fraction(&[]))
+    }
+
+    #[test]
+    fn should_find_empty_stern_brocot_path() {
+        assert_eq!(Vec::<bool>::new(), Fract32::new(1, 1).stern_brocot_path());
+        assert_eq!(Vec::<bool>::new(), Fract32::new(3, 3).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_find_stern_brocot_path() {
+        assert_eq!(
+            vec![false, true, false],
+            Fract32::new(3, 5).stern_brocot_path()
+        );
+        assert_eq!(vec![true, true], Fract32::new(3, 1).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_reduce_all() {
+        let expected: [Fract32; 2] = [Fract32::new(5, 9), Fract32::new(2, 1)];
+
+        let mut fractions: [Fract32; 2] = [Fract32::new(10, 18), Fract32::new(4, 2)];
+        reduce_all(&mut fractions);
+
+        assert_eq!(expected, fractions)
+    }
+
+    #[test]
+    fn should_add_checked() {
+        let expected: Fract32 = Fract32 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract32::new(1, 2).add_checked(&Fract32::new(9, 10))
+        )
+    }
+
+    #[test]
+    fn should_sub_checked() {
+        let expected: Fract32 = Fract32 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract32::new(4, 2).sub_checked(&Fract32::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract32::new(1, 2).sub_checked(&Fract32::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_div_checked() {
+        let expected: Fract32 = Fract32 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract32::new(1, 2).div_checked(&Fract32::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract32::new(1, 2).div_checked(&Fract32::new(0, 10))
+        )
+    }
+
+    #[test]
+    fn should_checked_assign() {
+        let mut value: Fract32 = Fract32::new(1, 2);
+
+        assert_eq!(Ok(()), value.checked_add_assign(Fract32::new(1, 2)));
+        assert_eq!(Fract32::new(2, 2), value);
+        assert_eq!(Ok(()), value.checked_sub_assign(Fract32::new(1, 2)));
+        assert_eq!(Fract32::new(1, 2), value);
+        assert_eq!(Ok(()), value.checked_mul_assign(Fract32::new(3, 1)));
+        assert_eq!(Fract32::new(3, 2), value);
+        assert_eq!(Ok(()), value.checked_div_assign(Fract32::new(3, 1)));
+        assert_eq!(Fract32::new(3, 6), value)
+    }
+
+    #[test]
+    fn should_leave_value_unchanged_on_failed_assign() {
+        let mut value: Fract32 = Fract32::new(u32::MAX, 2);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_add_assign(Fract32::new(1, 2))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_mul_assign(Fract32::new(2, 1))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            value.checked_div_assign(Fract32::new(0, 1))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_sub_assign(Fract32::new(u32::MAX, 1))
+        );
+        assert_eq!(Fract32::new(u32::MAX, 2), value)
+    }
+
+    #[test]
+    fn should_format_reduced() {
+        assert_eq!("1/2", Fract32::new(4, 8).to_string_reduced());
+        assert_eq!("2", Fract32::new(6, 3).to_string_reduced())
+    }
+
+    #[test]
+    fn should_factor_denominator() {
+        assert_eq!(vec![(2, 3)], Fract32::new(3, 8).denominator_factors());
+        assert_eq!(
+            vec![(2, 1), (3, 1)],
+            Fract32::new(1, 6).denominator_factors()
+        );
+        assert_eq!(vec![(3, 2)], Fract32::new(10, 18).denominator_factors())
+    }
+
+    #[test]
+    fn should_factor_whole_denominator() {
+        assert_eq!(
+            Vec::<(u32, u32)>::new(),
+            Fract32::new(4, 2).denominator_factors()
+        )
+    }
+
+    #[test]
+    fn should_terminate_in_base() {
+        assert!(Fract32::new(3, 8).terminates_in_base(10));
+        assert!(Fract32::new(7, 20).terminates_in_base(10));
+        assert!(Fract32::new(1, 3).terminates_in_base(6));
+        assert!(Fract32::new(5, 12).terminates_in_base(6));
+        assert!(Fract32::new(6, 3).terminates_in_base(7))
+    }
+
+    #[test]
+    fn should_not_terminate_in_base() {
+        assert!(!Fract32::new(1, 3).terminates_in_base(10));
+        assert!(!Fract32::new(1, 10).terminates_in_base(2));
+        assert!(!Fract32::new(1, 5).terminates_in_base(6))
+    }
+
+    #[test]
+    fn should_not_terminate_with_zero_denominator() {
+        assert!(!Fract32::new(3, 0).terminates_in_base(10));
+        assert!(!Fract32::new(0, 0).terminates_in_base(2))
+    }
+
+    #[test]
+    fn should_shift_left() {
+        assert_eq!(Fract32::new(1, 1), Fract32::new(1, 2) << 1);
+        assert_eq!(Fract32::new(12, 1), Fract32::new(3, 4) << 4);
+        assert_eq!(Fract32::new(3, 5), Fract32::new(3, 5) << 0)
+    }
+
+    #[test]
+    fn should_shift_zero_left() {
+        assert_eq!(Fract32::new(0, 1), Fract32::new(0, 1) << u32::BITS);
+        assert_eq!(Fract32::new(0, 3), Fract32::new(0, 3) << 2)
+    }
+
+    #[test]
+    fn should_shift_right() {
+        assert_eq!(Fract32::new(1, 4), Fract32::new(1, 2) >> 1);
+        assert_eq!(Fract32::new(3, 2), Fract32::new(12, 1) >> 3);
+        assert_eq!(Fract32::new(0, 3), Fract32::new(0, 3) >> 2)
+    }
+
+    #[test]
+    fn should_parse() {
+        assert_eq!(Ok(Fract32::new(3, 4)), "3/4".parse::<Fract32>());
+        assert_eq!(Ok(Fract32::new(10, 18)), " 10 / 18 ".parse::<Fract32>());
+        assert_eq!(Ok(Fract32::new(5, 1)), "5".parse::<Fract32>())
+    }
+
+    #[test]
+    fn should_not_parse_malformed() {
+        assert_eq!(Err(FractError::InvalidFormat), "a/4".parse::<Fract32>());
+        assert_eq!(Err(FractError::InvalidFormat), "3/".parse::<Fract32>());
+        assert_eq!(Err(FractError::InvalidFormat), "-3/4".parse::<Fract32>());
+        assert_eq!(Err(FractError::InvalidFormat), "1/2/3".parse::<Fract32>());
+        assert_eq!(Err(FractError::ZeroDenominator), "3/0".parse::<Fract32>())
+    }
+
+    #[test]
+    fn should_parse_list() {
+        let expected: Vec<Fract32> =
+            vec![Fract32::new(1, 2), Fract32::new(3, 4), Fract32::new(5, 6)];
+
+        assert_eq!(Ok(expected), parse_list("1/2, 3/4, 5/6"));
+        assert_eq!(Ok(Vec::new()), parse_list(" "))
+    }
+
+    #[test]
+    fn should_not_parse_list_with_bad_entry() {
+        assert_eq!(
+            Err(FractError::InvalidEntry(1)),
+            parse_list("1/2, 3/x, 5/6")
+        )
+    }
+
+    #[test]
+    fn should_find_proper_part() {
+        assert_eq!(Fract32::new(1, 2), Fract32::new(7, 2).proper_part());
+        assert_eq!(Fract32::new(0, 1), Fract32::new(4, 2).proper_part());
+        assert_eq!(Fract32::new(5, 9), Fract32::new(10, 18).proper_part())
+    }
+
+    #[test]
+    fn should_dedup_by_value() {
+        let expected: Vec<Fract32> = vec![Fract32::new(1, 3), Fract32::new(1, 2)];
+
+        let mut fractions: Vec<Fract32> =
+            vec![Fract32::new(2, 4), Fract32::new(1, 2), Fract32::new(1, 3)];
+        dedup_by_value(&mut fractions);
+
+        assert_eq!(expected, fractions)
+    }
+
+    #[test]
+    fn should_not_reduce_zero_over_zero() {
+        let expected: Fract32 = Fract32 {
+            numerator: 0,
+            denominator: 0,
+        };
+
+        assert_eq!(expected, Fract32::new(0, 0).reduce())
+    }
+
+    #[test]
+    fn should_find_integer_scale() {
+        assert_eq!(9, Fract32::new(10, 18).integer_scale());
+        assert_eq!(1, Fract32::new(6, 3).integer_scale())
+    }
+
+    #[test]
+    fn should_find_scaled_numerator() {
+        assert_eq!(5, Fract32::new(10, 18).scaled_numerator());
+        assert_eq!(2, Fract32::new(6, 3).scaled_numerator())
+    }
+
+    #[test]
+    fn should_convert_into_float() {
+        assert_approx_eq!(0.5, f32::from(Fract32::new(1, 2)));
+
+        let actual: f32 = Fract32::new(3, 4).into();
+        assert_approx_eq!(0.75, actual)
+    }
+
+    #[test]
+    fn should_raise_to_float_power() {
+        assert_approx_eq!(1.5, Fract32::new(9, 4).powf(0.5));
+        assert_approx_eq!(0.125, Fract32::new(1, 2).powf(3.0))
+    }
+
+    #[test]
+    fn should_approximate_float_power() {
+        assert_eq!(Fract32::new(3, 2), Fract32::new(9, 4).powf_approx(0.5, 100));
+        assert_eq!(Fract32::new(7, 5), Fract32::new(2, 1).powf_approx(0.5, 10))
+    }
+
+    #[test]
+    fn should_approximate_to_fract8() {
+        assert_eq!(
+            Fract8::new(u8::MAX, 2),
+            Fract32::new(u8::MAX as u32 * 2, 4).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(1, 1),
+            Fract32::new(u32::MAX, u32::MAX - 1).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(u8::MAX, 1),
+            Fract32::new(u32::MAX, 1).to_fract8_approx()
+        )
+    }
+
+    #[test]
+    fn should_approximate_to_fract16() {
+        assert_eq!(
+            Fract16::new(u16::MAX, 2),
+            Fract32::new(u16::MAX as u32 * 2, 4).to_fract16_approx()
+        );
+        assert_eq!(
+            Fract16::new(1, 1),
+            Fract32::new(u32::MAX, u32::MAX - 1).to_fract16_approx()
+        );
+        assert_eq!(
+            Fract16::new(u16::MAX, 1),
+            Fract32::new(u32::MAX, 1).to_fract16_approx()
+        )
+    }
+
+    #[test]
+    fn should_display() {
+        assert_eq!("3/4", format!("{}", Fract32::new(3, 4)));
+        assert_eq!("4/8", format!("{}", Fract32::new(4, 8)));
+        assert_eq!("5", format!("{}", Fract32::new(5, 1)));
+        assert_eq!("0", format!("{}", Fract32::new(0, 1)))
+    }
+
+    #[test]
+    fn should_find_largest_unit_fraction() {
+        assert_eq!(
+            Fract32::new(1, 2),
+            Fract32::new(3, 4).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract32::new(1, 3),
+            Fract32::new(2, 5).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract32::new(1, 4),
+            Fract32::new(2, 8).largest_unit_fraction()
+        )
+    }
+
+    #[test]
+    fn should_decompose_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract32::new(1, 2), Fract32::new(1, 4)]),
+            Fract32::new(3, 4).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract32::new(1, 2), Fract32::new(1, 3)]),
+            Fract32::new(5, 6).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract32::new(1, 7)]),
+            Fract32::new(2, 14).egyptian_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_whole_part_from_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract32::new(2, 1), Fract32::new(1, 2)]),
+            Fract32::new(5, 2).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract32::new(3, 1)]),
+            Fract32::new(6, 2).egyptian_fraction()
+        );
+        assert_eq!(Some(vec![]), Fract32::new(0, 4).egyptian_fraction());
+        assert_eq!(None, Fract32::new(1, 0).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_not_decompose_egyptian_fraction_on_overflow() {
+        assert_eq!(None, Fract32::new(5, 121).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_compare_safely() {
+        assert_eq!(
+            Some(Ordering::Less),
+            Fract32::new(1, 3).safe_cmp(&Fract32::new(1, 2))
+        );
+        assert_eq!(
+            Some(Ordering::Equal),
+            Fract32::new(2, 4).safe_cmp(&Fract32::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_compare_zero_denominator() {
+        assert_eq!(None, Fract32::new(1, 0).safe_cmp(&Fract32::new(1, 2)));
+        assert_eq!(None, Fract32::new(1, 2).safe_cmp(&Fract32::new(0, 0)))
+    }
+
+    #[test]
+    fn should_default_to_zero() {
+        assert_eq!(Fract32::new(0, 1), Fract32::default())
+    }
+
+    #[test]
+    fn should_build_with_numerator_and_denominator() {
+        let expected: Fract32 = Fract32 {
+            numerator: 3,
+            denominator: 4,
+        };
+
+        let actual: Fract32 = Fract32::default().with_numerator(3).with_denominator(4);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_build_invalid_with_zero_denominator() {
+        let actual: Fract32 = Fract32::new(3, 4).with_denominator(0);
+
+        assert_eq!(Fract32::new(3, 0), actual);
+        assert_eq!(None, actual.safe_cmp(&Fract32::new(3, 4)))
+    }
+
+    #[test]
+    fn should_parse_with_radix() {
+        let expected: Fract32 = Fract32 {
+            numerator: 255,
+            denominator: 16,
+        };
+
+        assert_eq!(Ok(expected), Fract32::from_str_radix("ff/10", 16));
+        assert_eq!(Ok(Fract32::new(5, 2)), Fract32::from_str_radix("101/10", 2))
+    }
+
+    #[test]
+    fn should_not_parse_invalid_digit_with_radix() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract32::from_str_radix("fg/10", 16)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract32::from_str_radix("12/10", 2)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract32::from_str_radix("1/1", 37)
+        )
+    }
+
+    #[test]
+    fn should_not_parse_overflow() {
+        let too_large: String = format!("{}0", u32::MAX);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            format!("{}/1", too_large).parse::<Fract32>()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            format!("1/{}", too_large).parse::<Fract32>()
+        )
+    }
+
+    #[test]
+    fn should_convert_to_basis_points() {
+        assert_eq!(5000, Fract32::new(1, 2).to_basis_points());
+        assert_eq!(2500, Fract32::new(1, 4).to_basis_points());
+        assert_eq!(3333, Fract32::new(1, 3).to_basis_points());
+        assert_eq!(6667, Fract32::new(2, 3).to_basis_points());
+        assert_eq!(25000, Fract32::new(5, 2).to_basis_points())
+    }
+
+    #[test]
+    fn should_round_half_up_to_basis_points() {
+        assert_eq!(313, Fract32::new(1, 32).to_basis_points());
+        assert_eq!(156, Fract32::new(1, 64).to_basis_points());
+        assert_eq!(1667, Fract32::new(1, 6).to_basis_points())
+    }
+
+    #[test]
+    fn should_convert_exactly_to_float() {
+        assert_eq!(Ok(0.5), Fract32::new(1, 2).to_float_exact());
+        assert_eq!(Ok(0.75), Fract32::new(6, 8).to_float_exact());
+        assert_eq!(Ok(0.0), Fract32::new(0, 3).to_float_exact())
+    }
+
+    #[test]
+    fn should_flag_inexact_float() {
+        assert_eq!(Err(1.0 / 3.0), Fract32::new(1, 3).to_float_exact());
+        assert!(Fract32::new(1, 0).to_float_exact().is_err())
+    }
+
+    #[test]
+    fn should_flag_numerator_beyond_mantissa() {
+        assert!(Fract32::new((1 << f32::MANTISSA_DIGITS) + 1, 1)
+            .to_float_exact()
+            .is_err());
+        assert!(Fract32::new(1 << f32::MANTISSA_DIGITS, 1)
+            .to_float_exact()
+            .is_ok())
+    }
+
+    #[test]
+    fn should_convert_unreduced_exactly() {
+        let k: u32 = 16_777_217;
+        assert_eq!(Ok(0.75), Fract32::new(3 * k, 4 * k).to_float_exact())
+    }
+
+    #[test]
+    fn should_add_integer() {
+        assert_eq!(Fract32::new(3, 2), Fract32::new(1, 2) + 1);
+        assert_eq!(Fract32::new(3, 2), 1 + Fract32::new(1, 2))
+    }
+
+    #[test]
+    fn should_sub_integer() {
+        assert_eq!(Fract32::new(1, 2), Fract32::new(3, 2) - 1)
+    }
+
+    #[test]
+    fn should_mul_integer() {
+        assert_eq!(Fract32::new(3, 2), Fract32::new(1, 2) * 3);
+        assert_eq!(Fract32::new(3, 2), 3 * Fract32::new(1, 2))
+    }
+
+    #[test]
+    fn should_div_integer() {
+        assert_eq!(Fract32::new(1, 6), Fract32::new(1, 2) / 3)
+    }
+
+    #[test]
+    fn should_return_raw_components() {
+        assert_eq!((2, 4), Fract32::new(2, 4).components());
+        assert_eq!((3, 0), Fract32::new(3, 0).components())
+    }
+
+    #[test]
+    fn should_expand_slice_to_common_denominator() {
+        let fractions: [Fract32; 3] = [Fract32::new(1, 2), Fract32::new(1, 3), Fract32::new(5, 6)];
+        assert_eq!(
+            Ok(vec![
+                Fract32::new(3, 6),
+                Fract32::new(2, 6),
+                Fract32::new(5, 6)
+            ]),
+            Fract32::common_denominator(&fractions)
+        );
+        assert_eq!(Ok(vec![]), Fract32::common_denominator(&[]))
+    }
+
+    #[test]
+    fn should_not_overflow_common_denominator() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract32::common_denominator(&[
+                Fract32::new(1, u32::MAX),
+                Fract32::new(1, u32::MAX - 1)
+            ])
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract32::common_denominator(&[Fract32::new(1, 2), Fract32::new(1, 0)])
+        )
+    }
+
+    #[test]
+    fn should_div_rem_fract() {
+        assert_eq!(
+            Some((3, Fract32::new(1, 2))),
+            Fract32::new(7, 2).div_rem_fract(&Fract32::new(1, 1))
+        );
+        assert_eq!(
+            Some((10, Fract32::new(1, 6))),
+            Fract32::new(7, 2).div_rem_fract(&Fract32::new(1, 3))
+        );
+        assert_eq!(
+            Some((0, Fract32::new(1, 4))),
+            Fract32::new(1, 4).div_rem_fract(&Fract32::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_div_rem_fract_by_zero() {
+        assert_eq!(None, Fract32::new(7, 2).div_rem_fract(&Fract32::new(0, 1)))
+    }
+
+    #[test]
+    fn should_reduce_in_place() {
+        let mut fraction: Fract32 = Fract32::new(10, 18);
+        fraction.reduce_mut();
+        assert_eq!(Fract32::new(5, 9), fraction);
+
+        let mut zero: Fract32 = Fract32::new(0, 18);
+        zero.reduce_mut();
+        assert_eq!(Fract32::new(0, 1), zero)
+    }
+
+    #[test]
+    fn should_display_alternate_with_decimal() {
+        let fraction: Fract32 = Fract32::new(3, 4);
+        assert_eq!("3/4", format!("{}", fraction));
+        assert_eq!("3/4 (0.75)", format!("{:#}", fraction));
+        assert_eq!("3 (3)", format!("{:#}", Fract32::new(3, 1)))
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_exact_floats() {
+        assert_eq!(0, Fract32::new(1, 2).float_ulp_error());
+        assert_eq!(0, Fract32::new(3, 8).float_ulp_error());
+        assert_eq!(0, Fract32::new(0, 5).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_correctly_rounded_floats() {
+        assert_eq!(0, Fract32::new(1, 3).float_ulp_error());
+        assert_eq!(0, Fract32::new(2, 7).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_ulp_error_of_rounded_numerator() {
+        assert_eq!(1, Fract32::new(16777217, 5).float_ulp_error())
+    }
+
+    #[test]
+    fn should_reduce_into_fract8() {
+        assert_eq!(
+            Ok(Fract8::new(1, 2)),
+            Fract32::new(100, 200).reduce_into_fract8()
+        );
+        assert_eq!(
+            Ok(Fract8::new(u8::MAX, 1)),
+            Fract32::new(u8::MAX as u32 * 2, 2).reduce_into_fract8()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract8() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract32::new(u8::MAX as u32 + 1, 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract32::new(1, u8::MAX as u32 + 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract8::try_from(Fract32::new(2, u8::MAX as u32 * 2))
+        )
+    }
+
+    #[test]
+    fn should_reduce_into_fract16() {
+        assert_eq!(
+            Ok(Fract16::new(1, 2)),
+            Fract32::new(100, 200).reduce_into_fract16()
+        );
+        assert_eq!(
+            Ok(Fract16::new(u16::MAX, 1)),
+            Fract32::new(u16::MAX as u32 * 2, 2).reduce_into_fract16()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract16() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract32::new(u16::MAX as u32 + 1, 1).reduce_into_fract16()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract32::new(1, u16::MAX as u32 + 1).reduce_into_fract16()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract16::try_from(Fract32::new(2, u16::MAX as u32 * 2))
+        )
+    }
+
+    #[test]
+    fn should_find_modular_inverse() {
+        assert_eq!(Some(5), Fract32::new(3, 7).modular_inverse());
+        assert_eq!(Some(3), Fract32::new(7, 10).modular_inverse())
+    }
+
+    #[test]
+    fn should_not_find_modular_inverse() {
+        assert_eq!(None, Fract32::new(4, 8).modular_inverse());
+        assert_eq!(None, Fract32::new(3, 0).modular_inverse())
+    }
+
+    #[test]
+    fn should_round_trip_le_bytes() {
+        let fraction: Fract32 = Fract32::new(3, 250);
+        assert_eq!(fraction, Fract32::from_le_bytes(fraction.to_le_bytes()));
+        assert_eq!(
+            Fract32::new(u32::MAX, 1),
+            Fract32::from_le_bytes(Fract32::new(u32::MAX, 1).to_le_bytes())
+        )
+    }
+
+    #[test]
+    fn should_checked_add_int() {
+        assert_eq!(
+            Some(Fract32::new(3, 2)),
+            Fract32::new(1, 2).checked_add_int(1)
+        );
+        assert_eq!(
+            Some(Fract32::new(7, 1)),
+            Fract32::new(2, 1).checked_add_int(5)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_add_int_on_overflow() {
+        assert_eq!(None, Fract32::new(u32::MAX, 1).checked_add_int(1));
+        assert_eq!(None, Fract32::new(1, 2).checked_add_int(u32::MAX / 2 + 1))
+    }
+
+    #[test]
+    fn should_display_exact_within_bound() {
+        assert_eq!("1/2", Fract32::new(1, 2).display_approx(4));
+        assert_eq!("1/2", Fract32::new(2, 4).display_approx(2))
+    }
+
+    #[test]
+    fn should_display_approx_beyond_bound() {
+        assert_eq!("≈1/2", Fract32::new(127, 255).display_approx(4));
+        assert_eq!("≈1", Fract32::new(99, 100).display_approx(3))
+    }
+
+    #[test]
+    fn should_convert_float_within_iteration_cap() {
+        assert_eq!(
+            Ok(Fract32::new(3, 4)),
+            Fract32::from_float_capped(0.75, 10, 100)
+        );
+        assert_eq!(
+            Ok(Fract32::new(22, 7)),
+            Fract32::from_float_capped(std::f64::consts::PI, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_not_convert_float_past_iteration_cap() {
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract32::from_float_capped(std::f64::consts::PI, 2, 200)
+        );
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract32::from_float_capped(0.5, 1, 10)
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract32::from_float_capped(-0.5, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_find_common_factor() {
+        assert_eq!(
+            10,
+            common_factor(&[Fract32::new(10, 20), Fract32::new(30, 40)])
+        );
+        assert_eq!(
+            1,
+            common_factor(&[Fract32::new(10, 20), Fract32::new(3, 40)])
+        );
+        assert_eq!(0, common_factor(&[]))
+    }
+
+    #[test]
+    fn should_sum_reduced() {
+        let halves: Vec<Fract32> = vec![Fract32::new(1, 2); 40];
+        assert_eq!(Some(Fract32::new(20, 1)), Fract32::sum_reduced(halves));
+        assert_eq!(
+            Some(Fract32::new(1, 6)),
+            Fract32::sum_reduced(vec![Fract32::new(1, 10), Fract32::new(1, 15)])
+        );
+        assert_eq!(Some(Fract32::new(0, 1)), Fract32::sum_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_sum_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract32::sum_reduced(vec![Fract32::new(u32::MAX, 1), Fract32::new(1, 1)])
+        );
+        assert_eq!(None, Fract32::sum_reduced(vec![Fract32::new(1, 0)]))
+    }
+
+    #[test]
+    fn should_product_reduced() {
+        let pairs: Vec<Fract32> = [Fract32::new(1, 2), Fract32::new(4, 2)].repeat(50);
+        assert_eq!(Some(Fract32::new(1, 1)), Fract32::product_reduced(pairs));
+        assert_eq!(
+            Some(Fract32::new(2, 5)),
+            Fract32::product_reduced(vec![Fract32::new(4, 6), Fract32::new(3, 5)])
+        );
+        assert_eq!(Some(Fract32::new(1, 1)), Fract32::product_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_product_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract32::product_reduced(vec![Fract32::new(u32::MAX, 1), Fract32::new(2, 1)])
+        )
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_terminating_fraction() {
+        assert_eq!(1, Fract32::new(1, 8).decimal_digit_at(1));
+        assert_eq!(2, Fract32::new(1, 8).decimal_digit_at(2));
+        assert_eq!(5, Fract32::new(1, 8).decimal_digit_at(3));
+        assert_eq!(0, Fract32::new(1, 8).decimal_digit_at(4));
+        assert_eq!(2, Fract32::new(25, 2).decimal_digit_at(0))
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_repeating_fraction() {
+        assert_eq!(3, Fract32::new(1, 3).decimal_digit_at(1));
+        assert_eq!(3, Fract32::new(1, 3).decimal_digit_at(50));
+        assert_eq!(7, Fract32::new(1, 7).decimal_digit_at(6));
+        assert_eq!(1, Fract32::new(1, 7).decimal_digit_at(7))
+    }
+
+    #[test]
+    fn should_reduce_checked() {
+        assert_eq!(
+            (Fract32::new(5, 9), true),
+            Fract32::new(10, 18).reduce_checked()
+        );
+        assert_eq!(
+            (Fract32::new(5, 9), false),
+            Fract32::new(5, 9).reduce_checked()
+        );
+        assert_eq!(
+            (Fract32::new(0, 1), true),
+            Fract32::new(0, 5).reduce_checked()
+        )
+    }
+
+    #[test]
+    fn should_create_from_bool() {
+        assert_eq!(Fract32::new(1, 1), Fract32::from(true));
+        assert_eq!(Fract32::new(0, 1), Fract32::from(false))
+    }
+
+    #[test]
+    fn should_scale() {
+        assert_eq!(Some(Fract32::new(3, 6)), Fract32::new(1, 2).scale(3));
+        assert_eq!(Some(Fract32::new(0, 0)), Fract32::new(1, 2).scale(0))
+    }
+
+    #[test]
+    fn should_not_scale_on_overflow() {
+        assert_eq!(None, Fract32::new(1, u32::MAX / 2 + 1).scale(2));
+        assert_eq!(None, Fract32::new(u32::MAX, 1).scale(2))
+    }
+
+    #[test]
+    fn should_format_sig_figs() {
+        assert_eq!("0.3333", Fract32::new(1, 3).to_sig_figs(4));
+        assert_eq!("3.14", Fract32::new(22, 7).to_sig_figs(3));
+        assert_eq!("0.667", Fract32::new(2, 3).to_sig_figs(3));
+        assert_eq!("", Fract32::new(2, 0).to_sig_figs(3))
+    }
+
+    #[test]
+    fn should_debug_fields() {
+        assert_eq!(
+            "Fract32 { numerator: 10, denominator: 18 }",
+            format!("{:?}", Fract32::new(10, 18))
+        )
+    }
+
+    #[test]
+    fn should_debug_alternate_with_reduced_value() {
+        assert_eq!(
+            "Fract32 { numerator: 1, denominator: 2 }",
+            format!("{:?}", Fract32::new(1, 2))
+        );
+        assert_eq!(
+            "Fract32 { numerator: 10, denominator: 20, reduced: 1/2, value: 0.5 }",
+            format!("{:#?}", Fract32::new(10, 20))
+        )
+    }
+
+    #[test]
+    fn should_clamp_to_one() {
+        assert_eq!(Fract32::new(1, 1), Fract32::new(3, 2).clamp01());
+        assert_eq!(Fract32::new(1, 2), Fract32::new(1, 2).clamp01());
+        assert_eq!(Fract32::new(4, 4), Fract32::new(4, 4).clamp01())
+    }
+
+    #[test]
+    fn should_find_period_length() {
+        assert_eq!(Some(0), Fract32::new(1, 2).period_length());
+        assert_eq!(Some(0), Fract32::new(3, 40).period_length());
+        assert_eq!(Some(1), Fract32::new(1, 3).period_length());
+        assert_eq!(Some(6), Fract32::new(1, 7).period_length());
+        assert_eq!(Some(6), Fract32::new(1, 14).period_length());
+        assert_eq!(Some(2), Fract32::new(1, 11).period_length());
+        assert_eq!(Some(0), Fract32::new(7, 7).period_length())
+    }
+
+    #[test]
+    fn should_fit_in_fract8() {
+        assert!(Fract32::new(100, 200).fits_in_fract8());
+        assert!(Fract32::new(u8::MAX as u32 * 2, 2).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract8() {
+        assert!(!Fract32::new(u8::MAX as u32 + 1, 1).fits_in_fract8());
+        assert!(!Fract32::new(1, u8::MAX as u32 + 1).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_fit_in_fract16() {
+        assert!(Fract32::new(100, 200).fits_in_fract16());
+        assert!(Fract32::new(u16::MAX as u32 * 2, 2).fits_in_fract16())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract16() {
+        assert!(!Fract32::new(u16::MAX as u32 + 1, 1).fits_in_fract16());
+        assert!(!Fract32::new(1, u16::MAX as u32 + 1).fits_in_fract16())
+    }
+
+    #[test]
+    fn should_mul_with_cross_cancellation() {
+        assert_eq!(
+            Fract32::new(1, 1),
+            Fract32::new(u32::MAX, 3) * Fract32::new(3, u32::MAX)
+        );
+        assert_eq!(
+            Fract32::new(2, 1),
+            Fract32::new(100, 9) * Fract32::new(9, 50)
+        )
+    }
+
+    #[test]
+    fn should_div_with_cross_cancellation() {
+        assert_eq!(
+            Fract32::new(2, 1),
+            Fract32::new(u32::MAX, 2) / Fract32::new(u32::MAX, 4)
+        );
+        assert_eq!(
+            Fract32::new(2, 3),
+            Fract32::new(200, 3) / Fract32::new(100, 1)
+        );
+        assert_eq!(Fract32::new(1, 4), Fract32::new(4, 6) / Fract32::new(8, 3))
+    }
+
+    #[test]
+    fn should_validate() {
+        assert_eq!(Ok(()), Fract32::new(1, 2).validate());
+        assert_eq!(Ok(()), Fract32::new(0, 3).validate())
+    }
+
+    #[test]
+    fn should_not_validate_zero_denominator() {
+        let fraction: Fract32 = Fract32 {
+            numerator: 1,
+            denominator: 0,
+        };
+
+        assert_eq!(Err(FractError::ZeroDenominator), fraction.validate())
+    }
+
+    #[test]
+    fn should_find_rounded_decimal_digits() {
+        assert_eq!(
+            vec![3, 3, 3, 3],
+            Fract32::new(1, 3).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![6, 7], Fract32::new(2, 3).decimal_digits_rounded(2));
+        assert_eq!(
+            vec![1, 2, 5, 0],
+            Fract32::new(1, 8).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![1, 3], Fract32::new(1, 8).decimal_digits_rounded(2))
+    }
+
+    #[test]
+    fn should_drop_carry_of_rounded_decimal_digits() {
+        assert_eq!(vec![0, 0], Fract32::new(199, 200).decimal_digits_rounded(2));
+        assert_eq!(
+            Vec::<u8>::new(),
+            Fract32::new(2, 3).decimal_digits_rounded(0)
+        )
+    }
+
+    #[test]
+    fn should_align_numerators() {
+        assert_eq!(
+            Ok((6, vec![3, 2])),
+            align_numerators(&[Fract32::new(1, 2), Fract32::new(1, 3)])
+        );
+        assert_eq!(
+            Ok((12, vec![9, 10, 6])),
+            align_numerators(&[Fract32::new(3, 4), Fract32::new(5, 6), Fract32::new(2, 4)])
+        );
+        assert_eq!(Ok((1, vec![])), align_numerators(&[]))
+    }
+
+    #[test]
+    fn should_not_align_numerators_on_overflow_or_zero() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            align_numerators(&[Fract32::new(1, 65536), Fract32::new(1, 65537)])
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            align_numerators(&[Fract32::new(1, 0), Fract32::new(1, 2)])
+        )
+    }
+
+    #[test]
+    fn should_reduce_fast_paths() {
+        assert_eq!(Fract32::new(1, 9), Fract32::new(1, 9).reduce());
+        assert_eq!(Fract32::new(9, 1), Fract32::new(9, 1).reduce());
+        assert_eq!(Fract32::new(0, 1), Fract32::new(0, 9).reduce());
+        assert_eq!(Fract32::new(0, 0), Fract32::new(0, 0).reduce());
+        assert_eq!(Fract32::new(1, 0), Fract32::new(1, 0).reduce())
+    }
+
+    #[test]
+    fn should_hash_consistently_across_widths() {
+        fn hash_of<T: Hash>(value: T) -> u64 {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash_of(Fract8::new(1, 2)), hash_of(Fract32::new(1, 2)));
+        assert_eq!(hash_of(Fract32::new(1, 2)), hash_of(Fract32::new(2, 4)));
+        assert_ne!(hash_of(Fract32::new(1, 2)), hash_of(Fract32::new(1, 3)))
+    }
+
+    #[test]
+    fn should_find_minimal_form() {
+        assert_eq!(Fract32::new(1, 2), Fract32::new(50, 100).minimal());
+        assert_eq!(Fract32::new(0, 1), Fract32::new(0, 7).minimal());
+        assert_eq!(Fract32::new(3, 4), Fract32::new(3, 4).minimal())
+    }
+
+    #[test]
+    fn should_checked_shl() {
+        assert_eq!(Some(Fract32::new(3, 1)), Fract32::new(3, 4).checked_shl(2));
+        assert_eq!(Some(Fract32::new(12, 1)), Fract32::new(3, 1).checked_shl(2));
+        assert_eq!(
+            Some(Fract32::new(0, 5)),
+            Fract32::new(0, 5).checked_shl(200)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_shl_on_overflow() {
+        assert_eq!(None, Fract32::new(u32::MAX / 2 + 1, 1).checked_shl(1));
+        assert_eq!(None, Fract32::new(1, 1).checked_shl(u32::BITS))
+    }
+
+    #[test]
+    fn should_checked_shr() {
+        assert_eq!(Some(Fract32::new(3, 1)), Fract32::new(12, 1).checked_shr(2));
+        assert_eq!(Some(Fract32::new(3, 4)), Fract32::new(3, 1).checked_shr(2))
+    }
+
+    #[test]
+    fn should_not_checked_shr_on_overflow() {
+        assert_eq!(None, Fract32::new(1, u32::MAX / 2 + 1).checked_shr(1))
+    }
+
+    #[test]
+    fn should_reduce_keeping_zero_denominator() {
+        assert_eq!(
+            Fract32::new(0, 6),
+            Fract32::new(0, 6).reduce_keep_denominator()
+        );
+        assert_eq!(
+            Fract32::new(5, 9),
+            Fract32::new(10, 18).reduce_keep_denominator()
+        )
+    }
+
+    #[test]
+    fn should_format_mixed_string() {
+        assert_eq!("3 1/2", Fract32::new(7, 2).to_mixed_string());
+        assert_eq!("1/2", Fract32::new(1, 2).to_mixed_string());
+        assert_eq!("2", Fract32::new(6, 3).to_mixed_string());
+        assert_eq!("1 1/3", Fract32::new(8, 6).to_mixed_string());
+        assert_eq!("0", Fract32::new(0, 4).to_mixed_string())
+    }
+
+    #[test]
+    fn should_find_ratio() {
+        assert_eq!(
+            Some(Fract32::new(3, 2)),
+            Fract32::new(3, 4).ratio_to(&Fract32::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract32::new(1, 1)),
+            Fract32::new(6, 8).ratio_to(&Fract32::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_not_find_ratio_to_zero() {
+        assert_eq!(None, Fract32::new(3, 4).ratio_to(&Fract32::new(0, 1)))
+    }
+
+    #[test]
+    fn should_be_between() {
+        assert!(Fract32::new(1, 2).is_between(&Fract32::new(0, 1), &Fract32::new(1, 1)));
+        assert!(Fract32::new(1, 2).is_between(&Fract32::new(1, 1), &Fract32::new(0, 1)));
+        assert!(Fract32::new(2, 4).is_between(&Fract32::new(1, 2), &Fract32::new(3, 4)))
+    }
+
+    #[test]
+    fn should_not_be_between() {
+        assert!(!Fract32::new(1, 2).is_between(&Fract32::new(3, 4), &Fract32::new(1, 1)))
+    }
+
+    #[test]
+    fn should_find_convergents() {
+        let convergents: Vec<Fract32> = Fract32::new(43, 19).convergents();
+        assert_eq!(Fract32::new(2, 1), convergents[0]);
+        assert_eq!(Fract32::new(7, 3), convergents[1]);
+        assert_eq!(
+            vec![
+                Fract32::new(2, 1),
+                Fract32::new(7, 3),
+                Fract32::new(9, 4),
+                Fract32::new(43, 19)
+            ],
+            convergents
+        );
+        assert_eq!(
+            Some(&Fract32::new(43, 19)),
+            Fract32::new(86, 38).convergents().last()
+        )
+    }
+
+    #[test]
+    fn should_find_convergents_of_edge_values() {
+        assert_eq!(
+            vec![Fract32::new(0, 1), Fract32::new(1, 3)],
+            Fract32::new(1, 3).convergents()
+        );
+        assert_eq!(
+            vec![Fract32::new(u32::MAX, 1)],
+            Fract32::new(u32::MAX, 1).convergents()
+        );
+        assert_eq!(Vec::<Fract32>::new(), Fract32::new(1, 0).convergents())
+    }
+
+    #[test]
+    fn should_cmp_with_float_exactly() {
+        assert_eq!(Ordering::Equal, Fract32::new(1, 2).cmp_with_float(0.5));
+        assert_eq!(Ordering::Less, Fract32::new(1, 2).cmp_with_float(0.75));
+        assert_eq!(Ordering::Greater, Fract32::new(3, 2).cmp_with_float(1.25))
+    }
+
+    #[test]
+    fn should_cmp_with_float_next_to_one_third() {
+        let third: Fract32 = Fract32::new(1, 3);
+        let nearest: f64 = (1.0f32 / 3.0) as f64;
+        assert_eq!(nearest, third.to_float() as f64);
+        assert_eq!(Ordering::Less, third.cmp_with_float(nearest))
+    }
+
+    #[test]
+    fn should_split_integer_and_fraction() {
+        assert_eq!(
+            (Fract32::new(3, 1), Fract32::new(1, 2)),
+            Fract32::new(7, 2).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract32::new(2, 1), Fract32::new(0, 1)),
+            Fract32::new(6, 3).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract32::new(0, 1), Fract32::new(2, 3)),
+            Fract32::new(4, 6).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_zero_denominator_into_itself() {
+        assert_eq!(
+            (Fract32::new(1, 0), Fract32::new(0, 1)),
+            Fract32::new(1, 0).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_snap_to_closest() {
+        let allowed: [Fract32; 3] = [Fract32::new(1, 2), Fract32::new(3, 4), Fract32::new(1, 1)];
+        assert_eq!(
+            Some(Fract32::new(1, 2)),
+            Fract32::new(9, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract32::new(3, 4)),
+            Fract32::new(11, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract32::new(1, 1)),
+            Fract32::new(3, 2).snap_to(&allowed)
+        )
+    }
+
+    #[test]
+    fn should_snap_ties_to_first() {
+        let allowed: [Fract32; 2] = [Fract32::new(1, 2), Fract32::new(3, 4)];
+        assert_eq!(
+            Some(Fract32::new(1, 2)),
+            Fract32::new(5, 8).snap_to(&allowed)
+        );
+        let reversed: [Fract32; 2] = [Fract32::new(3, 4), Fract32::new(1, 2)];
+        assert_eq!(
+            Some(Fract32::new(3, 4)),
+            Fract32::new(5, 8).snap_to(&reversed)
+        )
+    }
+
+    #[test]
+    fn should_not_snap_to_empty_slice() {
+        assert_eq!(None, Fract32::new(5, 8).snap_to(&[]))
+    }
+
+    #[test]
+    fn should_try_from_signed_pair() {
+        assert_eq!(Ok(Fract32::new(2, 3)), Fract32::try_from((10i64, 15i64)));
+        assert_eq!(Ok(Fract32::new(0, 1)), Fract32::try_from((0i64, 7i64)))
+    }
+
+    #[test]
+    fn should_not_try_from_negative_pair() {
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract32::try_from((-1i64, 2i64))
+        );
+        assert_eq!(
+            Err(FractError::DenominatorUnderflow),
+            Fract32::try_from((1i64, -2i64))
+        )
+    }
+
+    #[test]
+    fn should_not_try_from_signed_zero_denominator() {
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract32::try_from((1i64, 0i64))
+        )
+    }
+
+    #[test]
+    fn should_render_in_radix() {
+        assert_eq!("0.1", Fract32::new(1, 2).to_string_radix(2, 10));
+        assert_eq!("0.1", Fract32::new(1, 3).to_string_radix(3, 10));
+        assert_eq!("0.0101", Fract32::new(1, 3).to_string_radix(2, 4));
+        assert_eq!("12", Fract32::new(10, 2).to_string_radix(3, 4))
+    }
+
+    #[test]
+    fn should_format_continued_fraction() {
+        assert_eq!(
+            "[2; 3, 1, 4]",
+            Fract32::new(43, 19).continued_fraction_string()
+        );
+        assert_eq!("[0; 2]", Fract32::new(1, 2).continued_fraction_string());
+        assert_eq!("[5]", Fract32::new(5, 1).continued_fraction_string())
+    }
+
+    #[test]
+    fn should_find_reduced_reciprocal() {
+        assert_eq!(
+            Some(Fract32::new(9, 5)),
+            Fract32::new(10, 18).reciprocal_reduced()
+        );
+        assert_eq!(
+            Some(Fract32::new(1, 3)),
+            Fract32::new(3, 1).reciprocal_reduced()
+        )
+    }
+
+    #[test]
+    fn should_not_find_reciprocal_of_zero() {
+        assert_eq!(None, Fract32::new(0, 5).reciprocal_reduced())
+    }
+
+    #[test]
+    fn should_find_cross_difference() {
+        assert_eq!(-1, Fract32::new(1, 2).cross_difference(&Fract32::new(2, 3)));
+        assert_eq!(1, Fract32::new(2, 3).cross_difference(&Fract32::new(1, 2)));
+        assert_eq!(0, Fract32::new(2, 4).cross_difference(&Fract32::new(1, 2)));
+        assert_eq!(
+            u32::MAX as i128,
+            Fract32::new(u32::MAX, 1).cross_difference(&Fract32::new(0, 1))
+        )
+    }
+
+    #[test]
+    fn should_find_simplest_between() {
+        assert_eq!(
+            Some(Fract32::new(2, 5)),
+            Fract32::new(1, 3).simplest_between(&Fract32::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract32::new(2, 5)),
+            Fract32::new(1, 2).simplest_between(&Fract32::new(1, 3))
+        );
+        assert_eq!(
+            Some(Fract32::new(3, 7)),
+            Fract32::new(5, 12).simplest_between(&Fract32::new(4, 9))
+        )
+    }
+
+    #[test]
+    fn should_find_integer_between() {
+        assert_eq!(
+            Some(Fract32::new(2, 1)),
+            Fract32::new(3, 2).simplest_between(&Fract32::new(7, 2))
+        );
+        assert_eq!(
+            Some(Fract32::new(1, 1)),
+            Fract32::new(0, 1).simplest_between(&Fract32::new(3, 1))
+        )
+    }
+
+    #[test]
+    fn should_return_reduced_value_between_equal_bounds() {
+        assert_eq!(
+            Some(Fract32::new(1, 2)),
+            Fract32::new(2, 4).simplest_between(&Fract32::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_find_simplest_between_beyond_width() {
+        assert_eq!(
+            None,
+            Fract32::new(1, u32::MAX).simplest_between(&Fract32::new(1, u32::MAX - 1))
+        );
+        assert_eq!(
+            None,
+            Fract32::new(u32::MAX - 1, u32::MAX).simplest_between(&Fract32::new(1, 1))
+        )
+    }
+}
+
+// Fract64
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct Fract64 {
+    pub numerator: u64,
+    pub denominator: u64,
+}
+
+impl Fract<u64, Fract64, f64> for Fract64 {
+    #[inline]
+    fn to_float(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    #[inline]
+    fn new(numerator: u64, denominator: u64) -> Fract64 {
+        Fract64 {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn invert(&self) -> Fract64 {
+        Fract64 {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+
+    #[inline]
+    fn expand(&self, multiplicator: u64) -> Fract64 {
+        Fract64 {
+            numerator: self.numerator * multiplicator,
+            denominator: self.denominator * multiplicator,
+        }
+    }
+
+    #[inline]
+    fn reduce(&self) -> Fract64 {
+        // already reduced forms skip the gcd loop
+        if self.numerator == 1 || self.denominator == 1 {
+            return *self;
+        }
+        if self.numerator == 0 && self.denominator != 0 {
+            return Fract64 {
+                numerator: 0,
+                denominator: 1,
+            };
+        }
+
+        match utils::checked_gcd_u64(self.numerator, self.denominator) {
+            Some(gcd) => Fract64 {
+                numerator: self.numerator / gcd,
+                denominator: self.denominator / gcd,
+            },
+            None => *self,
+        }
+    }
+
+    #[inline]
+    fn invert_checked(&self) -> Result<Fract64, FractError> {
+        if self.numerator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(self.invert())
+    }
+
+    #[inline]
+    fn nearest_over(&self, denominator: u64) -> Fract64 {
+        let whole: u64 = self.numerator / self.denominator;
+        let remainder: u64 = self.numerator % self.denominator;
+        let scaled: u128 = remainder as u128 * denominator as u128;
+        let quotient: u128 = scaled / self.denominator as u128;
+        let rest: u128 = scaled % self.denominator as u128;
+        // ties round up; comparing against the complement avoids doubling `rest`
+        let rounded: u64 = quotient as u64 + (rest >= self.denominator as u128 - rest) as u64;
+
+        Fract64 {
+            numerator: whole * denominator + rounded,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn mul_checked(&self, other: &Fract64) -> Result<Fract64, FractError> {
+        let numerator: u64 = self
+            .numerator
+            .checked_mul(other.numerator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let denominator: u64 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+
+        Ok(Fract64 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn round_with(&self, mode: RoundingMode) -> u64 {
+        let whole: u64 = self.numerator / self.denominator;
+        let remainder: u64 = self.numerator % self.denominator;
+        if remainder == 0 {
+            return whole;
+        }
+
+        // compares the remainder against half the denominator without overflow
+        let half: Ordering = remainder.cmp(&(self.denominator - remainder));
+        let round_up: bool = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => match half {
+                Ordering::Less => false,
+                Ordering::Equal => whole % 2 == 1,
+                Ordering::Greater => true,
+            },
+        };
+
+        if round_up {
+            whole + 1
+        } else {
+            whole
+        }
+    }
+
+    #[inline]
+    fn percentage_of(&self, whole: &Fract64) -> Fract64 {
+        let ratio: Fract64 = (*self / *whole).reduce();
+        let gcd: u64 = utils::gcd_u64(100, ratio.denominator);
+        Fract64 {
+            numerator: ratio.numerator * (100 / gcd),
+            denominator: ratio.denominator / gcd,
+        }
+    }
+
+    #[inline]
+    fn try_from_u128(value: u128) -> Result<Fract64, FractError> {
+        let numerator: u64 = u64::try_from(value).map_err(|_| FractError::NumeratorOverflow)?;
+        Ok(Fract64::from(numerator))
+    }
+
+    #[inline]
+    fn equivalent_forms(&self, max_denominator: u64) -> Vec<Fract64> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let reduced: Fract64 = self.reduce();
+        let mut forms: Vec<Fract64> = Vec::new();
+        let mut factor: u64 = 1;
+        while let (Some(numerator), Some(denominator)) = (
+            reduced.numerator.checked_mul(factor),
+            reduced.denominator.checked_mul(factor),
+        ) {
+            if denominator > max_denominator {
+                break;
+            }
+
+            forms.push(Fract64 {
+                numerator,
+                denominator,
+            });
+            factor = match factor.checked_add(1) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        forms
+    }
+
+    #[inline]
+    fn cmp_exact(&self, other: &Fract64) -> Ordering {
+        let (lhs, rhs) = self.cross_multiply(other);
+
+        lhs.cmp(&rhs)
+    }
+
+    #[inline]
+    fn abs_diff_float(&self, other: &Fract64) -> f64 {
+        (self.to_float() - other.to_float()).abs()
+    }
+
+    #[inline]
+    fn saturating_from_float(value: f64, max_denominator: u64) -> Fract64 {
+        if value.is_nan() || value <= 0.0 {
+            return Fract64::from(0);
+        }
+
+        if value >= u64::MAX as f64 {
+            return Fract64::from(u64::MAX);
+        }
+
+        let (numerator, denominator) =
+            utils::approximate_f64(value, u64::MAX as u128, max_denominator.max(1) as u128);
+        Fract64 {
+            numerator: numerator as u64,
+            denominator: denominator as u64,
+        }
+    }
+
+    #[inline]
+    fn continued_fraction(&self) -> Vec<u64> {
+        let mut coefficients: Vec<u64> = Vec::new();
+        let mut numerator: u64 = self.numerator;
+        let mut denominator: u64 = self.denominator;
+        while denominator != 0 {
+            coefficients.push(numerator / denominator);
+            let remainder: u64 = numerator % denominator;
+            numerator = denominator;
+            denominator = remainder;
+        }
+
+        coefficients
+    }
+
+    #[inline]
+    fn from_continued_fraction(coefficients: &[u64]) -> Fract64 {
+        let (last, rest) = match coefficients.split_last() {
+            Some(split) => split,
+            None => return Fract64::from(0),
+        };
+
+        rest.iter()
+            .rev()
+            .fold(Fract64::from(*last), |value, coefficient| Fract64 {
+                numerator: coefficient * value.numerator + value.denominator,
+                denominator: value.numerator,
+            })
+    }
+
+    #[inline]
+    fn stern_brocot_path(&self) -> Vec<bool> {
+        let reduced: Fract64 = self.reduce();
+        if reduced.numerator == 0 || reduced.denominator == 0 {
+            return Vec::new();
+        }
+
+        let coefficients: Vec<u64> = reduced.continued_fraction();
+        let last: usize = coefficients.len() - 1;
+        let mut path: Vec<bool> = Vec::new();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            let steps: u64 = if index == last {
+                coefficient - 1
+            } else {
+                *coefficient
+            };
+
+            for _ in 0..steps {
+                path.push(index % 2 == 0);
+            }
+        }
+
+        path
+    }
+
+    #[inline]
+    fn add_checked(&self, other: &Fract64) -> Result<Fract64, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract64 {
+            numerator: lhs.checked_add(rhs).ok_or(FractError::NumeratorOverflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn sub_checked(&self, other: &Fract64) -> Result<Fract64, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract64 {
+            numerator: lhs.checked_sub(rhs).ok_or(FractError::NumeratorUnderflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn div_checked(&self, other: &Fract64) -> Result<Fract64, FractError> {
+        self.mul_checked(&other.invert_checked()?)
+    }
+
+    #[inline]
+    fn checked_add_assign(&mut self, other: Fract64) -> Result<(), FractError> {
+        *self = self.add_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_sub_assign(&mut self, other: Fract64) -> Result<(), FractError> {
+        *self = self.sub_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_mul_assign(&mut self, other: Fract64) -> Result<(), FractError> {
+        *self = self.mul_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_div_assign(&mut self, other: Fract64) -> Result<(), FractError> {
+        *self = self.div_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn to_string_reduced(&self) -> String {
+        let reduced: Fract64 = self.reduce();
+        if reduced.denominator == 1 {
+            reduced.numerator.to_string()
+        } else {
+            format!("{}/{}", reduced.numerator, reduced.denominator)
+        }
+    }
+
+    #[inline]
+    fn denominator_factors(&self) -> Vec<(u64, u32)> {
+        let mut remaining: u64 = self.reduce().denominator;
+        let mut factors: Vec<(u64, u32)> = Vec::new();
+        let mut divisor: u64 = 2;
+        while remaining > 1 && divisor <= remaining / divisor {
+            let mut exponent: u32 = 0;
+            while remaining.is_multiple_of(divisor) {
+                remaining /= divisor;
+                exponent += 1;
+            }
+
+            if exponent > 0 {
+                factors.push((divisor, exponent));
+            }
+            divisor += 1;
+        }
+
+        if remaining > 1 {
+            factors.push((remaining, 1));
+        }
+
+        factors
+    }
+
+    #[inline]
+    fn terminates_in_base(&self, base: u64) -> bool {
+        let mut denominator: u64 = self.reduce().denominator;
+        if denominator == 0 {
+            return false;
+        }
+        if base < 2 {
+            return denominator == 1;
+        }
+
+        // strips every prime factor shared with the base
+        loop {
+            let gcd: u64 = utils::gcd_u64(denominator, base);
+            if gcd == 1 {
+                return denominator == 1;
+            }
+            denominator /= gcd;
+        }
+    }
+
+    #[inline]
+    fn proper_part(&self) -> Fract64 {
+        Fract64 {
+            numerator: self.numerator % self.denominator,
+            denominator: self.denominator,
+        }
+        .reduce()
+    }
+
+    #[inline]
+    fn integer_scale(&self) -> u64 {
+        self.reduce().denominator
+    }
+
+    #[inline]
+    fn scaled_numerator(&self) -> u64 {
+        self.reduce().numerator
+    }
+
+    #[inline]
+    fn powf(&self, exp: f64) -> f64 {
+        (self.numerator as f64 / self.denominator as f64).powf(exp)
+    }
+
+    #[inline]
+    fn powf_approx(&self, exp: f64, max_denominator: u64) -> Fract64 {
+        Fract64::saturating_from_float(self.powf(exp), max_denominator)
+    }
+
+    #[inline]
+    fn largest_unit_fraction(&self) -> Fract64 {
+        let mut denominator: u64 = self.denominator / self.numerator;
+        if !self.denominator.is_multiple_of(self.numerator) {
+            denominator += 1;
+        }
+
+        Fract64 {
+            numerator: 1,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn egyptian_fraction(&self) -> Option<Vec<Fract64>> {
+        if self.denominator == 0 {
+            return None;
+        }
+
+        let mut units: Vec<Fract64> = Vec::new();
+        let whole: u64 = self.numerator / self.denominator;
+        if whole != 0 {
+            units.push(Fract64::from(whole));
+        }
+
+        let mut remaining: Fract64 = self.proper_part();
+        while remaining.numerator != 0 {
+            let unit: Fract64 = remaining.largest_unit_fraction();
+            units.push(unit);
+            remaining = remaining.sub_checked(&unit).ok()?.reduce();
+        }
+
+        Some(units)
+    }
+
+    #[inline]
+    fn safe_cmp(&self, other: &Fract64) -> Option<Ordering> {
+        if self.denominator == 0 || other.denominator == 0 {
+            return None;
+        }
+
+        Some(self.cmp_exact(other))
+    }
+
+    #[inline]
+    fn with_numerator(&self, numerator: u64) -> Fract64 {
+        Fract64 {
+            numerator,
+            denominator: self.denominator,
+        }
+    }
+
+    #[inline]
+    fn with_denominator(&self, denominator: u64) -> Fract64 {
+        Fract64 {
+            numerator: self.numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Fract64, FractError> {
+        let (numerator, denominator) = match s.trim().split_once('/') {
+            Some((numerator, denominator)) => (numerator.trim(), denominator.trim()),
+            None => (s.trim(), "1"),
+        };
+
+        if !(2..=36).contains(&radix)
+            || !utils::is_digits_radix(numerator, radix)
+            || !utils::is_digits_radix(denominator, radix)
+        {
+            return Err(FractError::InvalidFormat);
+        }
+
+        let numerator: u64 =
+            u64::from_str_radix(numerator, radix).map_err(|_| FractError::NumeratorOverflow)?;
+        let denominator: u64 =
+            u64::from_str_radix(denominator, radix).map_err(|_| FractError::DenominatorOverflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(Fract64 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn to_basis_points(&self) -> u32 {
+        let denominator: u128 = self.denominator as u128;
+        let mut basis_points: u128 = (self.numerator as u128 / denominator).saturating_mul(10000);
+        let mut remainder: u128 = self.numerator as u128 % denominator;
+        for scale in &[1000, 100, 10, 1] {
+            let (digit, next) = utils::next_digit_u128(remainder, denominator, 10);
+            basis_points = basis_points.saturating_add(digit as u128 * scale);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, denominator, 10).0 >= 5 {
+            basis_points = basis_points.saturating_add(1);
+        }
+
+        u32::try_from(basis_points).unwrap_or(u32::MAX)
+    }
+
+    #[inline]
+    fn to_float_exact(&self) -> Result<f64, f64> {
+        let reduced: Fract64 = self.reduce();
+        let value: f64 = reduced.to_float();
+        if reduced.denominator == 0 || !reduced.denominator.is_power_of_two() {
+            return Err(value);
+        }
+        if reduced.numerator == 0 {
+            return Ok(value);
+        }
+
+        let significant: u64 = reduced.numerator >> reduced.numerator.trailing_zeros();
+        if u64::BITS - significant.leading_zeros() <= f64::MANTISSA_DIGITS {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+
+    #[inline]
+    fn components(&self) -> (u64, u64) {
+        (self.numerator, self.denominator)
+    }
+
+    #[inline]
+    fn div_rem_fract(&self, other: &Fract64) -> Option<(u64, Fract64)> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        let (lhs, rhs, denominator) = self
+            .reduce()
+            .expand_to_common_checked(&other.reduce())
+            .ok()?;
+
+        Some((lhs / rhs, Fract64::new(lhs % rhs, denominator).reduce()))
+    }
+
+    #[inline]
+    fn reduce_mut(&mut self) {
+        *self = self.reduce();
+    }
+
+    #[inline]
+    fn float_ulp_error(&self) -> u64 {
+        if self.denominator == 0 {
+            return 0;
+        }
+
+        utils::ulp_error(
+            self.to_float().to_bits(),
+            f64::MANTISSA_DIGITS - 1,
+            1075,
+            self.numerator as u128,
+            self.denominator as u128,
+        )
+    }
+
+    #[inline]
+    fn modular_inverse(&self) -> Option<u64> {
+        utils::inverse_mod_u128(self.numerator as u128, self.denominator as u128)
+            .map(|inverse| inverse as u64)
+    }
+
+    #[inline]
+    fn checked_add_int(&self, value: u64) -> Option<Fract64> {
+        self.add_checked(&Fract64::from(value)).ok()
+    }
+
+    #[inline]
+    fn display_approx(&self, max_denominator: u64) -> String {
+        let reduced: Fract64 = self.reduce();
+        if reduced.denominator <= max_denominator {
+            return reduced.to_string();
+        }
+
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            reduced.numerator as u128,
+            reduced.denominator as u128,
+            u64::MAX as u128,
+            max_denominator.max(1) as u128,
+        );
+
+        format!("≈{}", Fract64::new(numerator as u64, denominator as u64))
+    }
+
+    #[inline]
+    fn from_float_capped(
+        value: f64,
+        max_iterations: u32,
+        max_denominator: u64,
+    ) -> Result<Fract64, FractError> {
+        if value.is_nan() {
+            return Err(FractError::InvalidFormat);
+        }
+        if value < 0.0 {
+            return Err(FractError::NumeratorUnderflow);
+        }
+        if value >= u64::MAX as f64 + 1.0 {
+            return Err(FractError::NumeratorOverflow);
+        }
+
+        let (numerator, denominator) = utils::approximate_f64_capped(
+            value,
+            u64::MAX as u128,
+            max_denominator.max(1) as u128,
+            max_iterations,
+        )
+        .map_err(|_| FractError::IterationLimit)?;
+
+        Ok(Fract64::new(numerator as u64, denominator as u64))
+    }
+
+    #[inline]
+    fn decimal_digit_at(&self, position: u32) -> u8 {
+        if self.denominator == 0 {
+            return 0;
+        }
+        if position == 0 {
+            return (self.numerator / self.denominator % 10) as u8;
+        }
+
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        let mut digit: u32 = 0;
+        for _ in 0..position {
+            let (next_digit, next_remainder) =
+                utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digit = next_digit;
+            remainder = next_remainder;
+        }
+
+        digit as u8
+    }
+
+    #[inline]
+    fn reduce_checked(&self) -> (Fract64, bool) {
+        let reduced: Fract64 = self.reduce();
+
+        (reduced, reduced != *self)
+    }
+
+    #[inline]
+    fn scale(&self, factor: u64) -> Option<Fract64> {
+        Some(Fract64 {
+            numerator: self.numerator.checked_mul(factor)?,
+            denominator: self.denominator.checked_mul(factor)?,
+        })
+    }
+
+    #[inline]
+    fn to_sig_figs(&self, sig_figs: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::sig_figs_u128(self.numerator as u128, self.denominator as u128, sig_figs)
+    }
+
+    #[inline]
+    fn clamp01(&self) -> Fract64 {
+        if self.numerator > self.denominator {
+            Fract64::new(1, 1)
+        } else {
+            *self
+        }
+    }
+
+    #[inline]
+    fn period_length(&self) -> Option<u64> {
+        let mut modulus: u64 = self.reduce().denominator;
+        if modulus == 0 {
+            return Some(0);
+        }
+        while modulus.is_multiple_of(2) {
+            modulus /= 2;
+        }
+        while modulus.is_multiple_of(5) {
+            modulus /= 5;
+        }
+        if modulus == 1 {
+            return Some(0);
+        }
+
+        u64::try_from(utils::decimal_period_u128(modulus as u128)).ok()
+    }
+
+    #[inline]
+    fn validate(&self) -> Result<(), FractError> {
+        if self.denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn decimal_digits_rounded(&self, count: usize) -> Vec<u8> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let mut digits: Vec<u8> = Vec::with_capacity(count);
+        let mut remainder: u128 = (self.numerator % self.denominator) as u128;
+        for _ in 0..count {
+            let (digit, next) = utils::next_digit_u128(remainder, self.denominator as u128, 10);
+            digits.push(digit as u8);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, self.denominator as u128, 10).0 >= 5 {
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    break;
+                }
+            }
+        }
+
+        digits
+    }
+
+    #[inline]
+    fn minimal(&self) -> Fract64 {
+        self.reduce()
+    }
+
+    #[inline]
+    fn checked_shl(&self, n: u32) -> Option<Fract64> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.denominator.trailing_zeros().min(n);
+        Some(Fract64 {
+            numerator: self
+                .numerator
+                .checked_mul(u64::checked_pow(2, n - cancelled)?)?,
+            denominator: self.denominator.checked_shr(cancelled)?,
+        })
+    }
+
+    #[inline]
+    fn checked_shr(&self, n: u32) -> Option<Fract64> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.numerator.trailing_zeros().min(n);
+        Some(Fract64 {
+            numerator: self.numerator >> cancelled,
+            denominator: self
+                .denominator
+                .checked_mul(u64::checked_pow(2, n - cancelled)?)?,
+        })
+    }
+
+    #[inline]
+    fn reduce_keep_denominator(&self) -> Fract64 {
+        if self.numerator == 0 {
+            return *self;
+        }
+
+        self.reduce()
+    }
+
+    #[inline]
+    fn to_mixed_string(&self) -> String {
+        if self.denominator == 0 {
+            return self.to_string();
+        }
+
+        let whole: u64 = self.numerator / self.denominator;
+        let proper: Fract64 = self.proper_part();
+        if proper.numerator == 0 {
+            whole.to_string()
+        } else if whole == 0 {
+            proper.to_string()
+        } else {
+            format!("{} {}", whole, proper)
+        }
+    }
+
+    #[inline]
+    fn ratio_to(&self, other: &Fract64) -> Option<Fract64> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        Fract64::product_reduced([*self, other.invert()])
+    }
+
+    #[inline]
+    fn is_between(&self, low: &Fract64, high: &Fract64) -> bool {
+        let (low, high) = if low.cmp_exact(high) == Ordering::Greater {
+            (high, low)
+        } else {
+            (low, high)
+        };
+
+        self.cmp_exact(low) != Ordering::Less && self.cmp_exact(high) != Ordering::Greater
+    }
+
+    #[inline]
+    fn convergents(&self) -> Vec<Fract64> {
+        let (mut p0, mut q0, mut p1, mut q1): (u64, u64, u64, u64) = (0, 1, 1, 0);
+        self.continued_fraction()
+            .into_iter()
+            .map(|coefficient| {
+                let (p2, q2) = (coefficient * p1 + p0, coefficient * q1 + q0);
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+                Fract64::new(p2, q2)
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn cmp_with_float(&self, value: f64) -> Ordering {
+        utils::cmp_ratio_f64(self.numerator as u128, self.denominator as u128, value)
+    }
+
+    #[inline]
+    fn integer_and_fraction(&self) -> (Fract64, Fract64) {
+        if self.denominator == 0 {
+            return (*self, Fract64::new(0, 1));
+        }
+
+        (
+            Fract64::from(self.numerator / self.denominator),
+            self.proper_part(),
+        )
+    }
+
+    #[inline]
+    fn snap_to(&self, allowed: &[Fract64]) -> Option<Fract64> {
+        let distance = |other: &Fract64| {
+            utils::distance_u128(
+                self.numerator as u128,
+                self.denominator as u128,
+                other.numerator as u128,
+                other.denominator as u128,
+            )
+        };
+
+        let mut best: Option<(Fract64, Option<(u128, u128)>)> = None;
+        for candidate in allowed {
+            let candidate_distance: Option<(u128, u128)> = distance(candidate);
+            let closer: bool = match best {
+                None => true,
+                Some((current, current_distance)) => match (candidate_distance, current_distance) {
+                    (Some(lhs), Some(rhs)) => {
+                        utils::cmp_ratio_u128(lhs.0, lhs.1, rhs.0, rhs.1) == Ordering::Less
+                    }
+                    _ => self.abs_diff_float(candidate) < self.abs_diff_float(&current),
+                },
+            };
+            if closer {
+                best = Some((*candidate, candidate_distance));
+            }
+        }
+
+        best.map(|(fraction, _)| fraction)
+    }
+
+    #[inline]
+    fn to_string_radix(&self, radix: u32, max_digits: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::radix_string_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            radix,
+            max_digits,
+        )
+    }
+
+    #[inline]
+    fn continued_fraction_string(&self) -> String {
+        let coefficients: Vec<String> = self
+            .continued_fraction()
+            .iter()
+            .map(|coefficient| coefficient.to_string())
+            .collect();
+
+        match coefficients.split_first() {
+            None => String::from("[]"),
+            Some((first, [])) => format!("[{}]", first),
+            Some((first, rest)) => format!("[{}; {}]", first, rest.join(", ")),
+        }
+    }
+
+    #[inline]
+    fn reciprocal_reduced(&self) -> Option<Fract64> {
+        if self.numerator == 0 {
+            return None;
+        }
+
+        Some(self.invert().reduce())
+    }
+
+    #[inline]
+    fn cross_difference(&self, other: &Fract64) -> i128 {
+        utils::cross_difference_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            other.numerator as u128,
+            other.denominator as u128,
+        )
+    }
+
+    #[inline]
+    fn simplest_between(&self, other: &Fract64) -> Option<Fract64> {
+        let (low, high) = match self.cmp_exact(other) {
+            Ordering::Equal => return Some(self.reduce()),
+            Ordering::Less => (self, other),
+            Ordering::Greater => (other, self),
+        };
+
+        let (numerator, denominator) = utils::simplest_between_u128(
+            low.numerator as u128,
+            low.denominator as u128,
+            high.numerator as u128,
+            high.denominator as u128,
+        )?;
+
+        Some(Fract64::new(
+            u64::try_from(numerator).ok()?,
+            u64::try_from(denominator).ok()?,
+        ))
+    }
+}
+
+impl From<u64> for Fract64 {
+    #[inline]
+    fn from(input: u64) -> Self {
+        Fract64 {
+            numerator: input,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract64 = self;
+        let mut nrhs: Fract64 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u64 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract64 {
+            numerator: nlhs.numerator + nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Sub for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract64 = self;
+        let mut nrhs: Fract64 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u64 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract64 {
+            numerator: nlhs.numerator - nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Mul for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cancels common factors crosswise before multiplying to keep the products small
+        let first: u64 = utils::gcd_u64(self.numerator, rhs.denominator).max(1);
+        let second: u64 = utils::gcd_u64(rhs.numerator, self.denominator).max(1);
+
+        Fract64 {
+            numerator: (self.numerator / first) * (rhs.numerator / second),
+            denominator: (self.denominator / second) * (rhs.denominator / first),
+        }
+    }
+}
+
+impl Div for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.invert()
+    }
+}
+
+impl Widen for Fract64 {
+    type Wider = Fract128;
+
+    #[inline]
+    fn widen(self) -> Fract128 {
+        Fract128 {
+            numerator: self.numerator as u128,
+            denominator: self.denominator as u128,
+        }
+    }
+}
+
+impl PartialOrd for Fract64 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fract64 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_exact(other)
+            .then(self.numerator.cmp(&other.numerator))
+            .then(self.denominator.cmp(&other.denominator))
+    }
+}
+
+impl Fract64 {
+    pub fn from_repeating_decimal(
+        non_repeating: &str,
+        repeating: &str,
+    ) -> Result<Fract64, FractError> {
+        let (whole, fraction) = match non_repeating.split_once('.') {
+            Some(parts) => parts,
+            None => (non_repeating, ""),
+        };
+
+        if whole.is_empty()
+            || repeating.is_empty()
+            || !utils::is_digits(whole)
+            || !utils::is_digits(fraction)
+            || !utils::is_digits(repeating)
+        {
+            return Err(FractError::InvalidFormat);
+        }
+
+        // x = (whole.fraction repeating) - (whole.fraction) over 10^k * (10^r - 1)
+        let prefix: u64 = utils::push_digits_u64(0, whole)
+            .and_then(|value| utils::push_digits_u64(value, fraction))
+            .ok_or(FractError::NumeratorOverflow)?;
+        let full: u64 =
+            utils::push_digits_u64(prefix, repeating).ok_or(FractError::NumeratorOverflow)?;
+        let denominator: u64 = 10u64
+            .checked_pow(fraction.len() as u32)
+            .and_then(|shift| {
+                10u64
+                    .checked_pow(repeating.len() as u32)
+                    .and_then(|period| shift.checked_mul(period - 1))
+            })
+            .ok_or(FractError::DenominatorOverflow)?;
+
+        Ok(Fract64::new(full - prefix, denominator).reduce())
+    }
+
+    pub fn from_decimal_str(s: &str) -> Result<Fract64, FractError> {
+        let (whole, fraction) = match s.split_once('.') {
+            Some(parts) => parts,
+            None => (s, ""),
+        };
+
+        if (whole.is_empty() && fraction.is_empty())
+            || !utils::is_digits(whole)
+            || !utils::is_digits(fraction)
+        {
+            return Err(FractError::InvalidFormat);
+        }
+
+        let numerator: u64 = utils::push_digits_u64(0, whole)
+            .and_then(|value| utils::push_digits_u64(value, fraction))
+            .ok_or(FractError::NumeratorOverflow)?;
+        let denominator: u64 = 10u64
+            .checked_pow(fraction.len() as u32)
+            .ok_or(FractError::DenominatorOverflow)?;
+
+        Ok(Fract64::new(numerator, denominator).reduce())
+    }
+
+    #[inline]
+    pub fn cross_multiply(&self, other: &Fract64) -> (u128, u128) {
+        (
+            self.numerator as u128 * other.denominator as u128,
+            other.numerator as u128 * self.denominator as u128,
+        )
+    }
+
+    #[inline]
+    fn expand_to_common_checked(&self, other: &Fract64) -> Result<(u64, u64, u64), FractError> {
+        if self.denominator == other.denominator {
+            return Ok((self.numerator, other.numerator, self.denominator));
+        }
+
+        let denominator: u64 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+        let lhs: u64 = self
+            .numerator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let rhs: u64 = other
+            .numerator
+            .checked_mul(self.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+
+        Ok((lhs, rhs, denominator))
+    }
+
+    pub fn from_sexagesimal(whole: u32, minutes: u32, seconds: u32) -> Fract64 {
+        let numerator: u64 = whole as u64 * 3600 + minutes as u64 * 60 + seconds as u64;
+        Fract64::new(numerator, 3600).reduce()
+    }
+
+    /// Rounds to the nearest second, ties up; the whole part saturates at `u32::MAX`, which
+    /// is also what zero denominators give, with no minutes or seconds.
+    pub fn to_sexagesimal(&self) -> (u32, u32, u32) {
+        if self.denominator == 0 {
+            return (u32::MAX, 0, 0);
+        }
+
+        let denominator: u128 = self.denominator as u128;
+        let total: u128 = (self.numerator as u128 * 7200 + denominator) / (2 * denominator);
+        let whole: u32 = u32::try_from(total / 3600).unwrap_or(u32::MAX);
+
+        (whole, (total % 3600 / 60) as u32, (total % 60) as u32)
+    }
+
+    #[inline]
+    pub fn to_fract8_approx(&self) -> Fract8 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            u8::MAX as u128,
+            u8::MAX as u128,
+        );
+
+        Fract8 {
+            numerator: numerator as u8,
+            denominator: denominator as u8,
+        }
+    }
+
+    #[inline]
+    pub fn to_fract16_approx(&self) -> Fract16 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            u16::MAX as u128,
+            u16::MAX as u128,
+        );
+
+        Fract16 {
+            numerator: numerator as u16,
+            denominator: denominator as u16,
+        }
+    }
+
+    #[inline]
+    pub fn to_fract32_approx(&self) -> Fract32 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator as u128,
+            self.denominator as u128,
+            u32::MAX as u128,
+            u32::MAX as u128,
+        );
+
+        Fract32 {
+            numerator: numerator as u32,
+            denominator: denominator as u32,
+        }
+    }
+
+    pub fn common_denominator(fractions: &[Fract64]) -> Result<Vec<Fract64>, FractError> {
+        let mut denominator: u64 = 1;
+        for fraction in fractions {
+            if fraction.denominator == 0 {
+                return Err(FractError::ZeroDenominator);
+            }
+            denominator = utils::checked_lcm_u64(denominator, fraction.denominator)
+                .ok_or(FractError::DenominatorOverflow)?;
+        }
+
+        fractions
+            .iter()
+            .map(|fraction| {
+                fraction
+                    .numerator
+                    .checked_mul(denominator / fraction.denominator)
+                    .map(|numerator| Fract64::new(numerator, denominator))
+                    .ok_or(FractError::NumeratorOverflow)
+            })
+            .collect()
+    }
+
+    /// `F(n+1)/F(n)` for `terms` Fibonacci numbers, stopping at the last pair that fits `u64`.
+    pub fn golden_ratio(terms: u32) -> Fract64 {
+        let mut previous: u64 = 1;
+        let mut current: u64 = 1;
+        for _ in 1..terms {
+            match previous.checked_add(current) {
+                Some(next) => {
+                    previous = current;
+                    current = next;
+                }
+                None => break,
+            }
+        }
+
+        Fract64::new(current, previous)
+    }
+
+    pub fn pi_approx(max_denominator: u64) -> Fract64 {
+        Fract64::saturating_from_float(std::f64::consts::PI, max_denominator)
+    }
+
+    pub fn e_approx(max_denominator: u64) -> Fract64 {
+        Fract64::saturating_from_float(std::f64::consts::E, max_denominator)
+    }
+
+    #[inline]
+    pub fn reduce_into_fract8(&self) -> Result<Fract8, FractError> {
+        Fract8::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn reduce_into_fract16(&self) -> Result<Fract16, FractError> {
+        Fract16::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn reduce_into_fract32(&self) -> Result<Fract32, FractError> {
+        Fract32::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        let mut bytes: [u8; 16] = [0; 16];
+        let (numerator, denominator) = bytes.split_at_mut(8);
+        numerator.copy_from_slice(&self.numerator.to_le_bytes());
+        denominator.copy_from_slice(&self.denominator.to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Fract64 {
+        let mut numerator: [u8; 8] = [0; 8];
+        let mut denominator: [u8; 8] = [0; 8];
+        numerator.copy_from_slice(&bytes[..8]);
+        denominator.copy_from_slice(&bytes[8..]);
+
+        Fract64 {
+            numerator: u64::from_le_bytes(numerator),
+            denominator: u64::from_le_bytes(denominator),
+        }
+    }
+
+    /// Sums over common denominators, reducing after every step; `None` once an
+    /// intermediate value overflows or a denominator is zero.
+    pub fn sum_reduced<I: IntoIterator<Item = Fract64>>(fractions: I) -> Option<Fract64> {
+        fractions
+            .into_iter()
+            .try_fold(Fract64::new(0, 1), |sum, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract64 = fraction.reduce();
+                let denominator: u64 =
+                    utils::checked_lcm_u64(sum.denominator, fraction.denominator)?;
+                let numerator: u64 = sum
+                    .numerator
+                    .checked_mul(denominator / sum.denominator)?
+                    .checked_add(
+                        fraction
+                            .numerator
+                            .checked_mul(denominator / fraction.denominator)?,
+                    )?;
+
+                Some(Fract64::new(numerator, denominator).reduce())
+            })
+    }
+
+    /// Multiplies with cross-cancellation so the running product stays reduced; `None` on
+    /// overflow or a zero denominator.
+    pub fn product_reduced<I: IntoIterator<Item = Fract64>>(fractions: I) -> Option<Fract64> {
+        fractions
+            .into_iter()
+            .try_fold(Fract64::new(1, 1), |product, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract64 = fraction.reduce();
+                let first: u64 = utils::gcd_u64(product.numerator, fraction.denominator).max(1);
+                let second: u64 = utils::gcd_u64(fraction.numerator, product.denominator).max(1);
+
+                Some(Fract64::new(
+                    (product.numerator / first).checked_mul(fraction.numerator / second)?,
+                    (product.denominator / second).checked_mul(fraction.denominator / first)?,
+                ))
+            })
+    }
+
+    /// Parses mixed numbers (`2 3/4`), decimals (`2.75`) and plain fractions (`11/4`),
+    /// returning the reduced value.
+    pub fn parse_flexible(s: &str) -> Result<Fract64, FractError> {
+        let s: &str = s.trim();
+        if s.contains('.') {
+            return Fract64::from_decimal_str(s);
+        }
+
+        match s.split_once(' ') {
+            Some((whole, fraction)) if !fraction.trim_start().starts_with('/') => {
+                // the part after the whole number must itself be a fraction
+                if !utils::is_digits(whole) || whole.is_empty() || !fraction.contains('/') {
+                    return Err(FractError::InvalidFormat);
+                }
+
+                let whole: u64 = whole.parse().map_err(|_| FractError::NumeratorOverflow)?;
+                let fraction: Fract64 = fraction.parse()?;
+                Ok(Fract64::from(whole)
+                    .add_checked(&fraction.reduce())?
+                    .reduce())
+            }
+            _ => Ok(s.parse::<Fract64>()?.reduce()),
+        }
+    }
+
+    /// The exact dyadic fraction of `value`, built from its mantissa and exponent.
+    pub fn from_f64_exact(value: f64) -> Result<Fract64, FractError> {
+        if !value.is_finite() {
+            return Err(FractError::InvalidFormat);
+        }
+        if value == 0.0 {
+            return Ok(Fract64::new(0, 1));
+        }
+        if value < 0.0 {
+            return Err(FractError::NumeratorUnderflow);
+        }
+
+        let bits: u64 = value.to_bits();
+        let exponent_field: i32 = (bits >> 52) as i32;
+        let fraction: u64 = bits & ((1 << 52) - 1);
+        let (mut mantissa, mut exponent): (u64, i32) = if exponent_field == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, exponent_field - 1075)
+        };
+
+        let shift: u32 = mantissa.trailing_zeros();
+        mantissa >>= shift;
+        exponent += shift as i32;
+
+        if exponent >= 0 {
+            if exponent as u32 > mantissa.leading_zeros() {
+                return Err(FractError::NumeratorOverflow);
+            }
+            Ok(Fract64::new(mantissa << exponent, 1))
+        } else {
+            let denominator: u64 = 1u64
+                .checked_shl(-exponent as u32)
+                .ok_or(FractError::DenominatorOverflow)?;
+            Ok(Fract64::new(mantissa, denominator))
+        }
+    }
+
+    #[inline]
+    pub fn fits_in_fract8(&self) -> bool {
+        self.reduce_into_fract8().is_ok()
+    }
+
+    #[inline]
+    pub fn fits_in_fract16(&self) -> bool {
+        self.reduce_into_fract16().is_ok()
+    }
+
+    #[inline]
+    pub fn fits_in_fract32(&self) -> bool {
+        self.reduce_into_fract32().is_ok()
+    }
+
+    /// `Ok` when `f32` represents the value exactly, otherwise `Err` with the rounded value.
+    pub fn to_f32_checked(&self) -> Result<f32, f32> {
+        let reduced: Fract64 = self.reduce();
+        let value: f32 = reduced.numerator as f32 / reduced.denominator as f32;
+        if reduced.denominator == 0 || !reduced.denominator.is_power_of_two() {
+            return Err(value);
+        }
+        if reduced.numerator == 0 {
+            return Ok(value);
+        }
+
+        let significant: u64 = reduced.numerator >> reduced.numerator.trailing_zeros();
+        if u64::BITS - significant.leading_zeros() <= f32::MANTISSA_DIGITS {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+
+    /// Reduced `numerator / denominator`, with zero always stored as `0/1`.
+    pub fn from_counts(numerator: u64, denominator: u64) -> Result<Fract64, FractError> {
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(Fract64::new(numerator, denominator).reduce())
+    }
+}
+
+impl Shl<u32> for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the denominator before growing the numerator
+        let cancelled: u32 = self.denominator.trailing_zeros().min(rhs);
+        Fract64 {
+            numerator: self.numerator * (1 << (rhs - cancelled)),
+            denominator: self.denominator >> cancelled,
+        }
+    }
+}
+
+impl Shr<u32> for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the numerator before growing the denominator
+        let cancelled: u32 = self.numerator.trailing_zeros().min(rhs);
+        Fract64 {
+            numerator: self.numerator >> cancelled,
+            denominator: self.denominator * (1 << (rhs - cancelled)),
+        }
+    }
+}
+
+impl FromStr for Fract64 {
+    type Err = FractError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Fract64::from_str_radix(s, 10)
+    }
+}
+
+impl From<Fract64> for f64 {
+    #[inline]
+    fn from(input: Fract64) -> Self {
+        input.to_float()
+    }
+}
+
+impl fmt::Display for Fract64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)?;
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)?;
+        }
+
+        if f.alternate() {
+            write!(f, " ({})", self.to_float())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Fract64 {
+    #[inline]
+    fn default() -> Self {
+        Fract64 {
+            numerator: 0,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add<u64> for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn add(self, rhs: u64) -> Self::Output {
+        self + Fract64::from(rhs)
+    }
+}
+
+impl Sub<u64> for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn sub(self, rhs: u64) -> Self::Output {
+        self - Fract64::from(rhs)
+    }
+}
+
+impl Mul<u64> for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn mul(self, rhs: u64) -> Self::Output {
+        self * Fract64::from(rhs)
+    }
+}
+
+impl Div<u64> for Fract64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn div(self, rhs: u64) -> Self::Output {
+        self / Fract64::from(rhs)
+    }
+}
+
+impl Add<Fract64> for u64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn add(self, rhs: Fract64) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Mul<Fract64> for u64 {
+    type Output = Fract64;
+
+    #[inline]
+    fn mul(self, rhs: Fract64) -> Self::Output {
+        rhs * self
+    }
+}
+
+pub fn mean(fractions: &[Fract64]) -> Option<Fract64> {
+    if fractions.is_empty() {
+        return None;
+    }
+
+    let sum: Fract64 = fractions.iter().fold(Fract64::new(0, 1), |sum, fraction| {
+        (sum + fraction.reduce()).reduce()
+    });
+
+    Some((sum / Fract64::from(fractions.len() as u64)).reduce())
+}
+
+/// Name of the smallest width whose backing integer holds both parts as given. Every `u64`
+/// pair fits `Fract64`, so `None` is only returned for a zero denominator.
+pub fn smallest_width(numerator: u64, denominator: u64) -> Option<&'static str> {
+    if denominator == 0 {
+        return None;
+    }
+
+    let largest: u64 = numerator.max(denominator);
+    if largest <= u8::MAX as u64 {
+        Some("Fract8")
+    } else if largest <= u16::MAX as u64 {
+        Some("Fract16")
+    } else if largest <= u32::MAX as u64 {
+        Some("Fract32")
+    } else {
+        Some("Fract64")
+    }
+}
+
+impl TryFrom<Fract64> for Fract8 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract64) -> Result<Self, Self::Error> {
+        Ok(Fract8 {
+            numerator: u8::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u8::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl TryFrom<Fract64> for Fract16 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract64) -> Result<Self, Self::Error> {
+        Ok(Fract16 {
+            numerator: u16::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u16::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl TryFrom<Fract64> for Fract32 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract64) -> Result<Self, Self::Error> {
+        Ok(Fract32 {
+            numerator: u32::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u32::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl From<bool> for Fract64 {
+    #[inline]
+    fn from(input: bool) -> Self {
+        Fract64 {
+            numerator: input as u64,
+            denominator: 1,
+        }
+    }
+}
+
+pub fn weighted_average(items: &[(Fract64, Fract64)]) -> Option<Fract64> {
+    let total: Fract64 = Fract64::sum_reduced(items.iter().map(|(_, weight)| *weight))?;
+    if total.numerator == 0 {
+        return None;
+    }
+
+    let weighted: Fract64 = Fract64::sum_reduced(
+        items
+            .iter()
+            .map(|(value, weight)| Fract64::product_reduced([*value, *weight]))
+            .collect::<Option<Vec<Fract64>>>()?,
+    )?;
+
+    Fract64::product_reduced([weighted, total.invert()])
+}
+
+pub fn parallel(values: &[Fract64]) -> Option<Fract64> {
+    if values.is_empty() || values.iter().any(|value| value.numerator == 0) {
+        return None;
+    }
+
+    let sum: Fract64 = Fract64::sum_reduced(values.iter().map(|value| value.invert()))?;
+    if sum.numerator == 0 {
+        return None;
+    }
+
+    Some(sum.invert().reduce())
+}
+
+impl fmt::Debug for Fract64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "Fract64 {{ numerator: {}, denominator: {}, reduced: {}, value: {} }}",
+                self.numerator,
+                self.denominator,
+                self.reduce(),
+                self.to_float()
+            )
+        } else {
+            f.debug_struct("Fract64")
+                .field("numerator", &self.numerator)
+                .field("denominator", &self.denominator)
+                .finish()
+        }
+    }
+}
+
+pub fn geometric_mean(fractions: &[Fract64], max_denominator: u64) -> Option<Fract64> {
+    if fractions.is_empty() {
+        return None;
+    }
+    if fractions.iter().any(|fraction| fraction.numerator == 0) {
+        return Some(Fract64::new(0, 1));
+    }
+
+    // averaging logarithms keeps long products from overflowing the float
+    let log_sum: f64 = fractions
+        .iter()
+        .map(|fraction| fraction.to_float().ln())
+        .sum();
+    let root: f64 = (log_sum / fractions.len() as f64).exp();
+
+    Some(Fract64::saturating_from_float(root, max_denominator))
+}
+
+pub fn harmonic_mean(fractions: &[Fract64]) -> Option<Fract64> {
+    if fractions.is_empty() || fractions.iter().any(|fraction| fraction.numerator == 0) {
+        return None;
+    }
+
+    let reciprocal_sum: Fract64 =
+        Fract64::sum_reduced(fractions.iter().map(|fraction| fraction.invert()))?;
+
+    Fract64::from(fractions.len() as u64).ratio_to(&reciprocal_sum)
+}
+
+impl FromIterator<Fract64> for Fract64 {
+    fn from_iter<I: IntoIterator<Item = Fract64>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Fract64::new(0, 1), |sum, fraction| sum + fraction)
+    }
+}
+
+/// Approximates `value` by the closest fraction with a denominator of at most
+/// `max_denominator`; the result is generally not exactly equal to `value`.
+pub fn rationalize(value: f64, max_denominator: u64) -> Fract64 {
+    Fract64::saturating_from_float(value, max_denominator)
+}
+
+pub fn solve_proportion(known: Fract64, target_denominator: u64) -> Option<u64> {
+    if known.denominator == 0 || target_denominator == 0 {
+        return None;
+    }
+
+    let scaled: u128 = known.numerator as u128 * target_denominator as u128;
+    if !scaled.is_multiple_of(known.denominator as u128) {
+        return None;
+    }
+
+    u64::try_from(scaled / known.denominator as u128).ok()
+}
+
+impl Hash for Fract64 {
+    // hashes the reduced value as u128 so equal values match across widths
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let reduced: Fract64 = self.reduce();
+        (reduced.numerator as u128).hash(state);
+        (reduced.denominator as u128).hash(state);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AnyFract {
+    Fract8(Fract8),
+    Fract16(Fract16),
+    Fract32(Fract32),
+    Fract64(Fract64),
+}
+
+impl AnyFract {
+    /// Smallest width holding the reduced fraction, or `None` for a zero denominator.
+    pub fn fit(numerator: u64, denominator: u64) -> Option<AnyFract> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let fraction: Fract64 = Fract64::new(numerator, denominator).reduce();
+        if let Ok(fraction) = fraction.reduce_into_fract8() {
+            Some(AnyFract::Fract8(fraction))
+        } else if let Ok(fraction) = fraction.reduce_into_fract16() {
+            Some(AnyFract::Fract16(fraction))
+        } else if let Ok(fraction) = fraction.reduce_into_fract32() {
+            Some(AnyFract::Fract32(fraction))
+        } else {
+            Some(AnyFract::Fract64(fraction))
+        }
+    }
+}
+
+pub fn linspace(start: Fract64, end: Fract64, steps: u32) -> Vec<Fract64> {
+    if steps <= 1 {
+        return (0..steps).map(|_| start.reduce()).collect();
+    }
+
+    let ascending: bool = start.cmp_exact(&end) != Ordering::Greater;
+    let span: Fract64 = if ascending {
+        (end - start).reduce()
+    } else {
+        (start - end).reduce()
+    };
+
+    (0..steps)
+        .map(|step| {
+            let offset: Fract64 = span * Fract64::new(step as u64, (steps - 1) as u64);
+            if ascending {
+                (start + offset).reduce()
+            } else {
+                (start - offset).reduce()
+            }
+        })
+        .collect()
+}
+
+impl TryFrom<(i64, i64)> for Fract64 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from((numerator, denominator): (i64, i64)) -> Result<Self, Self::Error> {
+        let numerator: u64 =
+            u64::try_from(numerator).map_err(|_| FractError::NumeratorUnderflow)?;
+        let denominator: u64 =
+            u64::try_from(denominator).map_err(|_| FractError::DenominatorUnderflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        let divisor: u64 = utils::gcd_u64(numerator, denominator);
+        Ok(Fract64 {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        })
+    }
+}
+#[cfg(test)]
+mod tests_fract64 {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::convert::TryFrom;
+    use std::hash::{Hash, Hasher};
+
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::{
+        geometric_mean, harmonic_mean, linspace, mean, parallel, rationalize, smallest_width,
+        solve_proportion, weighted_average, AnyFract, Fract, Fract128, Fract16, Fract32, Fract64,
+        Fract8, FractError, RoundingMode, Widen,
+    };
+
+    #[test]
+    fn should_create() {
+        let expected: Fract64 = Fract64 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let actual: Fract64 = Fract64::new(8, 10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_invert() {
+        let expected: Fract64 = Fract64 {
+            numerator: 10,
+            denominator: 8,
+        };
+
+        let actual: Fract64 = Fract64::new(8, 10).invert();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_expand() {
+        let expected: Fract64 = Fract64 {
+            numerator: 80,
+            denominator: 100,
+        };
+
+        let actual: Fract64 = Fract64::new(8, 10).expand(10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_convert() {
+        let expected: f64 = 0.8;
+        let actual: f64 = Fract64::new(8, 10).to_float();
+
+        assert_approx_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_add() {
+        let expected: Fract64 = Fract64 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        let first: Fract64 = Fract64::new(1, 2);
+        let second: Fract64 = Fract64::new(9, 10);
+        let result: Fract64 = first + second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_sub() {
+        let expected: Fract64 = Fract64 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        let first: Fract64 = Fract64::new(4, 2);
+        let second: Fract64 = Fract64::new(9, 10);
+        let result: Fract64 = first - second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_mul() {
+        let expected: Fract64 = Fract64 {
+            numerator: 4,
+            denominator: 5,
+        };
+
+        let first: Fract64 = Fract64::new(2, 5);
+        let second: Fract64 = Fract64::new(4, 2);
+        let result: Fract64 = first * second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_div() {
+        let expected: Fract64 = Fract64 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let first: Fract64 = Fract64::new(1, 2);
+        let second: Fract64 = Fract64::new(9, 10);
+        let result: Fract64 = first / second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_reduce() {
+        let expected: Fract64 = Fract64 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let value: Fract64 = Fract64 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(expected, value.reduce())
+    }
+
+    #[test]
+    fn should_invert_checked() {
+        let expected: Fract64 = Fract64 {
+            numerator: 3,
+            denominator: 2,
+        };
+
+        let actual: Result<Fract64, FractError> = Fract64::new(2, 3).invert_checked();
+
+        assert_eq!(Ok(expected), actual)
+    }
+
+    #[test]
+    fn should_not_invert_zero() {
+        let actual: Result<Fract64, FractError> = Fract64::new(0, 5).invert_checked();
+
+        assert_eq!(Err(FractError::ZeroDenominator), actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over() {
+        let expected: Fract64 = Fract64 {
+            numerator: 8,
+            denominator: 12,
+        };
+
+        let actual: Fract64 = Fract64::new(5, 8).nearest_over(12);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_improper() {
+        let expected: Fract64 = Fract64 {
+            numerator: 7,
+            denominator: 3,
+        };
+
+        let actual: Fract64 = Fract64::new(7, 3).nearest_over(3);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_round_tie_up_in_nearest_over() {
+        let expected: Fract64 = Fract64 {
+            numerator: 1,
+            denominator: 2,
+        };
+
+        let actual: Fract64 = Fract64::new(1, 4).nearest_over(2);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_near_max() {
+        assert_eq!(
+            Fract64::new(u64::MAX - 2, u64::MAX - 1),
+            Fract64::new(u64::MAX - 1, u64::MAX).nearest_over(u64::MAX - 1)
+        )
+    }
+
+    #[test]
+    fn should_widen() {
+        let expected: Fract128 = Fract128 {
+            numerator: u64::MAX as u128,
+            denominator: 10,
+        };
+
+        let actual: Fract128 = Fract64::new(u64::MAX, 10).widen();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_mul_checked() {
+        let expected: Fract64 = Fract64 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let first: Fract64 = Fract64::new(2, 5);
+        let second: Fract64 = Fract64::new(4, 2);
+
+        assert_eq!(Ok(expected), first.mul_checked(&second))
+    }
+
+    #[test]
+    fn should_report_numerator_overflow_in_mul_checked() {
+        let first: Fract64 = Fract64::new(u64::MAX, 1);
+        let second: Fract64 = Fract64::new(2, 3);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_report_denominator_overflow_in_mul_checked() {
+        let first: Fract64 = Fract64::new(1, u64::MAX);
+        let second: Fract64 = Fract64::new(1, 2);
+
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_order_by_value() {
+        let smaller: Fract64 = Fract64::new(1, 3);
+        let larger: Fract64 = Fract64::new(2, 5);
+
+        assert!(smaller < larger);
+        assert!(larger > smaller)
+    }
+
+    #[test]
+    fn should_order_equal_values_by_representation() {
+        let reduced: Fract64 = Fract64::new(1, 2);
+        let expanded: Fract64 = Fract64::new(2, 4);
+
+        assert!(reduced < expanded);
+        assert!(reduced < Fract64::new(3, 5));
+        assert!(expanded < Fract64::new(3, 5))
+    }
+
+    #[test]
+    fn should_order_large_values() {
+        let smaller: Fract64 = Fract64::new(u64::MAX - 1, u64::MAX);
+        let larger: Fract64 = Fract64::new(u64::MAX, u64::MAX - 1);
+
+        assert!(smaller < larger);
+        assert!(smaller < Fract64::new(1, 1));
+        assert!(Fract64::new(1, 1) < larger)
+    }
+
+    #[test]
+    fn should_order_when_cross_products_overflow_u64() {
+        // 2^32 * 2^32 wraps to 0 in u64, which would invert this comparison
+        let smaller: Fract64 = Fract64::new((1 << 32) + 1, 1 << 32);
+        let larger: Fract64 = Fract64::new(1 << 32, (1 << 32) - 1);
+
+        assert!(smaller < larger);
+        assert_eq!(Ordering::Less, smaller.cmp_exact(&larger));
+        assert_eq!(Some(Ordering::Greater), larger.safe_cmp(&smaller))
+    }
+
+    #[test]
+    fn should_create_from_repeating_decimal() {
+        let expected: Fract64 = Fract64 {
+            numerator: 1,
+            denominator: 3,
+        };
+
+        assert_eq!(Ok(expected), Fract64::from_repeating_decimal("0", "3"))
+    }
+
+    #[test]
+    fn should_create_from_repeating_decimal_with_prefix() {
+        let expected: Fract64 = Fract64 {
+            numerator: 1,
+            denominator: 6,
+        };
+
+        assert_eq!(Ok(expected), Fract64::from_repeating_decimal("0.16", "6"))
+    }
+
+    #[test]
+    fn should_not_create_from_invalid_repeating_decimal() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_repeating_decimal("0.1a", "6")
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_repeating_decimal("0", "")
+        )
+    }
+
+    #[test]
+    fn should_create_from_decimal_str() {
+        let expected: Fract64 = Fract64 {
+            numerator: 1,
+            denominator: 8,
+        };
+
+        assert_eq!(Ok(expected), Fract64::from_decimal_str("0.125"))
+    }
+
+    #[test]
+    fn should_create_from_decimal_str_with_whole_part() {
+        let expected: Fract64 = Fract64 {
+            numerator: 5,
+            denominator: 2,
+        };
+
+        assert_eq!(Ok(expected), Fract64::from_decimal_str("2.5"))
+    }
+
+    #[test]
+    fn should_not_create_from_malformed_decimal_str() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_decimal_str("abc")
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_decimal_str(".")
+        )
+    }
+
+    #[test]
+    fn should_not_create_from_overflowing_decimal_str() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract64::from_decimal_str("123456789012345678901")
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract64::from_decimal_str("0.00000000000000000001")
+        )
+    }
+
+    #[test]
+    fn should_round_half_up() {
+        assert_eq!(3, Fract64::new(5, 2).round_with(RoundingMode::HalfUp));
+        assert_eq!(4, Fract64::new(7, 2).round_with(RoundingMode::HalfUp))
+    }
+
+    #[test]
+    fn should_round_half_even() {
+        assert_eq!(2, Fract64::new(5, 2).round_with(RoundingMode::HalfEven));
+        assert_eq!(4, Fract64::new(7, 2).round_with(RoundingMode::HalfEven))
+    }
+
+    #[test]
+    fn should_round_half_down() {
+        assert_eq!(2, Fract64::new(5, 2).round_with(RoundingMode::HalfDown));
+        assert_eq!(3, Fract64::new(8, 3).round_with(RoundingMode::HalfDown))
+    }
+
+    #[test]
+    fn should_round_floor_and_ceil() {
+        assert_eq!(2, Fract64::new(8, 3).round_with(RoundingMode::Floor));
+        assert_eq!(3, Fract64::new(7, 3).round_with(RoundingMode::Ceil));
+        assert_eq!(2, Fract64::new(4, 2).round_with(RoundingMode::Ceil))
+    }
+
+    #[test]
+    fn should_calculate_percentage_of() {
+        let expected: Fract64 = Fract64 {
+            numerator: 50,
+            denominator: 1,
+        };
+
+        let actual: Fract64 = Fract64::new(1, 4).percentage_of(&Fract64::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_percentage_of_above_hundred() {
+        let expected: Fract64 = Fract64 {
+            numerator: 150,
+            denominator: 1,
+        };
+
+        let actual: Fract64 = Fract64::new(3, 4).percentage_of(&Fract64::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_fractional_percentage_of() {
+        let expected: Fract64 = Fract64 {
+            numerator: 100,
+            denominator: 3,
+        };
+
+        let actual: Fract64 = Fract64::new(1, 3).percentage_of(&Fract64::new(1, 1));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_create_from_u128() {
+        let expected: Fract64 = Fract64 {
+            numerator: u64::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(Ok(expected), Fract64::try_from_u128(u64::MAX as u128))
+    }
+
+    #[test]
+    fn should_not_create_from_out_of_range_u128() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract64::try_from_u128(u64::MAX as u128 + 1)
+        )
+    }
+
+    #[test]
+    fn should_list_equivalent_forms() {
+        let expected: Vec<Fract64> = vec![
+            Fract64::new(1, 2),
+            Fract64::new(2, 4),
+            Fract64::new(3, 6),
+            Fract64::new(4, 8),
+        ];
+
+        assert_eq!(expected, Fract64::new(2, 4).equivalent_forms(8))
+    }
+
+    #[test]
+    fn should_find_no_equivalent_forms_for_zero_denominator() {
+        assert_eq!(
+            Vec::<Fract64>::new(),
+            Fract64::new(3, 0).equivalent_forms(u64::MAX)
+        );
+        assert_eq!(
+            Vec::<Fract64>::new(),
+            Fract64::new(0, 0).equivalent_forms(u64::MAX)
+        )
+    }
+
+    #[test]
+    fn should_not_list_equivalent_forms_below_reduced_denominator() {
+        assert_eq!(
+            Vec::<Fract64>::new(),
+            Fract64::new(2, 6).equivalent_forms(2)
+        )
+    }
+
+    #[test]
+    fn should_compare_exact() {
+        assert_eq!(
+            Ordering::Equal,
+            Fract64::new(1, 2).cmp_exact(&Fract64::new(2, 4))
+        );
+        assert_eq!(
+            Ordering::Less,
+            Fract64::new(1, 3).cmp_exact(&Fract64::new(1, 2))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Fract64::new(3, 4).cmp_exact(&Fract64::new(2, 3))
+        )
+    }
+
+    #[test]
+    fn should_compare_exact_below_float_precision() {
+        let first: Fract64 = Fract64::new(u64::MAX - 1, u64::MAX);
+        let second: Fract64 = Fract64::new(u64::MAX - 2, u64::MAX - 1);
+
+        assert_eq!(first.to_float(), second.to_float());
+        assert_eq!(Ordering::Greater, first.cmp_exact(&second))
+    }
+
+    #[test]
+    fn should_calculate_abs_diff_float() {
+        let expected: f64 = 0.25;
+
+        assert_approx_eq!(
+            expected,
+            Fract64::new(1, 2).abs_diff_float(&Fract64::new(3, 4))
+        );
+        assert_approx_eq!(
+            expected,
+            Fract64::new(3, 4).abs_diff_float(&Fract64::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_saturate_huge_float() {
+        let expected: Fract64 = Fract64 {
+            numerator: u64::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract64::saturating_from_float(f64::MAX, 10));
+        assert_eq!(expected, Fract64::saturating_from_float(f64::INFINITY, 10))
+    }
+
+    #[test]
+    fn should_saturate_negative_float() {
+        let expected: Fract64 = Fract64 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract64::saturating_from_float(-2.5, 10));
+        assert_eq!(expected, Fract64::saturating_from_float(f64::NAN, 10))
+    }
+
+    #[test]
+    fn should_approximate_float_in_range() {
+        assert_eq!(
+            Fract64::new(1, 10),
+            Fract64::saturating_from_float(0.1, 100)
+        );
+        assert_eq!(
+            Fract64::new(22, 7),
+            Fract64::saturating_from_float(std::f64::consts::PI, 10)
+        );
+        assert_eq!(Fract64::new(5, 2), Fract64::saturating_from_float(2.5, 10))
+    }
+
+    #[test]
+    fn should_cross_multiply() {
+        let first: Fract64 = Fract64::new(2, 3);
+        let second: Fract64 = Fract64::new(5, 7);
+
+        assert_eq!((14, 15), first.cross_multiply(&second))
+    }
+
+    #[test]
+    fn should_cross_multiply_without_overflow() {
+        let first: Fract64 = Fract64::new(u64::MAX, 1);
+        let second: Fract64 = Fract64::new(1, u64::MAX);
+
+        assert_eq!(
+            (u64::MAX as u128 * u64::MAX as u128, 1),
+            first.cross_multiply(&second)
+        )
+    }
+
+    #[test]
+    fn should_expand_continued_fraction() {
+        assert_eq!(vec![2, 3, 1, 4], Fract64::new(43, 19).continued_fraction());
+        assert_eq!(vec![0, 2], Fract64::new(1, 2).continued_fraction())
+    }
+
+    #[test]
+    fn should_create_from_continued_fraction() {
+        let expected: Fract64 = Fract64 {
+            numerator: 43,
+            denominator: 19,
+        };
+
+        assert_eq!(expected, Fract64::from_continued_fraction(&[2, 3, 1, 4]))
+    }
+
+    #[test]
+    fn should_create_zero_from_empty_continued_fraction() {
+        let expected: Fract64 = Fract64 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract64::from_continued_fraction(&[]))
+    }
+
+    #[test]
+    fn should_find_empty_stern_brocot_path() {
+        assert_eq!(Vec::<bool>::new(), Fract64::new(1, 1).stern_brocot_path());
+        assert_eq!(Vec::<bool>::new(), Fract64::new(3, 3).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_find_stern_brocot_path() {
+        assert_eq!(
+            vec![false, true, false],
+            Fract64::new(3, 5).stern_brocot_path()
+        );
+        assert_eq!(vec![true, true], Fract64::new(3, 1).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_add_checked() {
+        let expected: Fract64 = Fract64 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract64::new(1, 2).add_checked(&Fract64::new(9, 10))
+        )
+    }
+
+    #[test]
+    fn should_sub_checked() {
+        let expected: Fract64 = Fract64 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract64::new(4, 2).sub_checked(&Fract64::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract64::new(1, 2).sub_checked(&Fract64::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_div_checked() {
+        let expected: Fract64 = Fract64 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract64::new(1, 2).div_checked(&Fract64::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract64::new(1, 2).div_checked(&Fract64::new(0, 10))
+        )
+    }
+
+    #[test]
+    fn should_checked_assign() {
+        let mut value: Fract64 = Fract64::new(1, 2);
+
+        assert_eq!(Ok(()), value.checked_add_assign(Fract64::new(1, 2)));
+        assert_eq!(Fract64::new(2, 2), value);
+        assert_eq!(Ok(()), value.checked_sub_assign(Fract64::new(1, 2)));
+        assert_eq!(Fract64::new(1, 2), value);
+        assert_eq!(Ok(()), value.checked_mul_assign(Fract64::new(3, 1)));
+        assert_eq!(Fract64::new(3, 2), value);
+        assert_eq!(Ok(()), value.checked_div_assign(Fract64::new(3, 1)));
+        assert_eq!(Fract64::new(3, 6), value)
+    }
+
+    #[test]
+    fn should_leave_value_unchanged_on_failed_assign() {
+        let mut value: Fract64 = Fract64::new(u64::MAX, 2);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_add_assign(Fract64::new(1, 2))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_mul_assign(Fract64::new(2, 1))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            value.checked_div_assign(Fract64::new(0, 1))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_sub_assign(Fract64::new(u64::MAX, 1))
+        );
+        assert_eq!(Fract64::new(u64::MAX, 2), value)
+    }
+
+    #[test]
+    fn should_format_reduced() {
+        assert_eq!("1/2", Fract64::new(4, 8).to_string_reduced());
+        assert_eq!("2", Fract64::new(6, 3).to_string_reduced())
+    }
+
+    #[test]
+    fn should_factor_denominator() {
+        assert_eq!(vec![(2, 3)], Fract64::new(3, 8).denominator_factors());
+        assert_eq!(
+            vec![(2, 1), (3, 1)],
+            Fract64::new(1, 6).denominator_factors()
+        );
+        assert_eq!(vec![(3, 2)], Fract64::new(10, 18).denominator_factors())
+    }
+
+    #[test]
+    fn should_factor_whole_denominator() {
+        assert_eq!(
+            Vec::<(u64, u32)>::new(),
+            Fract64::new(4, 2).denominator_factors()
+        )
+    }
+
+    #[test]
+    fn should_terminate_in_base() {
+        assert!(Fract64::new(3, 8).terminates_in_base(10));
+        assert!(Fract64::new(7, 20).terminates_in_base(10));
+        assert!(Fract64::new(1, 3).terminates_in_base(6));
+        assert!(Fract64::new(5, 12).terminates_in_base(6));
+        assert!(Fract64::new(6, 3).terminates_in_base(7))
+    }
+
+    #[test]
+    fn should_not_terminate_in_base() {
+        assert!(!Fract64::new(1, 3).terminates_in_base(10));
+        assert!(!Fract64::new(1, 10).terminates_in_base(2));
+        assert!(!Fract64::new(1, 5).terminates_in_base(6))
+    }
+
+    #[test]
+    fn should_not_terminate_with_zero_denominator() {
+        assert!(!Fract64::new(3, 0).terminates_in_base(10));
+        assert!(!Fract64::new(0, 0).terminates_in_base(2))
+    }
+
+    #[test]
+    fn should_shift_left() {
+        assert_eq!(Fract64::new(1, 1), Fract64::new(1, 2) << 1);
+        assert_eq!(Fract64::new(12, 1), Fract64::new(3, 4) << 4);
+        assert_eq!(Fract64::new(3, 5), Fract64::new(3, 5) << 0)
+    }
+
+    #[test]
+    fn should_shift_zero_left() {
+        assert_eq!(Fract64::new(0, 1), Fract64::new(0, 1) << u64::BITS);
+        assert_eq!(Fract64::new(0, 3), Fract64::new(0, 3) << 2)
+    }
+
+    #[test]
+    fn should_shift_right() {
+        assert_eq!(Fract64::new(1, 4), Fract64::new(1, 2) >> 1);
+        assert_eq!(Fract64::new(3, 2), Fract64::new(12, 1) >> 3);
+        assert_eq!(Fract64::new(0, 3), Fract64::new(0, 3) >> 2)
+    }
+
+    #[test]
+    fn should_parse() {
+        assert_eq!(Ok(Fract64::new(3, 4)), "3/4".parse::<Fract64>());
+        assert_eq!(Ok(Fract64::new(10, 18)), " 10 / 18 ".parse::<Fract64>());
+        assert_eq!(Ok(Fract64::new(5, 1)), "5".parse::<Fract64>())
+    }
+
+    #[test]
+    fn should_not_parse_malformed() {
+        assert_eq!(Err(FractError::InvalidFormat), "a/4".parse::<Fract64>());
+        assert_eq!(Err(FractError::InvalidFormat), "3/".parse::<Fract64>());
+        assert_eq!(Err(FractError::InvalidFormat), "-3/4".parse::<Fract64>());
+        assert_eq!(Err(FractError::InvalidFormat), "1/2/3".parse::<Fract64>());
+        assert_eq!(Err(FractError::ZeroDenominator), "3/0".parse::<Fract64>())
+    }
+
+    #[test]
+    fn should_create_from_sexagesimal() {
+        assert_eq!(Fract64::new(1, 2), Fract64::from_sexagesimal(0, 30, 0));
+        assert_eq!(Fract64::new(7, 4), Fract64::from_sexagesimal(1, 45, 0));
+        assert_eq!(Fract64::new(1, 3600), Fract64::from_sexagesimal(0, 0, 1))
+    }
+
+    #[test]
+    fn should_convert_to_sexagesimal() {
+        assert_eq!(
+            (1, 45, 0),
+            Fract64::from_sexagesimal(1, 45, 0).to_sexagesimal()
+        );
+        assert_eq!(
+            (12, 3, 4),
+            Fract64::from_sexagesimal(12, 3, 4).to_sexagesimal()
+        );
+        assert_eq!((0, 20, 0), Fract64::new(1, 3).to_sexagesimal())
+    }
+
+    #[test]
+    fn should_round_to_nearest_second_in_sexagesimal() {
+        assert_eq!((0, 0, 1), Fract64::new(1, 7200).to_sexagesimal());
+        assert_eq!((0, 0, 0), Fract64::new(1, 7201).to_sexagesimal());
+        assert_eq!((1, 0, 0), Fract64::new(7199, 7200).to_sexagesimal())
+    }
+
+    #[test]
+    fn should_saturate_zero_denominator_in_sexagesimal() {
+        assert_eq!((u32::MAX, 0, 0), Fract64::new(1, 0).to_sexagesimal());
+        assert_eq!((u32::MAX, 0, 0), Fract64::new(0, 0).to_sexagesimal())
+    }
+
+    #[test]
+    fn should_find_proper_part() {
+        assert_eq!(Fract64::new(1, 2), Fract64::new(7, 2).proper_part());
+        assert_eq!(Fract64::new(0, 1), Fract64::new(4, 2).proper_part());
+        assert_eq!(Fract64::new(5, 9), Fract64::new(10, 18).proper_part())
+    }
+
+    #[test]
+    fn should_not_reduce_zero_over_zero() {
+        let expected: Fract64 = Fract64 {
+            numerator: 0,
+            denominator: 0,
+        };
+
+        assert_eq!(expected, Fract64::new(0, 0).reduce())
+    }
+
+    #[test]
+    fn should_find_integer_scale() {
+        assert_eq!(9, Fract64::new(10, 18).integer_scale());
+        assert_eq!(1, Fract64::new(6, 3).integer_scale())
+    }
+
+    #[test]
+    fn should_find_scaled_numerator() {
+        assert_eq!(5, Fract64::new(10, 18).scaled_numerator());
+        assert_eq!(2, Fract64::new(6, 3).scaled_numerator())
+    }
+
+    #[test]
+    fn should_convert_into_float() {
+        assert_approx_eq!(0.5, f64::from(Fract64::new(1, 2)));
+
+        let actual: f64 = Fract64::new(3, 4).into();
+        assert_approx_eq!(0.75, actual)
+    }
+
+    #[test]
+    fn should_raise_to_float_power() {
+        assert_approx_eq!(1.5, Fract64::new(9, 4).powf(0.5));
+        assert_approx_eq!(0.125, Fract64::new(1, 2).powf(3.0))
+    }
+
+    #[test]
+    fn should_approximate_float_power() {
+        assert_eq!(Fract64::new(3, 2), Fract64::new(9, 4).powf_approx(0.5, 100));
+        assert_eq!(Fract64::new(7, 5), Fract64::new(2, 1).powf_approx(0.5, 10))
+    }
+
+    #[test]
+    fn should_approximate_to_fract8() {
+        assert_eq!(
+            Fract8::new(u8::MAX, 2),
+            Fract64::new(u8::MAX as u64 * 2, 4).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(1, 1),
+            Fract64::new(u64::MAX, u64::MAX - 1).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(u8::MAX, 1),
+            Fract64::new(u64::MAX, 1).to_fract8_approx()
+        )
+    }
+
+    #[test]
+    fn should_approximate_to_fract16() {
+        assert_eq!(
+            Fract16::new(u16::MAX, 2),
+            Fract64::new(u16::MAX as u64 * 2, 4).to_fract16_approx()
+        );
+        assert_eq!(
+            Fract16::new(1, 1),
+            Fract64::new(u64::MAX, u64::MAX - 1).to_fract16_approx()
+        );
+        assert_eq!(
+            Fract16::new(u16::MAX, 1),
+            Fract64::new(u64::MAX, 1).to_fract16_approx()
+        )
+    }
+
+    #[test]
+    fn should_approximate_to_fract32() {
+        assert_eq!(
+            Fract32::new(u32::MAX, 2),
+            Fract64::new(u32::MAX as u64 * 2, 4).to_fract32_approx()
+        );
+        assert_eq!(
+            Fract32::new(1, 1),
+            Fract64::new(u64::MAX, u64::MAX - 1).to_fract32_approx()
+        );
+        assert_eq!(
+            Fract32::new(u32::MAX, 1),
+            Fract64::new(u64::MAX, 1).to_fract32_approx()
+        )
+    }
+
+    #[test]
+    fn should_approximate_large_value_to_fract8() {
+        let value: Fract64 = Fract64::new(3_141_592_653_589_793, 1_000_000_000_000_000);
+
+        assert_eq!(Fract8::new(245, 78), value.to_fract8_approx());
+        assert_eq!(
+            Fract32::new(1, 4),
+            Fract64::new(250_000_000_000, 1_000_000_000_000).to_fract32_approx()
+        )
+    }
+
+    #[test]
+    fn should_display() {
+        assert_eq!("3/4", format!("{}", Fract64::new(3, 4)));
+        assert_eq!("4/8", format!("{}", Fract64::new(4, 8)));
+        assert_eq!("5", format!("{}", Fract64::new(5, 1)));
+        assert_eq!("0", format!("{}", Fract64::new(0, 1)))
+    }
+
+    #[test]
+    fn should_find_largest_unit_fraction() {
+        assert_eq!(
+            Fract64::new(1, 2),
+            Fract64::new(3, 4).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract64::new(1, 3),
+            Fract64::new(2, 5).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract64::new(1, 4),
+            Fract64::new(2, 8).largest_unit_fraction()
+        )
+    }
+
+    #[test]
+    fn should_decompose_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract64::new(1, 2), Fract64::new(1, 4)]),
+            Fract64::new(3, 4).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract64::new(1, 2), Fract64::new(1, 3)]),
+            Fract64::new(5, 6).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract64::new(1, 7)]),
+            Fract64::new(2, 14).egyptian_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_whole_part_from_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract64::new(2, 1), Fract64::new(1, 2)]),
+            Fract64::new(5, 2).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract64::new(3, 1)]),
+            Fract64::new(6, 2).egyptian_fraction()
+        );
+        assert_eq!(Some(vec![]), Fract64::new(0, 4).egyptian_fraction());
+        assert_eq!(None, Fract64::new(1, 0).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_not_decompose_egyptian_fraction_on_overflow() {
+        assert_eq!(None, Fract64::new(5, 121).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_compare_safely() {
+        assert_eq!(
+            Some(Ordering::Less),
+            Fract64::new(1, 3).safe_cmp(&Fract64::new(1, 2))
+        );
+        assert_eq!(
+            Some(Ordering::Equal),
+            Fract64::new(2, 4).safe_cmp(&Fract64::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_compare_zero_denominator() {
+        assert_eq!(None, Fract64::new(1, 0).safe_cmp(&Fract64::new(1, 2)));
+        assert_eq!(None, Fract64::new(1, 2).safe_cmp(&Fract64::new(0, 0)))
+    }
+
+    #[test]
+    fn should_default_to_zero() {
+        assert_eq!(Fract64::new(0, 1), Fract64::default())
+    }
+
+    #[test]
+    fn should_build_with_numerator_and_denominator() {
+        let expected: Fract64 = Fract64 {
+            numerator: 3,
+            denominator: 4,
+        };
+
+        let actual: Fract64 = Fract64::default().with_numerator(3).with_denominator(4);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_build_invalid_with_zero_denominator() {
+        let actual: Fract64 = Fract64::new(3, 4).with_denominator(0);
+
+        assert_eq!(Fract64::new(3, 0), actual);
+        assert_eq!(None, actual.safe_cmp(&Fract64::new(3, 4)))
+    }
+
+    #[test]
+    fn should_parse_with_radix() {
+        let expected: Fract64 = Fract64 {
+            numerator: 255,
+            denominator: 16,
+        };
+
+        assert_eq!(Ok(expected), Fract64::from_str_radix("ff/10", 16));
+        assert_eq!(Ok(Fract64::new(5, 2)), Fract64::from_str_radix("101/10", 2))
+    }
+
+    #[test]
+    fn should_not_parse_invalid_digit_with_radix() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_str_radix("fg/10", 16)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_str_radix("12/10", 2)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_str_radix("1/1", 37)
+        )
+    }
+
+    #[test]
+    fn should_not_parse_overflow() {
+        let too_large: String = format!("{}0", u64::MAX);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            format!("{}/1", too_large).parse::<Fract64>()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            format!("1/{}", too_large).parse::<Fract64>()
+        )
+    }
+
+    #[test]
+    fn should_convert_to_basis_points() {
+        assert_eq!(5000, Fract64::new(1, 2).to_basis_points());
+        assert_eq!(2500, Fract64::new(1, 4).to_basis_points());
+        assert_eq!(3333, Fract64::new(1, 3).to_basis_points());
+        assert_eq!(6667, Fract64::new(2, 3).to_basis_points());
+        assert_eq!(25000, Fract64::new(5, 2).to_basis_points())
+    }
+
+    #[test]
+    fn should_round_half_up_to_basis_points() {
+        assert_eq!(313, Fract64::new(1, 32).to_basis_points());
+        assert_eq!(156, Fract64::new(1, 64).to_basis_points());
+        assert_eq!(1667, Fract64::new(1, 6).to_basis_points())
+    }
+
+    #[test]
+    fn should_convert_exactly_to_float() {
+        assert_eq!(Ok(0.5), Fract64::new(1, 2).to_float_exact());
+        assert_eq!(Ok(0.75), Fract64::new(6, 8).to_float_exact());
+        assert_eq!(Ok(0.0), Fract64::new(0, 3).to_float_exact())
+    }
+
+    #[test]
+    fn should_flag_inexact_float() {
+        assert_eq!(Err(1.0 / 3.0), Fract64::new(1, 3).to_float_exact());
+        assert!(Fract64::new(1, 0).to_float_exact().is_err())
+    }
+
+    #[test]
+    fn should_flag_numerator_beyond_mantissa() {
+        assert!(Fract64::new((1 << f64::MANTISSA_DIGITS) + 1, 1)
+            .to_float_exact()
+            .is_err());
+        assert!(Fract64::new(1 << f64::MANTISSA_DIGITS, 1)
+            .to_float_exact()
+            .is_ok())
+    }
+
+    #[test]
+    fn should_convert_unreduced_exactly() {
+        let k: u64 = (1 << 53) + 1;
+        assert_eq!(Ok(0.75), Fract64::new(3 * k, 4 * k).to_float_exact())
+    }
+
+    #[test]
+    fn should_add_integer() {
+        assert_eq!(Fract64::new(3, 2), Fract64::new(1, 2) + 1);
+        assert_eq!(Fract64::new(3, 2), 1 + Fract64::new(1, 2))
+    }
+
+    #[test]
+    fn should_sub_integer() {
+        assert_eq!(Fract64::new(1, 2), Fract64::new(3, 2) - 1)
+    }
+
+    #[test]
+    fn should_mul_integer() {
+        assert_eq!(Fract64::new(3, 2), Fract64::new(1, 2) * 3);
+        assert_eq!(Fract64::new(3, 2), 3 * Fract64::new(1, 2))
+    }
+
+    #[test]
+    fn should_div_integer() {
+        assert_eq!(Fract64::new(1, 6), Fract64::new(1, 2) / 3)
+    }
+
+    #[test]
+    fn should_return_raw_components() {
+        assert_eq!((2, 4), Fract64::new(2, 4).components());
+        assert_eq!((3, 0), Fract64::new(3, 0).components())
+    }
+
+    #[test]
+    fn should_find_mean() {
+        let fractions: [Fract64; 3] = [Fract64::new(1, 2), Fract64::new(1, 4), Fract64::new(1, 4)];
+        assert_eq!(Some(Fract64::new(1, 3)), mean(&fractions));
+        assert_eq!(Some(Fract64::new(2, 3)), mean(&[Fract64::new(4, 6)]))
+    }
+
+    #[test]
+    fn should_not_find_mean_of_empty_slice() {
+        assert_eq!(None, mean(&[]))
+    }
+
+    #[test]
+    fn should_expand_slice_to_common_denominator() {
+        let fractions: [Fract64; 3] = [Fract64::new(1, 2), Fract64::new(1, 3), Fract64::new(5, 6)];
+        assert_eq!(
+            Ok(vec![
+                Fract64::new(3, 6),
+                Fract64::new(2, 6),
+                Fract64::new(5, 6)
+            ]),
+            Fract64::common_denominator(&fractions)
+        );
+        assert_eq!(Ok(vec![]), Fract64::common_denominator(&[]))
+    }
+
+    #[test]
+    fn should_not_overflow_common_denominator() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract64::common_denominator(&[
+                Fract64::new(1, u64::MAX),
+                Fract64::new(1, u64::MAX - 1)
+            ])
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract64::common_denominator(&[Fract64::new(1, 2), Fract64::new(1, 0)])
+        )
+    }
+
+    #[test]
+    fn should_approximate_golden_ratio() {
+        let phi: f64 = (1.0 + 5f64.sqrt()) / 2.0;
+        assert_eq!(Fract64::new(1, 1), Fract64::golden_ratio(1));
+        assert_eq!(Fract64::new(8, 5), Fract64::golden_ratio(5));
+
+        let few: f64 = (Fract64::golden_ratio(5).to_float() - phi).abs();
+        let many: f64 = (Fract64::golden_ratio(20).to_float() - phi).abs();
+        assert!(many < few);
+        assert_approx_eq!(phi, Fract64::golden_ratio(40).to_float(), 1e-15)
+    }
+
+    #[test]
+    fn should_not_overflow_golden_ratio() {
+        assert_eq!(Fract64::golden_ratio(92), Fract64::golden_ratio(1000));
+        assert_eq!(12200160415121876738, Fract64::golden_ratio(1000).numerator)
+    }
+
+    #[test]
+    fn should_approximate_pi() {
+        assert_eq!(Fract64::new(3, 1), Fract64::pi_approx(1));
+        assert_eq!(Fract64::new(22, 7), Fract64::pi_approx(10));
+        assert_eq!(Fract64::new(355, 113), Fract64::pi_approx(1000))
+    }
+
+    #[test]
+    fn should_approximate_e() {
+        assert_eq!(Fract64::new(19, 7), Fract64::e_approx(10));
+        assert_eq!(Fract64::new(193, 71), Fract64::e_approx(100))
+    }
+
+    #[test]
+    fn should_div_rem_fract() {
+        assert_eq!(
+            Some((3, Fract64::new(1, 2))),
+            Fract64::new(7, 2).div_rem_fract(&Fract64::new(1, 1))
+        );
+        assert_eq!(
+            Some((10, Fract64::new(1, 6))),
+            Fract64::new(7, 2).div_rem_fract(&Fract64::new(1, 3))
+        );
+        assert_eq!(
+            Some((0, Fract64::new(1, 4))),
+            Fract64::new(1, 4).div_rem_fract(&Fract64::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_div_rem_fract_by_zero() {
+        assert_eq!(None, Fract64::new(7, 2).div_rem_fract(&Fract64::new(0, 1)))
+    }
+
+    #[test]
+    fn should_find_smallest_width() {
+        assert_eq!(Some("Fract8"), smallest_width(1, 255));
+        assert_eq!(Some("Fract16"), smallest_width(256, 3));
+        assert_eq!(Some("Fract32"), smallest_width(1, 65536));
+        assert_eq!(Some("Fract64"), smallest_width(u32::MAX as u64 + 1, 1));
+        assert_eq!(Some("Fract64"), smallest_width(u64::MAX, u64::MAX))
+    }
+
+    #[test]
+    fn should_not_find_width_for_zero_denominator() {
+        assert_eq!(None, smallest_width(3, 0))
+    }
+
+    #[test]
+    fn should_reduce_in_place() {
+        let mut fraction: Fract64 = Fract64::new(10, 18);
+        fraction.reduce_mut();
+        assert_eq!(Fract64::new(5, 9), fraction);
+
+        let mut zero: Fract64 = Fract64::new(0, 18);
+        zero.reduce_mut();
+        assert_eq!(Fract64::new(0, 1), zero)
+    }
+
+    #[test]
+    fn should_display_alternate_with_decimal() {
+        let fraction: Fract64 = Fract64::new(3, 4);
+        assert_eq!("3/4", format!("{}", fraction));
+        assert_eq!("3/4 (0.75)", format!("{:#}", fraction));
+        assert_eq!("3 (3)", format!("{:#}", Fract64::new(3, 1)))
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_exact_floats() {
+        assert_eq!(0, Fract64::new(1, 2).float_ulp_error());
+        assert_eq!(0, Fract64::new(3, 8).float_ulp_error());
+        assert_eq!(0, Fract64::new(0, 5).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_correctly_rounded_floats() {
+        assert_eq!(0, Fract64::new(1, 3).float_ulp_error());
+        assert_eq!(0, Fract64::new(2, 7).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_ulp_error_of_rounded_numerator() {
+        assert_eq!(1, Fract64::new((1 << 53) + 1, 3).float_ulp_error())
+    }
+
+    #[test]
+    fn should_reduce_into_fract8() {
+        assert_eq!(
+            Ok(Fract8::new(1, 2)),
+            Fract64::new(100, 200).reduce_into_fract8()
+        );
+        assert_eq!(
+            Ok(Fract8::new(u8::MAX, 1)),
+            Fract64::new(u8::MAX as u64 * 2, 2).reduce_into_fract8()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract8() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract64::new(u8::MAX as u64 + 1, 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract64::new(1, u8::MAX as u64 + 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract8::try_from(Fract64::new(2, u8::MAX as u64 * 2))
+        )
+    }
+
+    #[test]
+    fn should_reduce_into_fract16() {
+        assert_eq!(
+            Ok(Fract16::new(1, 2)),
+            Fract64::new(100, 200).reduce_into_fract16()
+        );
+        assert_eq!(
+            Ok(Fract16::new(u16::MAX, 1)),
+            Fract64::new(u16::MAX as u64 * 2, 2).reduce_into_fract16()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract16() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract64::new(u16::MAX as u64 + 1, 1).reduce_into_fract16()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract64::new(1, u16::MAX as u64 + 1).reduce_into_fract16()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract16::try_from(Fract64::new(2, u16::MAX as u64 * 2))
+        )
+    }
+
+    #[test]
+    fn should_reduce_into_fract32() {
+        assert_eq!(
+            Ok(Fract32::new(1, 2)),
+            Fract64::new(100, 200).reduce_into_fract32()
+        );
+        assert_eq!(
+            Ok(Fract32::new(u32::MAX, 1)),
+            Fract64::new(u32::MAX as u64 * 2, 2).reduce_into_fract32()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract32() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract64::new(u32::MAX as u64 + 1, 1).reduce_into_fract32()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract64::new(1, u32::MAX as u64 + 1).reduce_into_fract32()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract32::try_from(Fract64::new(2, u32::MAX as u64 * 2))
+        )
+    }
+
+    #[test]
+    fn should_find_modular_inverse() {
+        assert_eq!(Some(5), Fract64::new(3, 7).modular_inverse());
+        assert_eq!(Some(3), Fract64::new(7, 10).modular_inverse())
+    }
+
+    #[test]
+    fn should_not_find_modular_inverse() {
+        assert_eq!(None, Fract64::new(4, 8).modular_inverse());
+        assert_eq!(None, Fract64::new(3, 0).modular_inverse())
+    }
+
+    #[test]
+    fn should_round_trip_le_bytes() {
+        let fraction: Fract64 = Fract64::new(3, 250);
+        assert_eq!(fraction, Fract64::from_le_bytes(fraction.to_le_bytes()));
+        assert_eq!(
+            Fract64::new(u64::MAX, 1),
+            Fract64::from_le_bytes(Fract64::new(u64::MAX, 1).to_le_bytes())
+        )
+    }
+
+    #[test]
+    fn should_checked_add_int() {
+        assert_eq!(
+            Some(Fract64::new(3, 2)),
+            Fract64::new(1, 2).checked_add_int(1)
+        );
+        assert_eq!(
+            Some(Fract64::new(7, 1)),
+            Fract64::new(2, 1).checked_add_int(5)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_add_int_on_overflow() {
+        assert_eq!(None, Fract64::new(u64::MAX, 1).checked_add_int(1));
+        assert_eq!(None, Fract64::new(1, 2).checked_add_int(u64::MAX / 2 + 1))
+    }
+
+    #[test]
+    fn should_display_exact_within_bound() {
+        assert_eq!("1/2", Fract64::new(1, 2).display_approx(4));
+        assert_eq!("1/2", Fract64::new(2, 4).display_approx(2))
+    }
+
+    #[test]
+    fn should_display_approx_beyond_bound() {
+        assert_eq!("≈1/2", Fract64::new(127, 255).display_approx(4));
+        assert_eq!("≈1", Fract64::new(99, 100).display_approx(3))
+    }
+
+    #[test]
+    fn should_convert_float_within_iteration_cap() {
+        assert_eq!(
+            Ok(Fract64::new(3, 4)),
+            Fract64::from_float_capped(0.75, 10, 100)
+        );
+        assert_eq!(
+            Ok(Fract64::new(22, 7)),
+            Fract64::from_float_capped(std::f64::consts::PI, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_not_convert_float_past_iteration_cap() {
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract64::from_float_capped(std::f64::consts::PI, 2, 200)
+        );
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract64::from_float_capped(0.5, 1, 10)
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract64::from_float_capped(-0.5, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_sum_reduced() {
+        let halves: Vec<Fract64> = vec![Fract64::new(1, 2); 40];
+        assert_eq!(Some(Fract64::new(20, 1)), Fract64::sum_reduced(halves));
+        assert_eq!(
+            Some(Fract64::new(1, 6)),
+            Fract64::sum_reduced(vec![Fract64::new(1, 10), Fract64::new(1, 15)])
+        );
+        assert_eq!(Some(Fract64::new(0, 1)), Fract64::sum_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_sum_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract64::sum_reduced(vec![Fract64::new(u64::MAX, 1), Fract64::new(1, 1)])
+        );
+        assert_eq!(None, Fract64::sum_reduced(vec![Fract64::new(1, 0)]))
+    }
+
+    #[test]
+    fn should_product_reduced() {
+        let pairs: Vec<Fract64> = [Fract64::new(1, 2), Fract64::new(4, 2)].repeat(50);
+        assert_eq!(Some(Fract64::new(1, 1)), Fract64::product_reduced(pairs));
+        assert_eq!(
+            Some(Fract64::new(2, 5)),
+            Fract64::product_reduced(vec![Fract64::new(4, 6), Fract64::new(3, 5)])
+        );
+        assert_eq!(Some(Fract64::new(1, 1)), Fract64::product_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_product_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract64::product_reduced(vec![Fract64::new(u64::MAX, 1), Fract64::new(2, 1)])
+        )
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_terminating_fraction() {
+        assert_eq!(1, Fract64::new(1, 8).decimal_digit_at(1));
+        assert_eq!(2, Fract64::new(1, 8).decimal_digit_at(2));
+        assert_eq!(5, Fract64::new(1, 8).decimal_digit_at(3));
+        assert_eq!(0, Fract64::new(1, 8).decimal_digit_at(4));
+        assert_eq!(2, Fract64::new(25, 2).decimal_digit_at(0))
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_repeating_fraction() {
+        assert_eq!(3, Fract64::new(1, 3).decimal_digit_at(1));
+        assert_eq!(3, Fract64::new(1, 3).decimal_digit_at(50));
+        assert_eq!(7, Fract64::new(1, 7).decimal_digit_at(6));
+        assert_eq!(1, Fract64::new(1, 7).decimal_digit_at(7))
+    }
+
+    #[test]
+    fn should_reduce_checked() {
+        assert_eq!(
+            (Fract64::new(5, 9), true),
+            Fract64::new(10, 18).reduce_checked()
+        );
+        assert_eq!(
+            (Fract64::new(5, 9), false),
+            Fract64::new(5, 9).reduce_checked()
+        );
+        assert_eq!(
+            (Fract64::new(0, 1), true),
+            Fract64::new(0, 5).reduce_checked()
+        )
+    }
+
+    #[test]
+    fn should_create_from_bool() {
+        assert_eq!(Fract64::new(1, 1), Fract64::from(true));
+        assert_eq!(Fract64::new(0, 1), Fract64::from(false))
+    }
+
+    #[test]
+    fn should_find_weighted_average() {
+        let items: [(Fract64, Fract64); 2] = [
+            (Fract64::new(1, 2), Fract64::new(1, 1)),
+            (Fract64::new(1, 1), Fract64::new(3, 1)),
+        ];
+        assert_eq!(Some(Fract64::new(7, 8)), weighted_average(&items));
+
+        let items: [(Fract64, Fract64); 2] = [
+            (Fract64::new(2, 3), Fract64::new(1, 4)),
+            (Fract64::new(1, 3), Fract64::new(1, 4)),
+        ];
+        assert_eq!(Some(Fract64::new(1, 2)), weighted_average(&items))
+    }
+
+    #[test]
+    fn should_not_find_weighted_average_without_weight() {
+        let items: [(Fract64, Fract64); 2] = [
+            (Fract64::new(1, 2), Fract64::new(0, 1)),
+            (Fract64::new(1, 3), Fract64::new(0, 5)),
+        ];
+        assert_eq!(None, weighted_average(&items));
+        assert_eq!(None, weighted_average(&[]))
+    }
+
+    #[test]
+    fn should_scale() {
+        assert_eq!(Some(Fract64::new(3, 6)), Fract64::new(1, 2).scale(3));
+        assert_eq!(Some(Fract64::new(0, 0)), Fract64::new(1, 2).scale(0))
+    }
+
+    #[test]
+    fn should_not_scale_on_overflow() {
+        assert_eq!(None, Fract64::new(1, u64::MAX / 2 + 1).scale(2));
+        assert_eq!(None, Fract64::new(u64::MAX, 1).scale(2))
+    }
+
+    #[test]
+    fn should_parse_flexible_forms() {
+        let expected: Fract64 = Fract64::new(11, 4);
+        assert_eq!(Ok(expected), Fract64::parse_flexible("2 3/4"));
+        assert_eq!(Ok(expected), Fract64::parse_flexible("2.75"));
+        assert_eq!(Ok(expected), Fract64::parse_flexible("11/4"));
+        assert_eq!(Ok(expected), Fract64::parse_flexible(" 22 / 8 "));
+        assert_eq!(Ok(Fract64::new(3, 1)), Fract64::parse_flexible("3"))
+    }
+
+    #[test]
+    fn should_not_parse_malformed_flexible_input() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::parse_flexible("2 3/x")
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::parse_flexible("a 3/4")
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::parse_flexible("2.7.5")
+        );
+        assert_eq!(Err(FractError::InvalidFormat), Fract64::parse_flexible(""))
+    }
+
+    #[test]
+    fn should_not_parse_mixed_number_without_fraction() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::parse_flexible("2 5")
+        )
+    }
+
+    #[test]
+    fn should_combine_in_parallel() {
+        assert_eq!(
+            Some(Fract64::new(1, 1)),
+            parallel(&[Fract64::new(2, 1), Fract64::new(2, 1)])
+        );
+        assert_eq!(
+            Some(Fract64::new(6, 11)),
+            parallel(&[Fract64::new(1, 1), Fract64::new(2, 1), Fract64::new(3, 1)])
+        );
+        assert_eq!(Some(Fract64::new(3, 4)), parallel(&[Fract64::new(6, 8)]))
+    }
+
+    #[test]
+    fn should_not_combine_in_parallel() {
+        assert_eq!(None, parallel(&[]));
+        assert_eq!(None, parallel(&[Fract64::new(2, 1), Fract64::new(0, 1)]))
+    }
+
+    #[test]
+    fn should_format_sig_figs() {
+        assert_eq!("0.3333", Fract64::new(1, 3).to_sig_figs(4));
+        assert_eq!("3.14", Fract64::new(22, 7).to_sig_figs(3));
+        assert_eq!("0.667", Fract64::new(2, 3).to_sig_figs(3));
+        assert_eq!("", Fract64::new(2, 0).to_sig_figs(3))
+    }
+
+    #[test]
+    fn should_debug_fields() {
+        assert_eq!(
+            "Fract64 { numerator: 10, denominator: 18 }",
+            format!("{:?}", Fract64::new(10, 18))
+        )
+    }
+
+    #[test]
+    fn should_debug_alternate_with_reduced_value() {
+        assert_eq!(
+            "Fract64 { numerator: 1, denominator: 2 }",
+            format!("{:?}", Fract64::new(1, 2))
+        );
+        assert_eq!(
+            "Fract64 { numerator: 10, denominator: 20, reduced: 1/2, value: 0.5 }",
+            format!("{:#?}", Fract64::new(10, 20))
+        )
+    }
+
+    #[test]
+    fn should_clamp_to_one() {
+        assert_eq!(Fract64::new(1, 1), Fract64::new(3, 2).clamp01());
+        assert_eq!(Fract64::new(1, 2), Fract64::new(1, 2).clamp01());
+        assert_eq!(Fract64::new(4, 4), Fract64::new(4, 4).clamp01())
+    }
+
+    #[test]
+    fn should_find_geometric_mean() {
+        let fractions: [Fract64; 2] = [Fract64::new(1, 4), Fract64::new(1, 1)];
+        assert_eq!(Some(Fract64::new(1, 2)), geometric_mean(&fractions, 100));
+
+        let fractions: [Fract64; 2] = [Fract64::new(1, 1), Fract64::new(2, 1)];
+        assert_eq!(Some(Fract64::new(140, 99)), geometric_mean(&fractions, 100));
+        assert_eq!(
+            Some(Fract64::new(0, 1)),
+            geometric_mean(&[Fract64::new(0, 1), Fract64::new(3, 1)], 100)
+        )
+    }
+
+    #[test]
+    fn should_not_find_geometric_mean_of_empty_slice() {
+        assert_eq!(None, geometric_mean(&[], 100))
+    }
+
+    #[test]
+    fn should_find_harmonic_mean() {
+        let fractions: [Fract64; 2] = [Fract64::new(1, 1), Fract64::new(1, 2)];
+        assert_eq!(Some(Fract64::new(2, 3)), harmonic_mean(&fractions));
+        let fractions: [Fract64; 3] = [Fract64::new(2, 4), Fract64::new(1, 2), Fract64::new(1, 2)];
+        assert_eq!(Some(Fract64::new(1, 2)), harmonic_mean(&fractions))
+    }
+
+    #[test]
+    fn should_not_find_harmonic_mean_of_empty_or_zero() {
+        assert_eq!(None, harmonic_mean(&[]));
+        assert_eq!(
+            None,
+            harmonic_mean(&[Fract64::new(1, 2), Fract64::new(0, 3)])
+        )
+    }
+
+    #[test]
+    fn should_create_exactly_from_f64() {
+        assert_eq!(Ok(Fract64::new(1, 2)), Fract64::from_f64_exact(0.5));
+        assert_eq!(Ok(Fract64::new(1, 4)), Fract64::from_f64_exact(0.25));
+        assert_eq!(Ok(Fract64::new(3, 1)), Fract64::from_f64_exact(3.0));
+        assert_eq!(Ok(Fract64::new(0, 1)), Fract64::from_f64_exact(0.0));
+        assert_eq!(
+            Ok(Fract64::new(3602879701896397, 1 << 55)),
+            Fract64::from_f64_exact(0.1)
+        )
+    }
+
+    #[test]
+    fn should_not_create_exactly_from_f64() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_f64_exact(f64::NAN)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract64::from_f64_exact(f64::INFINITY)
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract64::from_f64_exact(-0.5)
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract64::from_f64_exact(1e20)
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract64::from_f64_exact(1e-30)
+        )
+    }
+
+    #[test]
+    fn should_find_period_length() {
+        assert_eq!(Some(0), Fract64::new(1, 2).period_length());
+        assert_eq!(Some(0), Fract64::new(3, 40).period_length());
+        assert_eq!(Some(1), Fract64::new(1, 3).period_length());
+        assert_eq!(Some(6), Fract64::new(1, 7).period_length());
+        assert_eq!(Some(6), Fract64::new(1, 14).period_length());
+        assert_eq!(Some(2), Fract64::new(1, 11).period_length());
+        assert_eq!(Some(0), Fract64::new(7, 7).period_length())
+    }
+
+    #[test]
+    fn should_find_long_period_length() {
+        assert_eq!(
+            Some(1_000_000_006),
+            Fract64::new(1, 1_000_000_007).period_length()
+        );
+        assert_eq!(
+            Some(499_122_178_994_733_056),
+            Fract64::new(3, 1_000_000_007 * 998_244_353 * 8).period_length()
+        )
+    }
+
+    #[test]
+    fn should_fit_in_fract8() {
+        assert!(Fract64::new(100, 200).fits_in_fract8());
+        assert!(Fract64::new(u8::MAX as u64 * 2, 2).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract8() {
+        assert!(!Fract64::new(u8::MAX as u64 + 1, 1).fits_in_fract8());
+        assert!(!Fract64::new(1, u8::MAX as u64 + 1).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_fit_in_fract16() {
+        assert!(Fract64::new(100, 200).fits_in_fract16());
+        assert!(Fract64::new(u16::MAX as u64 * 2, 2).fits_in_fract16())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract16() {
+        assert!(!Fract64::new(u16::MAX as u64 + 1, 1).fits_in_fract16());
+        assert!(!Fract64::new(1, u16::MAX as u64 + 1).fits_in_fract16())
+    }
+
+    #[test]
+    fn should_fit_in_fract32() {
+        assert!(Fract64::new(100, 200).fits_in_fract32());
+        assert!(Fract64::new(u32::MAX as u64 * 2, 2).fits_in_fract32())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract32() {
+        assert!(!Fract64::new(u32::MAX as u64 + 1, 1).fits_in_fract32());
+        assert!(!Fract64::new(1, u32::MAX as u64 + 1).fits_in_fract32())
+    }
+
+    #[test]
+    fn should_mul_with_cross_cancellation() {
+        assert_eq!(
+            Fract64::new(1, 1),
+            Fract64::new(u64::MAX, 3) * Fract64::new(3, u64::MAX)
+        );
+        assert_eq!(
+            Fract64::new(2, 1),
+            Fract64::new(100, 9) * Fract64::new(9, 50)
+        )
+    }
+
+    #[test]
+    fn should_div_with_cross_cancellation() {
+        assert_eq!(
+            Fract64::new(2, 1),
+            Fract64::new(u64::MAX, 2) / Fract64::new(u64::MAX, 4)
+        );
+        assert_eq!(
+            Fract64::new(2, 3),
+            Fract64::new(200, 3) / Fract64::new(100, 1)
+        );
+        assert_eq!(Fract64::new(1, 4), Fract64::new(4, 6) / Fract64::new(8, 3))
+    }
+
+    #[test]
+    fn should_validate() {
+        assert_eq!(Ok(()), Fract64::new(1, 2).validate());
+        assert_eq!(Ok(()), Fract64::new(0, 3).validate())
+    }
+
+    #[test]
+    fn should_not_validate_zero_denominator() {
+        let fraction: Fract64 = Fract64 {
+            numerator: 1,
+            denominator: 0,
+        };
+
+        assert_eq!(Err(FractError::ZeroDenominator), fraction.validate())
+    }
+
+    #[test]
+    fn should_find_rounded_decimal_digits() {
+        assert_eq!(
+            vec![3, 3, 3, 3],
+            Fract64::new(1, 3).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![6, 7], Fract64::new(2, 3).decimal_digits_rounded(2));
+        assert_eq!(
+            vec![1, 2, 5, 0],
+            Fract64::new(1, 8).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![1, 3], Fract64::new(1, 8).decimal_digits_rounded(2))
+    }
+
+    #[test]
+    fn should_drop_carry_of_rounded_decimal_digits() {
+        assert_eq!(vec![0, 0], Fract64::new(199, 200).decimal_digits_rounded(2));
+        assert_eq!(
+            Vec::<u8>::new(),
+            Fract64::new(2, 3).decimal_digits_rounded(0)
+        )
+    }
+
+    #[test]
+    fn should_reduce_fast_paths() {
+        assert_eq!(Fract64::new(1, 9), Fract64::new(1, 9).reduce());
+        assert_eq!(Fract64::new(9, 1), Fract64::new(9, 1).reduce());
+        assert_eq!(Fract64::new(0, 1), Fract64::new(0, 9).reduce());
+        assert_eq!(Fract64::new(0, 0), Fract64::new(0, 0).reduce());
+        assert_eq!(Fract64::new(1, 0), Fract64::new(1, 0).reduce())
+    }
+
+    #[test]
+    fn should_collect_into_sum() {
+        let fractions: Vec<Fract64> =
+            vec![Fract64::new(1, 2), Fract64::new(1, 3), Fract64::new(1, 6)];
+        let total: Fract64 = fractions.into_iter().collect();
+        assert_eq!(Fract64::new(1, 1), total.reduce());
+
+        let empty: Fract64 = Vec::new().into_iter().collect();
+        assert_eq!(Fract64::new(0, 1), empty)
+    }
+
+    #[test]
+    fn should_convert_exactly_to_f32() {
+        assert_eq!(Ok(0.5), Fract64::new(1, 2).to_f32_checked());
+        assert_eq!(Ok(16777216.0), Fract64::new(1 << 24, 1).to_f32_checked());
+        assert_eq!(Ok(0.0), Fract64::new(0, 7).to_f32_checked())
+    }
+
+    #[test]
+    fn should_flag_inexact_f32() {
+        assert_eq!(
+            Err(16777216.0),
+            Fract64::new((1 << 24) + 1, 1).to_f32_checked()
+        );
+        assert!(Fract64::new(1, 3).to_f32_checked().is_err());
+        assert!(Fract64::new(1, 0).to_f32_checked().is_err())
+    }
+
+    #[test]
+    fn should_convert_unreduced_exactly_to_f32() {
+        let k: u64 = 1_073_741_867;
+        assert_eq!(Ok(0.75), Fract64::new(3 * k, 4 * k).to_f32_checked())
+    }
+
+    #[test]
+    fn should_create_from_counts() {
+        assert_eq!(
+            Ok(Fract64::new(1, 2)),
+            Fract64::from_counts(1_000_000, 2_000_000)
+        );
+        assert_eq!(Ok(Fract64::new(0, 1)), Fract64::from_counts(0, 35))
+    }
+
+    #[test]
+    fn should_not_create_from_counts_with_zero_denominator() {
+        assert_eq!(Err(FractError::ZeroDenominator), Fract64::from_counts(3, 0))
+    }
+
+    #[test]
+    fn should_rationalize() {
+        assert_eq!(Fract64::new(1, 10), rationalize(0.1, 100));
+        assert_eq!(
+            Fract64::new(10, 21),
+            rationalize((Fract64::new(1, 3) + Fract64::new(1, 7)).to_float(), 100)
+        );
+        assert_eq!(Fract64::new(1, 3), rationalize(0.34, 5))
+    }
+
+    #[test]
+    fn should_solve_proportion() {
+        assert_eq!(Some(5), solve_proportion(Fract64::new(1, 2), 10));
+        assert_eq!(Some(6), solve_proportion(Fract64::new(4, 6), 9));
+        assert_eq!(
+            Some(u64::MAX),
+            solve_proportion(Fract64::new(u64::MAX, 2), 2)
+        )
+    }
+
+    #[test]
+    fn should_not_solve_proportion() {
+        assert_eq!(None, solve_proportion(Fract64::new(1, 3), 10));
+        assert_eq!(None, solve_proportion(Fract64::new(1, 2), 0));
+        assert_eq!(None, solve_proportion(Fract64::new(u64::MAX, 1), 2))
+    }
+
+    #[test]
+    fn should_hash_consistently_across_widths() {
+        fn hash_of<T: Hash>(value: T) -> u64 {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash_of(Fract8::new(1, 2)), hash_of(Fract64::new(1, 2)));
+        assert_eq!(hash_of(Fract64::new(1, 2)), hash_of(Fract64::new(2, 4)));
+        assert_ne!(hash_of(Fract64::new(1, 2)), hash_of(Fract64::new(1, 3)))
+    }
+
+    #[test]
+    fn should_find_minimal_form() {
+        assert_eq!(Fract64::new(1, 2), Fract64::new(50, 100).minimal());
+        assert_eq!(Fract64::new(0, 1), Fract64::new(0, 7).minimal());
+        assert_eq!(Fract64::new(3, 4), Fract64::new(3, 4).minimal())
+    }
+
+    #[test]
+    fn should_fit_smallest_width() {
+        assert_eq!(
+            Some(AnyFract::Fract8(Fract8::new(1, 2))),
+            AnyFract::fit(1, 2)
+        );
+        assert_eq!(
+            Some(AnyFract::Fract8(Fract8::new(1, 2))),
+            AnyFract::fit(500, 1000)
+        );
+        assert_eq!(
+            Some(AnyFract::Fract16(Fract16::new(256, 3))),
+            AnyFract::fit(256, 3)
+        );
+        assert_eq!(
+            Some(AnyFract::Fract32(Fract32::new(1, 65536))),
+            AnyFract::fit(1, 65536)
+        );
+        assert_eq!(
+            Some(AnyFract::Fract64(Fract64::new(u64::MAX, 2))),
+            AnyFract::fit(u64::MAX, 2)
+        )
+    }
+
+    #[test]
+    fn should_not_fit_zero_denominator() {
+        assert_eq!(None, AnyFract::fit(1, 0))
+    }
+
+    #[test]
+    fn should_linspace() {
+        assert_eq!(
+            vec![
+                Fract64::new(0, 1),
+                Fract64::new(1, 4),
+                Fract64::new(1, 2),
+                Fract64::new(3, 4),
+                Fract64::new(1, 1)
+            ],
+            linspace(Fract64::new(0, 1), Fract64::new(1, 1), 5)
+        );
+        assert_eq!(
+            vec![Fract64::new(1, 1), Fract64::new(2, 3), Fract64::new(1, 3)],
+            linspace(Fract64::new(1, 1), Fract64::new(1, 3), 3)
+        )
+    }
+
+    #[test]
+    fn should_linspace_few_steps() {
+        assert_eq!(
+            Vec::<Fract64>::new(),
+            linspace(Fract64::new(0, 1), Fract64::new(1, 1), 0)
+        );
+        assert_eq!(
+            vec![Fract64::new(1, 2)],
+            linspace(Fract64::new(2, 4), Fract64::new(1, 1), 1)
+        )
+    }
+
+    #[test]
+    fn should_checked_shl() {
+        assert_eq!(Some(Fract64::new(3, 1)), Fract64::new(3, 4).checked_shl(2));
+        assert_eq!(Some(Fract64::new(12, 1)), Fract64::new(3, 1).checked_shl(2));
+        assert_eq!(
+            Some(Fract64::new(0, 5)),
+            Fract64::new(0, 5).checked_shl(200)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_shl_on_overflow() {
+        assert_eq!(None, Fract64::new(u64::MAX / 2 + 1, 1).checked_shl(1));
+        assert_eq!(None, Fract64::new(1, 1).checked_shl(u64::BITS))
+    }
+
+    #[test]
+    fn should_checked_shr() {
+        assert_eq!(Some(Fract64::new(3, 1)), Fract64::new(12, 1).checked_shr(2));
+        assert_eq!(Some(Fract64::new(3, 4)), Fract64::new(3, 1).checked_shr(2))
+    }
+
+    #[test]
+    fn should_not_checked_shr_on_overflow() {
+        assert_eq!(None, Fract64::new(1, u64::MAX / 2 + 1).checked_shr(1))
+    }
+
+    #[test]
+    fn should_reduce_keeping_zero_denominator() {
+        assert_eq!(
+            Fract64::new(0, 6),
+            Fract64::new(0, 6).reduce_keep_denominator()
+        );
+        assert_eq!(
+            Fract64::new(5, 9),
+            Fract64::new(10, 18).reduce_keep_denominator()
+        )
+    }
+
+    #[test]
+    fn should_format_mixed_string() {
+        assert_eq!("3 1/2", Fract64::new(7, 2).to_mixed_string());
+        assert_eq!("1/2", Fract64::new(1, 2).to_mixed_string());
+        assert_eq!("2", Fract64::new(6, 3).to_mixed_string());
+        assert_eq!("1 1/3", Fract64::new(8, 6).to_mixed_string());
+        assert_eq!("0", Fract64::new(0, 4).to_mixed_string())
+    }
+
+    #[test]
+    fn should_find_ratio() {
+        assert_eq!(
+            Some(Fract64::new(3, 2)),
+            Fract64::new(3, 4).ratio_to(&Fract64::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract64::new(1, 1)),
+            Fract64::new(6, 8).ratio_to(&Fract64::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_not_find_ratio_to_zero() {
+        assert_eq!(None, Fract64::new(3, 4).ratio_to(&Fract64::new(0, 1)))
+    }
+
+    #[test]
+    fn should_be_between() {
+        assert!(Fract64::new(1, 2).is_between(&Fract64::new(0, 1), &Fract64::new(1, 1)));
+        assert!(Fract64::new(1, 2).is_between(&Fract64::new(1, 1), &Fract64::new(0, 1)));
+        assert!(Fract64::new(2, 4).is_between(&Fract64::new(1, 2), &Fract64::new(3, 4)))
+    }
+
+    #[test]
+    fn should_not_be_between() {
+        assert!(!Fract64::new(1, 2).is_between(&Fract64::new(3, 4), &Fract64::new(1, 1)))
+    }
+
+    #[test]
+    fn should_find_convergents() {
+        let convergents: Vec<Fract64> = Fract64::new(43, 19).convergents();
+        assert_eq!(Fract64::new(2, 1), convergents[0]);
+        assert_eq!(Fract64::new(7, 3), convergents[1]);
+        assert_eq!(
+            vec![
+                Fract64::new(2, 1),
+                Fract64::new(7, 3),
+                Fract64::new(9, 4),
+                Fract64::new(43, 19)
+            ],
+            convergents
+        );
+        assert_eq!(
+            Some(&Fract64::new(43, 19)),
+            Fract64::new(86, 38).convergents().last()
+        )
+    }
+
+    #[test]
+    fn should_find_convergents_of_edge_values() {
+        assert_eq!(
+            vec![Fract64::new(0, 1), Fract64::new(1, 3)],
+            Fract64::new(1, 3).convergents()
+        );
+        assert_eq!(
+            vec![Fract64::new(u64::MAX, 1)],
+            Fract64::new(u64::MAX, 1).convergents()
+        );
+        assert_eq!(Vec::<Fract64>::new(), Fract64::new(1, 0).convergents())
+    }
+
+    #[test]
+    fn should_cmp_with_float_exactly() {
+        assert_eq!(Ordering::Equal, Fract64::new(1, 2).cmp_with_float(0.5));
+        assert_eq!(Ordering::Less, Fract64::new(1, 2).cmp_with_float(0.75));
+        assert_eq!(Ordering::Greater, Fract64::new(3, 2).cmp_with_float(1.25))
+    }
+
+    #[test]
+    fn should_cmp_with_float_next_to_one_third() {
+        let third: Fract64 = Fract64::new(1, 3);
+        let nearest: f64 = 1.0 / 3.0;
+        assert_eq!(nearest, third.to_float());
+        assert_eq!(Ordering::Greater, third.cmp_with_float(nearest))
+    }
+
+    #[test]
+    fn should_split_integer_and_fraction() {
+        assert_eq!(
+            (Fract64::new(3, 1), Fract64::new(1, 2)),
+            Fract64::new(7, 2).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract64::new(2, 1), Fract64::new(0, 1)),
+            Fract64::new(6, 3).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract64::new(0, 1), Fract64::new(2, 3)),
+            Fract64::new(4, 6).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_zero_denominator_into_itself() {
+        assert_eq!(
+            (Fract64::new(1, 0), Fract64::new(0, 1)),
+            Fract64::new(1, 0).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_snap_to_closest() {
+        let allowed: [Fract64; 3] = [Fract64::new(1, 2), Fract64::new(3, 4), Fract64::new(1, 1)];
+        assert_eq!(
+            Some(Fract64::new(1, 2)),
+            Fract64::new(9, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract64::new(3, 4)),
+            Fract64::new(11, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract64::new(1, 1)),
+            Fract64::new(3, 2).snap_to(&allowed)
+        )
+    }
+
+    #[test]
+    fn should_snap_ties_to_first() {
+        let allowed: [Fract64; 2] = [Fract64::new(1, 2), Fract64::new(3, 4)];
+        assert_eq!(
+            Some(Fract64::new(1, 2)),
+            Fract64::new(5, 8).snap_to(&allowed)
+        );
+        let reversed: [Fract64; 2] = [Fract64::new(3, 4), Fract64::new(1, 2)];
+        assert_eq!(
+            Some(Fract64::new(3, 4)),
+            Fract64::new(5, 8).snap_to(&reversed)
+        )
+    }
+
+    #[test]
+    fn should_not_snap_to_empty_slice() {
+        assert_eq!(None, Fract64::new(5, 8).snap_to(&[]))
+    }
+
+    #[test]
+    fn should_try_from_signed_pair() {
+        assert_eq!(Ok(Fract64::new(2, 3)), Fract64::try_from((10i64, 15i64)));
+        assert_eq!(Ok(Fract64::new(0, 1)), Fract64::try_from((0i64, 7i64)))
+    }
+
+    #[test]
+    fn should_not_try_from_negative_pair() {
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract64::try_from((-1i64, 2i64))
+        );
+        assert_eq!(
+            Err(FractError::DenominatorUnderflow),
+            Fract64::try_from((1i64, -2i64))
+        )
+    }
+
+    #[test]
+    fn should_not_try_from_signed_zero_denominator() {
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract64::try_from((1i64, 0i64))
+        )
+    }
+
+    #[test]
+    fn should_render_in_radix() {
+        assert_eq!("0.1", Fract64::new(1, 2).to_string_radix(2, 10));
+        assert_eq!("0.1", Fract64::new(1, 3).to_string_radix(3, 10));
+        assert_eq!("0.0101", Fract64::new(1, 3).to_string_radix(2, 4));
+        assert_eq!("12", Fract64::new(10, 2).to_string_radix(3, 4))
+    }
+
+    #[test]
+    fn should_format_continued_fraction() {
+        assert_eq!(
+            "[2; 3, 1, 4]",
+            Fract64::new(43, 19).continued_fraction_string()
+        );
+        assert_eq!("[0; 2]", Fract64::new(1, 2).continued_fraction_string());
+        assert_eq!("[5]", Fract64::new(5, 1).continued_fraction_string())
+    }
+
+    #[test]
+    fn should_find_reduced_reciprocal() {
+        assert_eq!(
+            Some(Fract64::new(9, 5)),
+            Fract64::new(10, 18).reciprocal_reduced()
+        );
+        assert_eq!(
+            Some(Fract64::new(1, 3)),
+            Fract64::new(3, 1).reciprocal_reduced()
+        )
+    }
+
+    #[test]
+    fn should_not_find_reciprocal_of_zero() {
+        assert_eq!(None, Fract64::new(0, 5).reciprocal_reduced())
+    }
+
+    #[test]
+    fn should_find_cross_difference() {
+        assert_eq!(-1, Fract64::new(1, 2).cross_difference(&Fract64::new(2, 3)));
+        assert_eq!(1, Fract64::new(2, 3).cross_difference(&Fract64::new(1, 2)));
+        assert_eq!(0, Fract64::new(2, 4).cross_difference(&Fract64::new(1, 2)));
+        assert_eq!(
+            u64::MAX as i128,
+            Fract64::new(u64::MAX, 1).cross_difference(&Fract64::new(0, 1))
+        )
+    }
+
+    #[test]
+    fn should_find_simplest_between() {
+        assert_eq!(
+            Some(Fract64::new(2, 5)),
+            Fract64::new(1, 3).simplest_between(&Fract64::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract64::new(2, 5)),
+            Fract64::new(1, 2).simplest_between(&Fract64::new(1, 3))
+        );
+        assert_eq!(
+            Some(Fract64::new(3, 7)),
+            Fract64::new(5, 12).simplest_between(&Fract64::new(4, 9))
+        )
+    }
+
+    #[test]
+    fn should_find_integer_between() {
+        assert_eq!(
+            Some(Fract64::new(2, 1)),
+            Fract64::new(3, 2).simplest_between(&Fract64::new(7, 2))
+        );
+        assert_eq!(
+            Some(Fract64::new(1, 1)),
+            Fract64::new(0, 1).simplest_between(&Fract64::new(3, 1))
+        )
+    }
+
+    #[test]
+    fn should_return_reduced_value_between_equal_bounds() {
+        assert_eq!(
+            Some(Fract64::new(1, 2)),
+            Fract64::new(2, 4).simplest_between(&Fract64::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_find_simplest_between_beyond_width() {
+        assert_eq!(
+            None,
+            Fract64::new(1, u64::MAX).simplest_between(&Fract64::new(1, u64::MAX - 1))
+        );
+        assert_eq!(
+            None,
+            Fract64::new(u64::MAX - 1, u64::MAX).simplest_between(&Fract64::new(1, 1))
+        )
+    }
+}
+
+// Fract128
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct Fract128 {
+    pub numerator: u128,
+    pub denominator: u128,
+}
+
+impl Fract<u128, Fract128, f64> for Fract128 {
+    #[inline]
+    fn to_float(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    #[inline]
+    fn new(numerator: u128, denominator: u128) -> Fract128 {
+        Fract128 {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn invert(&self) -> Fract128 {
+        Fract128 {
+            numerator: self.denominator,
+            denominator: self.numerator,
+        }
+    }
+
+    #[inline]
+    fn expand(&self, multiplicator: u128) -> Fract128 {
+        Fract128 {
+            numerator: self.numerator * multiplicator,
+            denominator: self.denominator * multiplicator,
+        }
+    }
+
+    #[inline]
+    fn reduce(&self) -> Fract128 {
+        // already reduced forms skip the gcd loop
+        if self.numerator == 1 || self.denominator == 1 {
+            return *self;
+        }
+        if self.numerator == 0 && self.denominator != 0 {
+            return Fract128 {
+                numerator: 0,
+                denominator: 1,
+            };
+        }
+
+        match utils::checked_gcd_u128(self.numerator, self.denominator) {
+            Some(gcd) => Fract128 {
+                numerator: self.numerator / gcd,
+                denominator: self.denominator / gcd,
+            },
+            None => *self,
+        }
+    }
+
+    #[inline]
+    fn invert_checked(&self) -> Result<Fract128, FractError> {
+        if self.numerator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(self.invert())
+    }
+
+    #[inline]
+    fn nearest_over(&self, denominator: u128) -> Fract128 {
+        let whole: u128 = self.numerator / self.denominator;
+        let remainder: u128 = self.numerator % self.denominator;
+        let (quotient, rest) = utils::mul_div_rem_u128(remainder, denominator, self.denominator);
+        // ties round up; comparing against the complement avoids doubling `rest`
+        let rounded: u128 = quotient + (rest >= self.denominator - rest) as u128;
+
+        Fract128 {
+            numerator: whole * denominator + rounded,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn mul_checked(&self, other: &Fract128) -> Result<Fract128, FractError> {
+        let numerator: u128 = self
+            .numerator
+            .checked_mul(other.numerator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let denominator: u128 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+
+        Ok(Fract128 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn round_with(&self, mode: RoundingMode) -> u128 {
+        let whole: u128 = self.numerator / self.denominator;
+        let remainder: u128 = self.numerator % self.denominator;
+        if remainder == 0 {
+            return whole;
+        }
+
+        // compares the remainder against half the denominator without overflow
+        let half: Ordering = remainder.cmp(&(self.denominator - remainder));
+        let round_up: bool = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => match half {
+                Ordering::Less => false,
+                Ordering::Equal => whole % 2 == 1,
+                Ordering::Greater => true,
+            },
+        };
+
+        if round_up {
+            whole + 1
+        } else {
+            whole
+        }
+    }
+
+    #[inline]
+    fn percentage_of(&self, whole: &Fract128) -> Fract128 {
+        let ratio: Fract128 = (*self / *whole).reduce();
+        let gcd: u128 = utils::gcd_u128(100, ratio.denominator);
+        Fract128 {
+            numerator: ratio.numerator * (100 / gcd),
+            denominator: ratio.denominator / gcd,
+        }
+    }
+
+    #[inline]
+    fn try_from_u128(value: u128) -> Result<Fract128, FractError> {
+        let numerator: u128 = value;
+        Ok(Fract128::from(numerator))
+    }
+
+    #[inline]
+    fn equivalent_forms(&self, max_denominator: u128) -> Vec<Fract128> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let reduced: Fract128 = self.reduce();
+        let mut forms: Vec<Fract128> = Vec::new();
+        let mut factor: u128 = 1;
+        while let (Some(numerator), Some(denominator)) = (
+            reduced.numerator.checked_mul(factor),
+            reduced.denominator.checked_mul(factor),
+        ) {
+            if denominator > max_denominator {
+                break;
+            }
+
+            forms.push(Fract128 {
+                numerator,
+                denominator,
+            });
+            factor = match factor.checked_add(1) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        forms
+    }
+
+    #[inline]
+    fn cmp_exact(&self, other: &Fract128) -> Ordering {
+        utils::cmp_ratio_u128(
+            self.numerator,
+            self.denominator,
+            other.numerator,
+            other.denominator,
+        )
+    }
+
+    #[inline]
+    fn abs_diff_float(&self, other: &Fract128) -> f64 {
+        (self.to_float() - other.to_float()).abs()
+    }
+
+    #[inline]
+    fn saturating_from_float(value: f64, max_denominator: u128) -> Fract128 {
+        if value.is_nan() || value <= 0.0 {
+            return Fract128::from(0);
+        }
+
+        if value >= u128::MAX as f64 {
+            return Fract128::from(u128::MAX);
+        }
+
+        let (numerator, denominator) =
+            utils::approximate_f64(value, u128::MAX, max_denominator.max(1));
+        Fract128 {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn continued_fraction(&self) -> Vec<u128> {
+        let mut coefficients: Vec<u128> = Vec::new();
+        let mut numerator: u128 = self.numerator;
+        let mut denominator: u128 = self.denominator;
+        while denominator != 0 {
+            coefficients.push(numerator / denominator);
+            let remainder: u128 = numerator % denominator;
+            numerator = denominator;
+            denominator = remainder;
+        }
+
+        coefficients
+    }
+
+    #[inline]
+    fn from_continued_fraction(coefficients: &[u128]) -> Fract128 {
+        let (last, rest) = match coefficients.split_last() {
+            Some(split) => split,
+            None => return Fract128::from(0),
+        };
+
+        rest.iter()
+            .rev()
+            .fold(Fract128::from(*last), |value, coefficient| Fract128 {
+                numerator: coefficient * value.numerator + value.denominator,
+                denominator: value.numerator,
+            })
+    }
+
+    #[inline]
+    fn stern_brocot_path(&self) -> Vec<bool> {
+        let reduced: Fract128 = self.reduce();
+        if reduced.numerator == 0 || reduced.denominator == 0 {
+            return Vec::new();
+        }
+
+        let coefficients: Vec<u128> = reduced.continued_fraction();
+        let last: usize = coefficients.len() - 1;
+        let mut path: Vec<bool> = Vec::new();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            let steps: u128 = if index == last {
+                coefficient - 1
+            } else {
+                *coefficient
+            };
+
+            for _ in 0..steps {
+                path.push(index % 2 == 0);
+            }
+        }
+
+        path
+    }
+
+    #[inline]
+    fn add_checked(&self, other: &Fract128) -> Result<Fract128, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract128 {
+            numerator: lhs.checked_add(rhs).ok_or(FractError::NumeratorOverflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn sub_checked(&self, other: &Fract128) -> Result<Fract128, FractError> {
+        let (lhs, rhs, denominator) = self.expand_to_common_checked(other)?;
+        Ok(Fract128 {
+            numerator: lhs.checked_sub(rhs).ok_or(FractError::NumeratorUnderflow)?,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn div_checked(&self, other: &Fract128) -> Result<Fract128, FractError> {
+        self.mul_checked(&other.invert_checked()?)
+    }
+
+    #[inline]
+    fn checked_add_assign(&mut self, other: Fract128) -> Result<(), FractError> {
+        *self = self.add_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_sub_assign(&mut self, other: Fract128) -> Result<(), FractError> {
+        *self = self.sub_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_mul_assign(&mut self, other: Fract128) -> Result<(), FractError> {
+        *self = self.mul_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn checked_div_assign(&mut self, other: Fract128) -> Result<(), FractError> {
+        *self = self.div_checked(&other)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn to_string_reduced(&self) -> String {
+        let reduced: Fract128 = self.reduce();
+        if reduced.denominator == 1 {
+            reduced.numerator.to_string()
+        } else {
+            format!("{}/{}", reduced.numerator, reduced.denominator)
+        }
+    }
+
+    #[inline]
+    fn denominator_factors(&self) -> Vec<(u128, u32)> {
+        let mut remaining: u128 = self.reduce().denominator;
+        let mut factors: Vec<(u128, u32)> = Vec::new();
+        let mut divisor: u128 = 2;
+        while remaining > 1 && divisor <= remaining / divisor {
+            let mut exponent: u32 = 0;
+            while remaining.is_multiple_of(divisor) {
+                remaining /= divisor;
+                exponent += 1;
+            }
+
+            if exponent > 0 {
+                factors.push((divisor, exponent));
+            }
+            divisor += 1;
+        }
+
+        if remaining > 1 {
+            factors.push((remaining, 1));
+        }
+
+        factors
+    }
+
+    #[inline]
+    fn terminates_in_base(&self, base: u128) -> bool {
+        let mut denominator: u128 = self.reduce().denominator;
+        if denominator == 0 {
+            return false;
+        }
+        if base < 2 {
+            return denominator == 1;
+        }
+
+        // strips every prime factor shared with the base
+        loop {
+            let gcd: u128 = utils::gcd_u128(denominator, base);
+            if gcd == 1 {
+                return denominator == 1;
+            }
+            denominator /= gcd;
+        }
+    }
+
+    #[inline]
+    fn proper_part(&self) -> Fract128 {
+        Fract128 {
+            numerator: self.numerator % self.denominator,
+            denominator: self.denominator,
+        }
+        .reduce()
+    }
+
+    #[inline]
+    fn integer_scale(&self) -> u128 {
+        self.reduce().denominator
+    }
+
+    #[inline]
+    fn scaled_numerator(&self) -> u128 {
+        self.reduce().numerator
+    }
+
+    #[inline]
+    fn powf(&self, exp: f64) -> f64 {
+        (self.numerator as f64 / self.denominator as f64).powf(exp)
+    }
+
+    #[inline]
+    fn powf_approx(&self, exp: f64, max_denominator: u128) -> Fract128 {
+        Fract128::saturating_from_float(self.powf(exp), max_denominator)
+    }
+
+    #[inline]
+    fn largest_unit_fraction(&self) -> Fract128 {
+        let mut denominator: u128 = self.denominator / self.numerator;
+        if !self.denominator.is_multiple_of(self.numerator) {
+            denominator += 1;
+        }
+
+        Fract128 {
+            numerator: 1,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn egyptian_fraction(&self) -> Option<Vec<Fract128>> {
+        if self.denominator == 0 {
+            return None;
+        }
+
+        let mut units: Vec<Fract128> = Vec::new();
+        let whole: u128 = self.numerator / self.denominator;
+        if whole != 0 {
+            units.push(Fract128::from(whole));
+        }
+
+        let mut remaining: Fract128 = self.proper_part();
+        while remaining.numerator != 0 {
+            let unit: Fract128 = remaining.largest_unit_fraction();
+            units.push(unit);
+            remaining = remaining.sub_checked(&unit).ok()?.reduce();
+        }
+
+        Some(units)
+    }
+
+    #[inline]
+    fn safe_cmp(&self, other: &Fract128) -> Option<Ordering> {
+        if self.denominator == 0 || other.denominator == 0 {
+            return None;
+        }
+
+        Some(self.cmp_exact(other))
+    }
+
+    #[inline]
+    fn with_numerator(&self, numerator: u128) -> Fract128 {
+        Fract128 {
+            numerator,
+            denominator: self.denominator,
+        }
+    }
+
+    #[inline]
+    fn with_denominator(&self, denominator: u128) -> Fract128 {
+        Fract128 {
+            numerator: self.numerator,
+            denominator,
+        }
+    }
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Fract128, FractError> {
+        let (numerator, denominator) = match s.trim().split_once('/') {
+            Some((numerator, denominator)) => (numerator.trim(), denominator.trim()),
+            None => (s.trim(), "1"),
+        };
+
+        if !(2..=36).contains(&radix)
+            || !utils::is_digits_radix(numerator, radix)
+            || !utils::is_digits_radix(denominator, radix)
+        {
+            return Err(FractError::InvalidFormat);
+        }
+
+        let numerator: u128 =
+            u128::from_str_radix(numerator, radix).map_err(|_| FractError::NumeratorOverflow)?;
+        let denominator: u128 = u128::from_str_radix(denominator, radix)
+            .map_err(|_| FractError::DenominatorOverflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(Fract128 {
+            numerator,
+            denominator,
+        })
+    }
+
+    #[inline]
+    fn to_basis_points(&self) -> u32 {
+        let denominator: u128 = self.denominator;
+        let mut basis_points: u128 = (self.numerator / denominator).saturating_mul(10000);
+        let mut remainder: u128 = self.numerator % denominator;
+        for scale in &[1000, 100, 10, 1] {
+            let (digit, next) = utils::next_digit_u128(remainder, denominator, 10);
+            basis_points = basis_points.saturating_add(digit as u128 * scale);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, denominator, 10).0 >= 5 {
+            basis_points = basis_points.saturating_add(1);
+        }
+
+        u32::try_from(basis_points).unwrap_or(u32::MAX)
+    }
+
+    #[inline]
+    fn to_float_exact(&self) -> Result<f64, f64> {
+        let reduced: Fract128 = self.reduce();
+        let value: f64 = reduced.to_float();
+        if reduced.denominator == 0 || !reduced.denominator.is_power_of_two() {
+            return Err(value);
+        }
+        if reduced.numerator == 0 {
+            return Ok(value);
+        }
+
+        let significant: u128 = reduced.numerator >> reduced.numerator.trailing_zeros();
+        if u128::BITS - significant.leading_zeros() <= f64::MANTISSA_DIGITS {
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+
+    #[inline]
+    fn components(&self) -> (u128, u128) {
+        (self.numerator, self.denominator)
+    }
+
+    #[inline]
+    fn div_rem_fract(&self, other: &Fract128) -> Option<(u128, Fract128)> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        let (lhs, rhs, denominator) = self
+            .reduce()
+            .expand_to_common_checked(&other.reduce())
+            .ok()?;
+
+        Some((lhs / rhs, Fract128::new(lhs % rhs, denominator).reduce()))
+    }
+
+    #[inline]
+    fn reduce_mut(&mut self) {
+        *self = self.reduce();
+    }
+
+    #[inline]
+    fn float_ulp_error(&self) -> u64 {
+        if self.denominator == 0 {
+            return 0;
+        }
+
+        utils::ulp_error(
+            self.to_float().to_bits(),
+            f64::MANTISSA_DIGITS - 1,
+            1075,
+            self.numerator,
+            self.denominator,
+        )
+    }
+
+    #[inline]
+    fn modular_inverse(&self) -> Option<u128> {
+        utils::inverse_mod_u128(self.numerator, self.denominator)
+    }
+
+    #[inline]
+    fn checked_add_int(&self, value: u128) -> Option<Fract128> {
+        self.add_checked(&Fract128::from(value)).ok()
+    }
+
+    #[inline]
+    fn display_approx(&self, max_denominator: u128) -> String {
+        let reduced: Fract128 = self.reduce();
+        if reduced.denominator <= max_denominator {
+            return reduced.to_string();
+        }
+
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            reduced.numerator,
+            reduced.denominator,
+            u128::MAX,
+            max_denominator.max(1),
+        );
+
+        format!("≈{}", Fract128::new(numerator, denominator))
+    }
+
+    #[inline]
+    fn from_float_capped(
+        value: f64,
+        max_iterations: u32,
+        max_denominator: u128,
+    ) -> Result<Fract128, FractError> {
+        if value.is_nan() {
+            return Err(FractError::InvalidFormat);
+        }
+        if value < 0.0 {
+            return Err(FractError::NumeratorUnderflow);
+        }
+        if value >= u128::MAX as f64 + 1.0 {
+            return Err(FractError::NumeratorOverflow);
+        }
+
+        let (numerator, denominator) =
+            utils::approximate_f64_capped(value, u128::MAX, max_denominator.max(1), max_iterations)
+                .map_err(|_| FractError::IterationLimit)?;
+
+        Ok(Fract128::new(numerator, denominator))
+    }
+
+    #[inline]
+    fn decimal_digit_at(&self, position: u32) -> u8 {
+        if self.denominator == 0 {
+            return 0;
+        }
+        if position == 0 {
+            return (self.numerator / self.denominator % 10) as u8;
+        }
+
+        let mut remainder: u128 = self.numerator % self.denominator;
+        let mut digit: u32 = 0;
+        for _ in 0..position {
+            let (next_digit, next_remainder) =
+                utils::next_digit_u128(remainder, self.denominator, 10);
+            digit = next_digit;
+            remainder = next_remainder;
+        }
+
+        digit as u8
+    }
+
+    #[inline]
+    fn reduce_checked(&self) -> (Fract128, bool) {
+        let reduced: Fract128 = self.reduce();
+
+        (reduced, reduced != *self)
+    }
+
+    #[inline]
+    fn scale(&self, factor: u128) -> Option<Fract128> {
+        Some(Fract128 {
+            numerator: self.numerator.checked_mul(factor)?,
+            denominator: self.denominator.checked_mul(factor)?,
+        })
+    }
+
+    #[inline]
+    fn to_sig_figs(&self, sig_figs: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::sig_figs_u128(self.numerator, self.denominator, sig_figs)
+    }
+
+    #[inline]
+    fn clamp01(&self) -> Fract128 {
+        if self.numerator > self.denominator {
+            Fract128::new(1, 1)
+        } else {
+            *self
+        }
+    }
+
+    #[inline]
+    fn period_length(&self) -> Option<u64> {
+        let mut modulus: u128 = self.reduce().denominator;
+        if modulus == 0 {
+            return Some(0);
+        }
+        while modulus.is_multiple_of(2) {
+            modulus /= 2;
+        }
+        while modulus.is_multiple_of(5) {
+            modulus /= 5;
+        }
+        if modulus == 1 {
+            return Some(0);
+        }
+
+        u64::try_from(utils::decimal_period_u128(modulus)).ok()
+    }
+
+    #[inline]
+    fn validate(&self) -> Result<(), FractError> {
+        if self.denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn decimal_digits_rounded(&self, count: usize) -> Vec<u8> {
+        if self.denominator == 0 {
+            return Vec::new();
+        }
+
+        let mut digits: Vec<u8> = Vec::with_capacity(count);
+        let mut remainder: u128 = self.numerator % self.denominator;
+        for _ in 0..count {
+            let (digit, next) = utils::next_digit_u128(remainder, self.denominator, 10);
+            digits.push(digit as u8);
+            remainder = next;
+        }
+
+        if utils::next_digit_u128(remainder, self.denominator, 10).0 >= 5 {
+            for digit in digits.iter_mut().rev() {
+                if *digit == 9 {
+                    *digit = 0;
+                } else {
+                    *digit += 1;
+                    break;
+                }
+            }
+        }
+
+        digits
+    }
+
+    #[inline]
+    fn minimal(&self) -> Fract128 {
+        self.reduce()
+    }
+
+    #[inline]
+    fn checked_shl(&self, n: u32) -> Option<Fract128> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.denominator.trailing_zeros().min(n);
+        Some(Fract128 {
+            numerator: self
+                .numerator
+                .checked_mul(u128::checked_pow(2, n - cancelled)?)?,
+            denominator: self.denominator.checked_shr(cancelled)?,
+        })
+    }
+
+    #[inline]
+    fn checked_shr(&self, n: u32) -> Option<Fract128> {
+        if self.numerator == 0 {
+            return Some(*self);
+        }
+
+        let cancelled: u32 = self.numerator.trailing_zeros().min(n);
+        Some(Fract128 {
+            numerator: self.numerator >> cancelled,
+            denominator: self
+                .denominator
+                .checked_mul(u128::checked_pow(2, n - cancelled)?)?,
+        })
+    }
+
+    #[inline]
+    fn reduce_keep_denominator(&self) -> Fract128 {
+        if self.numerator == 0 {
+            return *self;
+        }
+
+        self.reduce()
+    }
+
+    #[inline]
+    fn to_mixed_string(&self) -> String {
+        if self.denominator == 0 {
+            return self.to_string();
+        }
+
+        let whole: u128 = self.numerator / self.denominator;
+        let proper: Fract128 = self.proper_part();
+        if proper.numerator == 0 {
+            whole.to_string()
+        } else if whole == 0 {
+            proper.to_string()
+        } else {
+            format!("{} {}", whole, proper)
+        }
+    }
+
+    #[inline]
+    fn ratio_to(&self, other: &Fract128) -> Option<Fract128> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        Fract128::product_reduced([*self, other.invert()])
+    }
+
+    #[inline]
+    fn is_between(&self, low: &Fract128, high: &Fract128) -> bool {
+        let (low, high) = if low.cmp_exact(high) == Ordering::Greater {
+            (high, low)
+        } else {
+            (low, high)
+        };
+
+        self.cmp_exact(low) != Ordering::Less && self.cmp_exact(high) != Ordering::Greater
+    }
+
+    #[inline]
+    fn convergents(&self) -> Vec<Fract128> {
+        let (mut p0, mut q0, mut p1, mut q1): (u128, u128, u128, u128) = (0, 1, 1, 0);
+        self.continued_fraction()
+            .into_iter()
+            .map(|coefficient| {
+                let (p2, q2) = (coefficient * p1 + p0, coefficient * q1 + q0);
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+                Fract128::new(p2, q2)
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn cmp_with_float(&self, value: f64) -> Ordering {
+        utils::cmp_ratio_f64(self.numerator, self.denominator, value)
+    }
+
+    #[inline]
+    fn integer_and_fraction(&self) -> (Fract128, Fract128) {
+        if self.denominator == 0 {
+            return (*self, Fract128::new(0, 1));
+        }
+
+        (
+            Fract128::from(self.numerator / self.denominator),
+            self.proper_part(),
+        )
+    }
+
+    #[inline]
+    fn snap_to(&self, allowed: &[Fract128]) -> Option<Fract128> {
+        let distance = |other: &Fract128| {
+            utils::distance_u128(
+                self.numerator,
+                self.denominator,
+                other.numerator,
+                other.denominator,
+            )
+        };
+
+        let mut best: Option<(Fract128, Option<(u128, u128)>)> = None;
+        for candidate in allowed {
+            let candidate_distance: Option<(u128, u128)> = distance(candidate);
+            let closer: bool = match best {
+                None => true,
+                Some((current, current_distance)) => match (candidate_distance, current_distance) {
+                    (Some(lhs), Some(rhs)) => {
+                        utils::cmp_ratio_u128(lhs.0, lhs.1, rhs.0, rhs.1) == Ordering::Less
+                    }
+                    _ => self.abs_diff_float(candidate) < self.abs_diff_float(&current),
+                },
+            };
+            if closer {
+                best = Some((*candidate, candidate_distance));
+            }
+        }
+
+        best.map(|(fraction, _)| fraction)
+    }
+
+    #[inline]
+    fn to_string_radix(&self, radix: u32, max_digits: usize) -> String {
+        if self.denominator == 0 {
+            return String::new();
+        }
+
+        utils::radix_string_u128(self.numerator, self.denominator, radix, max_digits)
+    }
+
+    #[inline]
+    fn continued_fraction_string(&self) -> String {
+        let coefficients: Vec<String> = self
+            .continued_fraction()
+            .iter()
+            .map(|coefficient| coefficient.to_string())
+            .collect();
+
+        match coefficients.split_first() {
+            None => String::from("[]"),
+            Some((first, [])) => format!("[{}]", first),
+            Some((first, rest)) => format!("[{}; {}]", first, rest.join(", ")),
+        }
+    }
+
+    #[inline]
+    fn reciprocal_reduced(&self) -> Option<Fract128> {
+        if self.numerator == 0 {
+            return None;
+        }
+
+        Some(self.invert().reduce())
+    }
+
+    #[inline]
+    fn cross_difference(&self, other: &Fract128) -> i128 {
+        utils::cross_difference_u128(
+            self.numerator,
+            self.denominator,
+            other.numerator,
+            other.denominator,
+        )
+    }
+
+    #[inline]
+    fn simplest_between(&self, other: &Fract128) -> Option<Fract128> {
+        let (low, high) = match self.cmp_exact(other) {
+            Ordering::Equal => return Some(self.reduce()),
+            Ordering::Less => (self, other),
+            Ordering::Greater => (other, self),
+        };
+
+        let (numerator, denominator) = utils::simplest_between_u128(
+            low.numerator,
+            low.denominator,
+            high.numerator,
+            high.denominator,
+        )?;
+
+        Some(Fract128::new(numerator, denominator))
+    }
+}
+
+impl From<u128> for Fract128 {
+    #[inline]
+    fn from(input: u128) -> Self {
+        Fract128 {
+            numerator: input,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract128 = self;
+        let mut nrhs: Fract128 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u128 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract128 {
+            numerator: nlhs.numerator + nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Sub for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut nlhs: Fract128 = self;
+        let mut nrhs: Fract128 = rhs;
+
+        if self.denominator != rhs.denominator {
+            let old_denom: u128 = nlhs.denominator;
+            nlhs = nlhs.expand(nrhs.denominator);
+            nrhs = nrhs.expand(old_denom);
+        }
+
+        Fract128 {
+            numerator: nlhs.numerator - nrhs.numerator,
+            denominator: nlhs.denominator,
+        }
+    }
+}
+
+impl Mul for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cancels common factors crosswise before multiplying to keep the products small
+        let first: u128 = utils::gcd_u128(self.numerator, rhs.denominator).max(1);
+        let second: u128 = utils::gcd_u128(rhs.numerator, self.denominator).max(1);
+
+        Fract128 {
+            numerator: (self.numerator / first) * (rhs.numerator / second),
+            denominator: (self.denominator / second) * (rhs.denominator / first),
+        }
+    }
+}
+
+impl Div for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.invert()
+    }
+}
+
+impl PartialOrd for Fract128 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Fract128 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_exact(other)
+            .then(self.numerator.cmp(&other.numerator))
+            .then(self.denominator.cmp(&other.denominator))
+    }
+}
+
+impl Fract128 {
+    #[inline]
+    fn expand_to_common_checked(&self, other: &Fract128) -> Result<(u128, u128, u128), FractError> {
+        if self.denominator == other.denominator {
+            return Ok((self.numerator, other.numerator, self.denominator));
+        }
+
+        let denominator: u128 = self
+            .denominator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::DenominatorOverflow)?;
+        let lhs: u128 = self
+            .numerator
+            .checked_mul(other.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+        let rhs: u128 = other
+            .numerator
+            .checked_mul(self.denominator)
+            .ok_or(FractError::NumeratorOverflow)?;
+
+        Ok((lhs, rhs, denominator))
+    }
+
+    #[inline]
+    pub fn to_fract8_approx(&self) -> Fract8 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator,
+            self.denominator,
+            u8::MAX as u128,
+            u8::MAX as u128,
+        );
+
+        Fract8 {
+            numerator: numerator as u8,
+            denominator: denominator as u8,
+        }
+    }
+
+    #[inline]
+    pub fn to_fract16_approx(&self) -> Fract16 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator,
+            self.denominator,
+            u16::MAX as u128,
+            u16::MAX as u128,
+        );
+
+        Fract16 {
+            numerator: numerator as u16,
+            denominator: denominator as u16,
+        }
+    }
+
+    #[inline]
+    pub fn to_fract32_approx(&self) -> Fract32 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator,
+            self.denominator,
+            u32::MAX as u128,
+            u32::MAX as u128,
+        );
+
+        Fract32 {
+            numerator: numerator as u32,
+            denominator: denominator as u32,
+        }
+    }
+
+    #[inline]
+    pub fn to_fract64_approx(&self) -> Fract64 {
+        let (numerator, denominator) = utils::approximate_ratio_u128(
+            self.numerator,
+            self.denominator,
+            u64::MAX as u128,
+            u64::MAX as u128,
+        );
+
+        Fract64 {
+            numerator: numerator as u64,
+            denominator: denominator as u64,
+        }
+    }
+
+    pub fn common_denominator(fractions: &[Fract128]) -> Result<Vec<Fract128>, FractError> {
+        let mut denominator: u128 = 1;
+        for fraction in fractions {
+            if fraction.denominator == 0 {
+                return Err(FractError::ZeroDenominator);
+            }
+            denominator = utils::checked_lcm_u128(denominator, fraction.denominator)
+                .ok_or(FractError::DenominatorOverflow)?;
+        }
+
+        fractions
+            .iter()
+            .map(|fraction| {
+                fraction
+                    .numerator
+                    .checked_mul(denominator / fraction.denominator)
+                    .map(|numerator| Fract128::new(numerator, denominator))
+                    .ok_or(FractError::NumeratorOverflow)
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub fn reduce_into_fract8(&self) -> Result<Fract8, FractError> {
+        Fract8::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn reduce_into_fract16(&self) -> Result<Fract16, FractError> {
+        Fract16::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn reduce_into_fract32(&self) -> Result<Fract32, FractError> {
+        Fract32::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn reduce_into_fract64(&self) -> Result<Fract64, FractError> {
+        Fract64::try_from(self.reduce())
+    }
+
+    #[inline]
+    pub fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes: [u8; 32] = [0; 32];
+        let (numerator, denominator) = bytes.split_at_mut(16);
+        numerator.copy_from_slice(&self.numerator.to_le_bytes());
+        denominator.copy_from_slice(&self.denominator.to_le_bytes());
+        bytes
+    }
+
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Fract128 {
+        let mut numerator: [u8; 16] = [0; 16];
+        let mut denominator: [u8; 16] = [0; 16];
+        numerator.copy_from_slice(&bytes[..16]);
+        denominator.copy_from_slice(&bytes[16..]);
+
+        Fract128 {
+            numerator: u128::from_le_bytes(numerator),
+            denominator: u128::from_le_bytes(denominator),
+        }
+    }
+
+    /// Sums over common denominators, reducing after every step; `None` once an
+    /// intermediate value overflows or a denominator is zero.
+    pub fn sum_reduced<I: IntoIterator<Item = Fract128>>(fractions: I) -> Option<Fract128> {
+        fractions
+            .into_iter()
+            .try_fold(Fract128::new(0, 1), |sum, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract128 = fraction.reduce();
+                let denominator: u128 =
+                    utils::checked_lcm_u128(sum.denominator, fraction.denominator)?;
+                let numerator: u128 = sum
+                    .numerator
+                    .checked_mul(denominator / sum.denominator)?
+                    .checked_add(
+                        fraction
+                            .numerator
+                            .checked_mul(denominator / fraction.denominator)?,
+                    )?;
+
+                Some(Fract128::new(numerator, denominator).reduce())
+            })
+    }
+
+    /// Multiplies with cross-cancellation so the running product stays reduced; `None` on
+    /// overflow or a zero denominator.
+    pub fn product_reduced<I: IntoIterator<Item = Fract128>>(fractions: I) -> Option<Fract128> {
+        fractions
+            .into_iter()
+            .try_fold(Fract128::new(1, 1), |product, fraction| {
+                if fraction.denominator == 0 {
+                    return None;
+                }
+
+                let fraction: Fract128 = fraction.reduce();
+                let first: u128 = utils::gcd_u128(product.numerator, fraction.denominator).max(1);
+                let second: u128 = utils::gcd_u128(fraction.numerator, product.denominator).max(1);
+
+                Some(Fract128::new(
+                    (product.numerator / first).checked_mul(fraction.numerator / second)?,
+                    (product.denominator / second).checked_mul(fraction.denominator / first)?,
+                ))
+            })
+    }
+
+    #[inline]
+    pub fn fits_in_fract8(&self) -> bool {
+        self.reduce_into_fract8().is_ok()
+    }
+
+    #[inline]
+    pub fn fits_in_fract16(&self) -> bool {
+        self.reduce_into_fract16().is_ok()
+    }
+
+    #[inline]
+    pub fn fits_in_fract32(&self) -> bool {
+        self.reduce_into_fract32().is_ok()
+    }
+
+    #[inline]
+    pub fn fits_in_fract64(&self) -> bool {
+        self.reduce_into_fract64().is_ok()
+    }
+}
+
+impl Shl<u32> for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn shl(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the denominator before growing the numerator
+        let cancelled: u32 = self.denominator.trailing_zeros().min(rhs);
+        Fract128 {
+            numerator: self.numerator * (1 << (rhs - cancelled)),
+            denominator: self.denominator >> cancelled,
+        }
+    }
+}
+
+impl Shr<u32> for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn shr(self, rhs: u32) -> Self::Output {
+        if self.numerator == 0 {
+            return self;
+        }
+
+        // cancels factors of two in the numerator before growing the denominator
+        let cancelled: u32 = self.numerator.trailing_zeros().min(rhs);
+        Fract128 {
+            numerator: self.numerator >> cancelled,
+            denominator: self.denominator * (1 << (rhs - cancelled)),
+        }
+    }
+}
+
+impl FromStr for Fract128 {
+    type Err = FractError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Fract128::from_str_radix(s, 10)
+    }
+}
+
+impl From<Fract128> for f64 {
+    #[inline]
+    fn from(input: Fract128) -> Self {
+        input.to_float()
+    }
+}
+
+impl fmt::Display for Fract128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)?;
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)?;
+        }
+
+        if f.alternate() {
+            write!(f, " ({})", self.to_float())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Fract128 {
+    #[inline]
+    fn default() -> Self {
+        Fract128 {
+            numerator: 0,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add<u128> for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn add(self, rhs: u128) -> Self::Output {
+        self + Fract128::from(rhs)
+    }
+}
+
+impl Sub<u128> for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn sub(self, rhs: u128) -> Self::Output {
+        self - Fract128::from(rhs)
+    }
+}
+
+impl Mul<u128> for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn mul(self, rhs: u128) -> Self::Output {
+        self * Fract128::from(rhs)
+    }
+}
+
+impl Div<u128> for Fract128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn div(self, rhs: u128) -> Self::Output {
+        self / Fract128::from(rhs)
+    }
+}
+
+impl Add<Fract128> for u128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn add(self, rhs: Fract128) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Mul<Fract128> for u128 {
+    type Output = Fract128;
+
+    #[inline]
+    fn mul(self, rhs: Fract128) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl TryFrom<Fract128> for Fract8 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract128) -> Result<Self, Self::Error> {
+        Ok(Fract8 {
+            numerator: u8::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u8::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl TryFrom<Fract128> for Fract16 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract128) -> Result<Self, Self::Error> {
+        Ok(Fract16 {
+            numerator: u16::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u16::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl TryFrom<Fract128> for Fract32 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract128) -> Result<Self, Self::Error> {
+        Ok(Fract32 {
+            numerator: u32::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u32::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl TryFrom<Fract128> for Fract64 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from(input: Fract128) -> Result<Self, Self::Error> {
+        Ok(Fract64 {
+            numerator: u64::try_from(input.numerator).map_err(|_| FractError::NumeratorOverflow)?,
+            denominator: u64::try_from(input.denominator)
+                .map_err(|_| FractError::DenominatorOverflow)?,
+        })
+    }
+}
+
+impl From<bool> for Fract128 {
+    #[inline]
+    fn from(input: bool) -> Self {
+        Fract128 {
+            numerator: input as u128,
+            denominator: 1,
+        }
+    }
+}
+
+impl fmt::Debug for Fract128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(
+                f,
+                "Fract128 {{ numerator: {}, denominator: {}, reduced: {}, value: {} }}",
+                self.numerator,
+                self.denominator,
+                self.reduce(),
+                self.to_float()
+            )
+        } else {
+            f.debug_struct("Fract128")
+                .field("numerator", &self.numerator)
+                .field("denominator", &self.denominator)
+                .finish()
+        }
+    }
+}
+
+impl Hash for Fract128 {
+    // hashes the reduced value as u128 so equal values match across widths
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let reduced: Fract128 = self.reduce();
+        reduced.numerator.hash(state);
+        reduced.denominator.hash(state);
+    }
+}
+
+impl TryFrom<(i64, i64)> for Fract128 {
+    type Error = FractError;
+
+    #[inline]
+    fn try_from((numerator, denominator): (i64, i64)) -> Result<Self, Self::Error> {
+        let numerator: u64 =
+            u64::try_from(numerator).map_err(|_| FractError::NumeratorUnderflow)?;
+        let denominator: u64 =
+            u64::try_from(denominator).map_err(|_| FractError::DenominatorUnderflow)?;
+        if denominator == 0 {
+            return Err(FractError::ZeroDenominator);
+        }
+
+        let divisor: u64 = utils::gcd_u64(numerator, denominator);
+        Ok(Fract128 {
+            numerator: u128::from(numerator / divisor),
+            denominator: u128::from(denominator / divisor),
+        })
+    }
+}
+#[cfg(test)]
+mod tests_fract128 {
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::convert::TryFrom;
+    use std::hash::{Hash, Hasher};
+
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::{Fract, Fract128, Fract16, Fract32, Fract64, Fract8, FractError, RoundingMode};
+
+    #[test]
+    fn should_create() {
+        let expected: Fract128 = Fract128 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let actual: Fract128 = Fract128::new(8, 10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_invert() {
+        let expected: Fract128 = Fract128 {
+            numerator: 10,
+            denominator: 8,
+        };
+
+        let actual: Fract128 = Fract128::new(8, 10).invert();
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_expand() {
+        let expected: Fract128 = Fract128 {
+            numerator: 80,
+            denominator: 100,
+        };
+
+        let actual: Fract128 = Fract128::new(8, 10).expand(10);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_convert() {
+        let expected: f64 = 0.8;
+        let actual: f64 = Fract128::new(8, 10).to_float();
+
+        assert_approx_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_add() {
+        let expected: Fract128 = Fract128 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        let first: Fract128 = Fract128::new(1, 2);
+        let second: Fract128 = Fract128::new(9, 10);
+        let result: Fract128 = first + second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_sub() {
+        let expected: Fract128 = Fract128 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        let first: Fract128 = Fract128::new(4, 2);
+        let second: Fract128 = Fract128::new(9, 10);
+        let result: Fract128 = first - second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_mul() {
+        let expected: Fract128 = Fract128 {
+            numerator: 4,
+            denominator: 5,
+        };
+
+        let first: Fract128 = Fract128::new(2, 5);
+        let second: Fract128 = Fract128::new(4, 2);
+        let result: Fract128 = first * second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_div() {
+        let expected: Fract128 = Fract128 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let first: Fract128 = Fract128::new(1, 2);
+        let second: Fract128 = Fract128::new(9, 10);
+        let result: Fract128 = first / second;
+
+        assert_eq!(expected, result)
+    }
+
+    #[test]
+    fn should_reduce() {
+        let expected: Fract128 = Fract128 {
+            numerator: 5,
+            denominator: 9,
+        };
+
+        let value: Fract128 = Fract128 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(expected, value.reduce())
+    }
+
+    #[test]
+    fn should_invert_checked() {
+        let expected: Fract128 = Fract128 {
+            numerator: 3,
+            denominator: 2,
+        };
+
+        let actual: Result<Fract128, FractError> = Fract128::new(2, 3).invert_checked();
+
+        assert_eq!(Ok(expected), actual)
+    }
+
+    #[test]
+    fn should_not_invert_zero() {
+        let actual: Result<Fract128, FractError> = Fract128::new(0, 5).invert_checked();
+
+        assert_eq!(Err(FractError::ZeroDenominator), actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over() {
+        let expected: Fract128 = Fract128 {
+            numerator: 8,
+            denominator: 12,
+        };
+
+        let actual: Fract128 = Fract128::new(5, 8).nearest_over(12);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_improper() {
+        let expected: Fract128 = Fract128 {
+            numerator: 7,
+            denominator: 3,
+        };
+
+        let actual: Fract128 = Fract128::new(7, 3).nearest_over(3);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_round_tie_up_in_nearest_over() {
+        let expected: Fract128 = Fract128 {
+            numerator: 1,
+            denominator: 2,
+        };
+
+        let actual: Fract128 = Fract128::new(1, 4).nearest_over(2);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_find_nearest_over_near_max() {
+        assert_eq!(
+            Fract128::new(u128::MAX - 2, u128::MAX - 1),
+            Fract128::new(u128::MAX - 1, u128::MAX).nearest_over(u128::MAX - 1)
+        )
+    }
+
+    #[test]
+    fn should_mul_checked() {
+        let expected: Fract128 = Fract128 {
+            numerator: 8,
+            denominator: 10,
+        };
+
+        let first: Fract128 = Fract128::new(2, 5);
+        let second: Fract128 = Fract128::new(4, 2);
+
+        assert_eq!(Ok(expected), first.mul_checked(&second))
+    }
+
+    #[test]
+    fn should_report_numerator_overflow_in_mul_checked() {
+        let first: Fract128 = Fract128::new(u128::MAX, 1);
+        let second: Fract128 = Fract128::new(2, 3);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_report_denominator_overflow_in_mul_checked() {
+        let first: Fract128 = Fract128::new(1, u128::MAX);
+        let second: Fract128 = Fract128::new(1, 2);
+
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            first.mul_checked(&second)
+        )
+    }
+
+    #[test]
+    fn should_order_by_value() {
+        let smaller: Fract128 = Fract128::new(1, 3);
+        let larger: Fract128 = Fract128::new(2, 5);
+
+        assert!(smaller < larger);
+        assert!(larger > smaller)
+    }
+
+    #[test]
+    fn should_order_equal_values_by_representation() {
+        let reduced: Fract128 = Fract128::new(1, 2);
+        let expanded: Fract128 = Fract128::new(2, 4);
+
+        assert!(reduced < expanded);
+        assert!(reduced < Fract128::new(3, 5));
+        assert!(expanded < Fract128::new(3, 5))
+    }
+
+    #[test]
+    fn should_order_large_values() {
+        let smaller: Fract128 = Fract128::new(u128::MAX - 1, u128::MAX);
+        let larger: Fract128 = Fract128::new(u128::MAX, u128::MAX - 1);
+
+        assert!(smaller < larger);
+        assert!(smaller < Fract128::new(1, 1));
+        assert!(Fract128::new(1, 1) < larger)
+    }
+
+    #[test]
+    fn should_round_half_up() {
+        assert_eq!(3, Fract128::new(5, 2).round_with(RoundingMode::HalfUp));
+        assert_eq!(4, Fract128::new(7, 2).round_with(RoundingMode::HalfUp))
+    }
+
+    #[test]
+    fn should_round_half_even() {
+        assert_eq!(2, Fract128::new(5, 2).round_with(RoundingMode::HalfEven));
+        assert_eq!(4, Fract128::new(7, 2).round_with(RoundingMode::HalfEven))
+    }
+
+    #[test]
+    fn should_round_half_down() {
+        assert_eq!(2, Fract128::new(5, 2).round_with(RoundingMode::HalfDown));
+        assert_eq!(3, Fract128::new(8, 3).round_with(RoundingMode::HalfDown))
+    }
+
+    #[test]
+    fn should_round_floor_and_ceil() {
+        assert_eq!(2, Fract128::new(8, 3).round_with(RoundingMode::Floor));
+        assert_eq!(3, Fract128::new(7, 3).round_with(RoundingMode::Ceil));
+        assert_eq!(2, Fract128::new(4, 2).round_with(RoundingMode::Ceil))
+    }
+
+    #[test]
+    fn should_calculate_percentage_of() {
+        let expected: Fract128 = Fract128 {
+            numerator: 50,
+            denominator: 1,
+        };
+
+        let actual: Fract128 = Fract128::new(1, 4).percentage_of(&Fract128::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_percentage_of_above_hundred() {
+        let expected: Fract128 = Fract128 {
+            numerator: 150,
+            denominator: 1,
+        };
+
+        let actual: Fract128 = Fract128::new(3, 4).percentage_of(&Fract128::new(1, 2));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_calculate_fractional_percentage_of() {
+        let expected: Fract128 = Fract128 {
+            numerator: 100,
+            denominator: 3,
+        };
+
+        let actual: Fract128 = Fract128::new(1, 3).percentage_of(&Fract128::new(1, 1));
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_create_from_u128() {
+        let expected: Fract128 = Fract128 {
+            numerator: u128::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(Ok(expected), Fract128::try_from_u128(u128::MAX))
+    }
+
+    #[test]
+    fn should_list_equivalent_forms() {
+        let expected: Vec<Fract128> = vec![
+            Fract128::new(1, 2),
+            Fract128::new(2, 4),
+            Fract128::new(3, 6),
+            Fract128::new(4, 8),
+        ];
+
+        assert_eq!(expected, Fract128::new(2, 4).equivalent_forms(8))
+    }
+
+    #[test]
+    fn should_find_no_equivalent_forms_for_zero_denominator() {
+        assert_eq!(
+            Vec::<Fract128>::new(),
+            Fract128::new(3, 0).equivalent_forms(u128::MAX)
+        );
+        assert_eq!(
+            Vec::<Fract128>::new(),
+            Fract128::new(0, 0).equivalent_forms(u128::MAX)
+        )
+    }
+
+    #[test]
+    fn should_not_list_equivalent_forms_below_reduced_denominator() {
+        assert_eq!(
+            Vec::<Fract128>::new(),
+            Fract128::new(2, 6).equivalent_forms(2)
+        )
+    }
+
+    #[test]
+    fn should_compare_exact() {
+        assert_eq!(
+            Ordering::Equal,
+            Fract128::new(1, 2).cmp_exact(&Fract128::new(2, 4))
+        );
+        assert_eq!(
+            Ordering::Less,
+            Fract128::new(1, 3).cmp_exact(&Fract128::new(1, 2))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Fract128::new(3, 4).cmp_exact(&Fract128::new(2, 3))
+        )
+    }
+
+    #[test]
+    fn should_compare_exact_below_float_precision() {
+        let first: Fract128 = Fract128::new(u128::MAX - 1, u128::MAX);
+        let second: Fract128 = Fract128::new(u128::MAX - 2, u128::MAX - 1);
+
+        assert_eq!(first.to_float(), second.to_float());
+        assert_eq!(Ordering::Greater, first.cmp_exact(&second))
+    }
+
+    #[test]
+    fn should_calculate_abs_diff_float() {
+        let expected: f64 = 0.25;
+
+        assert_approx_eq!(
+            expected,
+            Fract128::new(1, 2).abs_diff_float(&Fract128::new(3, 4))
+        );
+        assert_approx_eq!(
+            expected,
+            Fract128::new(3, 4).abs_diff_float(&Fract128::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_saturate_huge_float() {
+        let expected: Fract128 = Fract128 {
+            numerator: u128::MAX,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract128::saturating_from_float(f64::MAX, 10));
+        assert_eq!(expected, Fract128::saturating_from_float(f64::INFINITY, 10))
+    }
+
+    #[test]
+    fn should_saturate_negative_float() {
+        let expected: Fract128 = Fract128 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract128::saturating_from_float(-2.5, 10));
+        assert_eq!(expected, Fract128::saturating_from_float(f64::NAN, 10))
+    }
+
+    #[test]
+    fn should_approximate_float_in_range() {
+        assert_eq!(
+            Fract128::new(1, 10),
+            Fract128::saturating_from_float(0.1, 100)
+        );
+        assert_eq!(
+            Fract128::new(22, 7),
+            Fract128::saturating_from_float(std::f64::consts::PI, 10)
+        );
+        assert_eq!(
+            Fract128::new(5, 2),
+            Fract128::saturating_from_float(2.5, 10)
+        )
+    }
+
+    #[test]
+    fn should_expand_continued_fraction() {
+        assert_eq!(vec![2, 3, 1, 4], Fract128::new(43, 19).continued_fraction());
+        assert_eq!(vec![0, 2], Fract128::new(1, 2).continued_fraction())
+    }
+
+    #[test]
+    fn should_create_from_continued_fraction() {
+        let expected: Fract128 = Fract128 {
+            numerator: 43,
+            denominator: 19,
+        };
+
+        assert_eq!(expected, Fract128::from_continued_fraction(&[2, 3, 1, 4]))
+    }
+
+    #[test]
+    fn should_create_zero_from_empty_continued_fraction() {
+        let expected: Fract128 = Fract128 {
+            numerator: 0,
+            denominator: 1,
+        };
+
+        assert_eq!(expected, Fract128::from_continued_fraction(&[]))
+    }
+
+    #[test]
+    fn should_find_empty_stern_brocot_path() {
+        assert_eq!(Vec::<bool>::new(), Fract128::new(1, 1).stern_brocot_path());
+        assert_eq!(Vec::<bool>::new(), Fract128::new(3, 3).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_find_stern_brocot_path() {
+        assert_eq!(
+            vec![false, true, false],
+            Fract128::new(3, 5).stern_brocot_path()
+        );
+        assert_eq!(vec![true, true], Fract128::new(3, 1).stern_brocot_path())
+    }
+
+    #[test]
+    fn should_add_checked() {
+        let expected: Fract128 = Fract128 {
+            numerator: 28,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract128::new(1, 2).add_checked(&Fract128::new(9, 10))
+        )
+    }
+
+    #[test]
+    fn should_sub_checked() {
+        let expected: Fract128 = Fract128 {
+            numerator: 22,
+            denominator: 20,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract128::new(4, 2).sub_checked(&Fract128::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract128::new(1, 2).sub_checked(&Fract128::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_div_checked() {
+        let expected: Fract128 = Fract128 {
+            numerator: 10,
+            denominator: 18,
+        };
+
+        assert_eq!(
+            Ok(expected),
+            Fract128::new(1, 2).div_checked(&Fract128::new(9, 10))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract128::new(1, 2).div_checked(&Fract128::new(0, 10))
+        )
+    }
+
+    #[test]
+    fn should_checked_assign() {
+        let mut value: Fract128 = Fract128::new(1, 2);
+
+        assert_eq!(Ok(()), value.checked_add_assign(Fract128::new(1, 2)));
+        assert_eq!(Fract128::new(2, 2), value);
+        assert_eq!(Ok(()), value.checked_sub_assign(Fract128::new(1, 2)));
+        assert_eq!(Fract128::new(1, 2), value);
+        assert_eq!(Ok(()), value.checked_mul_assign(Fract128::new(3, 1)));
+        assert_eq!(Fract128::new(3, 2), value);
+        assert_eq!(Ok(()), value.checked_div_assign(Fract128::new(3, 1)));
+        assert_eq!(Fract128::new(3, 6), value)
+    }
+
+    #[test]
+    fn should_leave_value_unchanged_on_failed_assign() {
+        let mut value: Fract128 = Fract128::new(u128::MAX, 2);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_add_assign(Fract128::new(1, 2))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_mul_assign(Fract128::new(2, 1))
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            value.checked_div_assign(Fract128::new(0, 1))
+        );
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            value.checked_sub_assign(Fract128::new(u128::MAX, 1))
+        );
+        assert_eq!(Fract128::new(u128::MAX, 2), value)
+    }
+
+    #[test]
+    fn should_format_reduced() {
+        assert_eq!("1/2", Fract128::new(4, 8).to_string_reduced());
+        assert_eq!("2", Fract128::new(6, 3).to_string_reduced())
+    }
+
+    #[test]
+    fn should_factor_denominator() {
+        assert_eq!(vec![(2, 3)], Fract128::new(3, 8).denominator_factors());
+        assert_eq!(
+            vec![(2, 1), (3, 1)],
+            Fract128::new(1, 6).denominator_factors()
+        );
+        assert_eq!(vec![(3, 2)], Fract128::new(10, 18).denominator_factors())
+    }
+
+    #[test]
+    fn should_factor_whole_denominator() {
+        assert_eq!(
+            Vec::<(u128, u32)>::new(),
+            Fract128::new(4, 2).denominator_factors()
+        )
+    }
+
+    #[test]
+    fn should_terminate_in_base() {
+        assert!(Fract128::new(3, 8).terminates_in_base(10));
+        assert!(Fract128::new(7, 20).terminates_in_base(10));
+        assert!(Fract128::new(1, 3).terminates_in_base(6));
+        assert!(Fract128::new(5, 12).terminates_in_base(6));
+        assert!(Fract128::new(6, 3).terminates_in_base(7))
+    }
+
+    #[test]
+    fn should_not_terminate_in_base() {
+        assert!(!Fract128::new(1, 3).terminates_in_base(10));
+        assert!(!Fract128::new(1, 10).terminates_in_base(2));
+        assert!(!Fract128::new(1, 5).terminates_in_base(6))
+    }
+
+    #[test]
+    fn should_not_terminate_with_zero_denominator() {
+        assert!(!Fract128::new(3, 0).terminates_in_base(10));
+        assert!(!Fract128::new(0, 0).terminates_in_base(2))
+    }
+
+    #[test]
+    fn should_shift_left() {
+        assert_eq!(Fract128::new(1, 1), Fract128::new(1, 2) << 1);
+        assert_eq!(Fract128::new(12, 1), Fract128::new(3, 4) << 4);
+        assert_eq!(Fract128::new(3, 5), Fract128::new(3, 5) << 0)
+    }
+
+    #[test]
+    fn should_shift_zero_left() {
+        assert_eq!(Fract128::new(0, 1), Fract128::new(0, 1) << u128::BITS);
+        assert_eq!(Fract128::new(0, 3), Fract128::new(0, 3) << 2)
+    }
+
+    #[test]
+    fn should_shift_right() {
+        assert_eq!(Fract128::new(1, 4), Fract128::new(1, 2) >> 1);
+        assert_eq!(Fract128::new(3, 2), Fract128::new(12, 1) >> 3);
+        assert_eq!(Fract128::new(0, 3), Fract128::new(0, 3) >> 2)
+    }
+
+    #[test]
+    fn should_parse() {
+        assert_eq!(Ok(Fract128::new(3, 4)), "3/4".parse::<Fract128>());
+        assert_eq!(Ok(Fract128::new(10, 18)), " 10 / 18 ".parse::<Fract128>());
+        assert_eq!(Ok(Fract128::new(5, 1)), "5".parse::<Fract128>())
+    }
+
+    #[test]
+    fn should_not_parse_malformed() {
+        assert_eq!(Err(FractError::InvalidFormat), "a/4".parse::<Fract128>());
+        assert_eq!(Err(FractError::InvalidFormat), "3/".parse::<Fract128>());
+        assert_eq!(Err(FractError::InvalidFormat), "-3/4".parse::<Fract128>());
+        assert_eq!(Err(FractError::InvalidFormat), "1/2/3".parse::<Fract128>());
+        assert_eq!(Err(FractError::ZeroDenominator), "3/0".parse::<Fract128>())
+    }
+
+    #[test]
+    fn should_find_proper_part() {
+        assert_eq!(Fract128::new(1, 2), Fract128::new(7, 2).proper_part());
+        assert_eq!(Fract128::new(0, 1), Fract128::new(4, 2).proper_part());
+        assert_eq!(Fract128::new(5, 9), Fract128::new(10, 18).proper_part())
+    }
+
+    #[test]
+    fn should_not_reduce_zero_over_zero() {
+        let expected: Fract128 = Fract128 {
+            numerator: 0,
+            denominator: 0,
+        };
+
+        assert_eq!(expected, Fract128::new(0, 0).reduce())
+    }
+
+    #[test]
+    fn should_find_integer_scale() {
+        assert_eq!(9, Fract128::new(10, 18).integer_scale());
+        assert_eq!(1, Fract128::new(6, 3).integer_scale())
+    }
+
+    #[test]
+    fn should_find_scaled_numerator() {
+        assert_eq!(5, Fract128::new(10, 18).scaled_numerator());
+        assert_eq!(2, Fract128::new(6, 3).scaled_numerator())
+    }
+
+    #[test]
+    fn should_convert_into_float() {
+        assert_approx_eq!(0.5, f64::from(Fract128::new(1, 2)));
+
+        let actual: f64 = Fract128::new(3, 4).into();
+        assert_approx_eq!(0.75, actual)
+    }
+
+    #[test]
+    fn should_raise_to_float_power() {
+        assert_approx_eq!(1.5, Fract128::new(9, 4).powf(0.5));
+        assert_approx_eq!(0.125, Fract128::new(1, 2).powf(3.0))
+    }
+
+    #[test]
+    fn should_approximate_float_power() {
+        assert_eq!(
+            Fract128::new(3, 2),
+            Fract128::new(9, 4).powf_approx(0.5, 100)
+        );
+        assert_eq!(
+            Fract128::new(7, 5),
+            Fract128::new(2, 1).powf_approx(0.5, 10)
+        )
+    }
+
+    #[test]
+    fn should_approximate_to_fract8() {
+        assert_eq!(
+            Fract8::new(u8::MAX, 2),
+            Fract128::new(u8::MAX as u128 * 2, 4).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(1, 1),
+            Fract128::new(u128::MAX, u128::MAX - 1).to_fract8_approx()
+        );
+        assert_eq!(
+            Fract8::new(u8::MAX, 1),
+            Fract128::new(u128::MAX, 1).to_fract8_approx()
+        )
+    }
+
+    #[test]
+    fn should_approximate_to_fract16() {
+        assert_eq!(
+            Fract16::new(u16::MAX, 2),
+            Fract128::new(u16::MAX as u128 * 2, 4).to_fract16_approx()
+        );
+        assert_eq!(
+            Fract16::new(1, 1),
+            Fract128::new(u128::MAX, u128::MAX - 1).to_fract16_approx()
+        );
+        assert_eq!(
+            Fract16::new(u16::MAX, 1),
+            Fract128::new(u128::MAX, 1).to_fract16_approx()
+        )
+    }
+
+    #[test]
+    fn should_approximate_to_fract32() {
+        assert_eq!(
+            Fract32::new(u32::MAX, 2),
+            Fract128::new(u32::MAX as u128 * 2, 4).to_fract32_approx()
+        );
+        assert_eq!(
+            Fract32::new(1, 1),
+            Fract128::new(u128::MAX, u128::MAX - 1).to_fract32_approx()
+        );
+        assert_eq!(
+            Fract32::new(u32::MAX, 1),
+            Fract128::new(u128::MAX, 1).to_fract32_approx()
+        )
+    }
+
+    #[test]
+    fn should_approximate_to_fract64() {
+        assert_eq!(
+            Fract64::new(u64::MAX, 2),
+            Fract128::new(u64::MAX as u128 * 2, 4).to_fract64_approx()
+        );
+        assert_eq!(
+            Fract64::new(1, 1),
+            Fract128::new(u128::MAX, u128::MAX - 1).to_fract64_approx()
+        );
+        assert_eq!(
+            Fract64::new(u64::MAX, 1),
+            Fract128::new(u128::MAX, 1).to_fract64_approx()
+        )
+    }
+
+    #[test]
+    fn should_display() {
+        assert_eq!("3/4", format!("{}", Fract128::new(3, 4)));
+        assert_eq!("4/8", format!("{}", Fract128::new(4, 8)));
+        assert_eq!("5", format!("{}", Fract128::new(5, 1)));
+        assert_eq!("0", format!("{}", Fract128::new(0, 1)))
+    }
+
+    #[test]
+    fn should_find_largest_unit_fraction() {
+        assert_eq!(
+            Fract128::new(1, 2),
+            Fract128::new(3, 4).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract128::new(1, 3),
+            Fract128::new(2, 5).largest_unit_fraction()
+        );
+        assert_eq!(
+            Fract128::new(1, 4),
+            Fract128::new(2, 8).largest_unit_fraction()
+        )
+    }
+
+    #[test]
+    fn should_decompose_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract128::new(1, 2), Fract128::new(1, 4)]),
+            Fract128::new(3, 4).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract128::new(1, 2), Fract128::new(1, 3)]),
+            Fract128::new(5, 6).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract128::new(1, 7)]),
+            Fract128::new(2, 14).egyptian_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_whole_part_from_egyptian_fraction() {
+        assert_eq!(
+            Some(vec![Fract128::new(2, 1), Fract128::new(1, 2)]),
+            Fract128::new(5, 2).egyptian_fraction()
+        );
+        assert_eq!(
+            Some(vec![Fract128::new(3, 1)]),
+            Fract128::new(6, 2).egyptian_fraction()
+        );
+        assert_eq!(Some(vec![]), Fract128::new(0, 4).egyptian_fraction());
+        assert_eq!(None, Fract128::new(1, 0).egyptian_fraction())
+    }
+
+    #[test]
+    fn should_compare_safely() {
+        assert_eq!(
+            Some(Ordering::Less),
+            Fract128::new(1, 3).safe_cmp(&Fract128::new(1, 2))
+        );
+        assert_eq!(
+            Some(Ordering::Equal),
+            Fract128::new(2, 4).safe_cmp(&Fract128::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_compare_zero_denominator() {
+        assert_eq!(None, Fract128::new(1, 0).safe_cmp(&Fract128::new(1, 2)));
+        assert_eq!(None, Fract128::new(1, 2).safe_cmp(&Fract128::new(0, 0)))
+    }
+
+    #[test]
+    fn should_default_to_zero() {
+        assert_eq!(Fract128::new(0, 1), Fract128::default())
+    }
+
+    #[test]
+    fn should_build_with_numerator_and_denominator() {
+        let expected: Fract128 = Fract128 {
+            numerator: 3,
+            denominator: 4,
+        };
+
+        let actual: Fract128 = Fract128::default().with_numerator(3).with_denominator(4);
+
+        assert_eq!(expected, actual)
+    }
+
+    #[test]
+    fn should_build_invalid_with_zero_denominator() {
+        let actual: Fract128 = Fract128::new(3, 4).with_denominator(0);
+
+        assert_eq!(Fract128::new(3, 0), actual);
+        assert_eq!(None, actual.safe_cmp(&Fract128::new(3, 4)))
+    }
+
+    #[test]
+    fn should_parse_with_radix() {
+        let expected: Fract128 = Fract128 {
+            numerator: 255,
+            denominator: 16,
+        };
+
+        assert_eq!(Ok(expected), Fract128::from_str_radix("ff/10", 16));
+        assert_eq!(
+            Ok(Fract128::new(5, 2)),
+            Fract128::from_str_radix("101/10", 2)
+        )
+    }
+
+    #[test]
+    fn should_not_parse_invalid_digit_with_radix() {
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract128::from_str_radix("fg/10", 16)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract128::from_str_radix("12/10", 2)
+        );
+        assert_eq!(
+            Err(FractError::InvalidFormat),
+            Fract128::from_str_radix("1/1", 37)
+        )
+    }
+
+    #[test]
+    fn should_not_parse_overflow() {
+        let too_large: String = format!("{}0", u128::MAX);
+
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            format!("{}/1", too_large).parse::<Fract128>()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            format!("1/{}", too_large).parse::<Fract128>()
+        )
+    }
+
+    #[test]
+    fn should_convert_to_basis_points() {
+        assert_eq!(5000, Fract128::new(1, 2).to_basis_points());
+        assert_eq!(2500, Fract128::new(1, 4).to_basis_points());
+        assert_eq!(3333, Fract128::new(1, 3).to_basis_points());
+        assert_eq!(6667, Fract128::new(2, 3).to_basis_points());
+        assert_eq!(25000, Fract128::new(5, 2).to_basis_points())
+    }
+
+    #[test]
+    fn should_round_half_up_to_basis_points() {
+        assert_eq!(313, Fract128::new(1, 32).to_basis_points());
+        assert_eq!(156, Fract128::new(1, 64).to_basis_points());
+        assert_eq!(1667, Fract128::new(1, 6).to_basis_points())
+    }
+
+    #[test]
+    fn should_convert_exactly_to_float() {
+        assert_eq!(Ok(0.5), Fract128::new(1, 2).to_float_exact());
+        assert_eq!(Ok(0.75), Fract128::new(6, 8).to_float_exact());
+        assert_eq!(Ok(0.0), Fract128::new(0, 3).to_float_exact())
+    }
+
+    #[test]
+    fn should_flag_inexact_float() {
+        assert_eq!(Err(1.0 / 3.0), Fract128::new(1, 3).to_float_exact());
+        assert!(Fract128::new(1, 0).to_float_exact().is_err())
+    }
+
+    #[test]
+    fn should_flag_numerator_beyond_mantissa() {
+        assert!(Fract128::new((1 << f64::MANTISSA_DIGITS) + 1, 1)
+            .to_float_exact()
+            .is_err());
+        assert!(Fract128::new(1 << f64::MANTISSA_DIGITS, 1)
+            .to_float_exact()
+            .is_ok())
+    }
+
+    #[test]
+    fn should_convert_unreduced_exactly() {
+        let k: u128 = (1 << 53) + 1;
+        assert_eq!(Ok(0.75), Fract128::new(3 * k, 4 * k).to_float_exact())
+    }
+
+    #[test]
+    fn should_add_integer() {
+        assert_eq!(Fract128::new(3, 2), Fract128::new(1, 2) + 1);
+        assert_eq!(Fract128::new(3, 2), 1 + Fract128::new(1, 2))
+    }
+
+    #[test]
+    fn should_sub_integer() {
+        assert_eq!(Fract128::new(1, 2), Fract128::new(3, 2) - 1)
+    }
+
+    #[test]
+    fn should_mul_integer() {
+        assert_eq!(Fract128::new(3, 2), Fract128::new(1, 2) * 3);
+        assert_eq!(Fract128::new(3, 2), 3 * Fract128::new(1, 2))
+    }
+
+    #[test]
+    fn should_div_integer() {
+        assert_eq!(Fract128::new(1, 6), Fract128::new(1, 2) / 3)
+    }
+
+    #[test]
+    fn should_return_raw_components() {
+        assert_eq!((2, 4), Fract128::new(2, 4).components());
+        assert_eq!((3, 0), Fract128::new(3, 0).components())
+    }
+
+    #[test]
+    fn should_expand_slice_to_common_denominator() {
+        let fractions: [Fract128; 3] = [
+            Fract128::new(1, 2),
+            Fract128::new(1, 3),
+            Fract128::new(5, 6),
+        ];
+        assert_eq!(
+            Ok(vec![
+                Fract128::new(3, 6),
+                Fract128::new(2, 6),
+                Fract128::new(5, 6)
+            ]),
+            Fract128::common_denominator(&fractions)
+        );
+        assert_eq!(Ok(vec![]), Fract128::common_denominator(&[]))
+    }
+
+    #[test]
+    fn should_not_overflow_common_denominator() {
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract128::common_denominator(&[
+                Fract128::new(1, u128::MAX),
+                Fract128::new(1, u128::MAX - 1)
+            ])
+        );
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract128::common_denominator(&[Fract128::new(1, 2), Fract128::new(1, 0)])
+        )
+    }
+
+    #[test]
+    fn should_div_rem_fract() {
+        assert_eq!(
+            Some((3, Fract128::new(1, 2))),
+            Fract128::new(7, 2).div_rem_fract(&Fract128::new(1, 1))
+        );
+        assert_eq!(
+            Some((10, Fract128::new(1, 6))),
+            Fract128::new(7, 2).div_rem_fract(&Fract128::new(1, 3))
+        );
+        assert_eq!(
+            Some((0, Fract128::new(1, 4))),
+            Fract128::new(1, 4).div_rem_fract(&Fract128::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_div_rem_fract_by_zero() {
+        assert_eq!(
+            None,
+            Fract128::new(7, 2).div_rem_fract(&Fract128::new(0, 1))
+        )
+    }
+
+    #[test]
+    fn should_reduce_in_place() {
+        let mut fraction: Fract128 = Fract128::new(10, 18);
+        fraction.reduce_mut();
+        assert_eq!(Fract128::new(5, 9), fraction);
+
+        let mut zero: Fract128 = Fract128::new(0, 18);
+        zero.reduce_mut();
+        assert_eq!(Fract128::new(0, 1), zero)
+    }
+
+    #[test]
+    fn should_display_alternate_with_decimal() {
+        let fraction: Fract128 = Fract128::new(3, 4);
+        assert_eq!("3/4", format!("{}", fraction));
+        assert_eq!("3/4 (0.75)", format!("{:#}", fraction));
+        assert_eq!("3 (3)", format!("{:#}", Fract128::new(3, 1)))
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_exact_floats() {
+        assert_eq!(0, Fract128::new(1, 2).float_ulp_error());
+        assert_eq!(0, Fract128::new(3, 8).float_ulp_error());
+        assert_eq!(0, Fract128::new(0, 5).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_no_ulp_error_for_correctly_rounded_floats() {
+        assert_eq!(0, Fract128::new(1, 3).float_ulp_error());
+        assert_eq!(0, Fract128::new(2, 7).float_ulp_error())
+    }
+
+    #[test]
+    fn should_report_ulp_error_of_rounded_numerator() {
+        assert_eq!(1, Fract128::new((1 << 53) + 1, 3).float_ulp_error())
+    }
+
+    #[test]
+    fn should_reduce_into_fract8() {
+        assert_eq!(
+            Ok(Fract8::new(1, 2)),
+            Fract128::new(100, 200).reduce_into_fract8()
+        );
+        assert_eq!(
+            Ok(Fract8::new(u8::MAX, 1)),
+            Fract128::new(u8::MAX as u128 * 2, 2).reduce_into_fract8()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract8() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract128::new(u8::MAX as u128 + 1, 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract128::new(1, u8::MAX as u128 + 1).reduce_into_fract8()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract8::try_from(Fract128::new(2, u8::MAX as u128 * 2))
+        )
+    }
+
+    #[test]
+    fn should_reduce_into_fract16() {
+        assert_eq!(
+            Ok(Fract16::new(1, 2)),
+            Fract128::new(100, 200).reduce_into_fract16()
+        );
+        assert_eq!(
+            Ok(Fract16::new(u16::MAX, 1)),
+            Fract128::new(u16::MAX as u128 * 2, 2).reduce_into_fract16()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract16() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract128::new(u16::MAX as u128 + 1, 1).reduce_into_fract16()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract128::new(1, u16::MAX as u128 + 1).reduce_into_fract16()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract16::try_from(Fract128::new(2, u16::MAX as u128 * 2))
+        )
+    }
+
+    #[test]
+    fn should_reduce_into_fract32() {
+        assert_eq!(
+            Ok(Fract32::new(1, 2)),
+            Fract128::new(100, 200).reduce_into_fract32()
+        );
+        assert_eq!(
+            Ok(Fract32::new(u32::MAX, 1)),
+            Fract128::new(u32::MAX as u128 * 2, 2).reduce_into_fract32()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract32() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract128::new(u32::MAX as u128 + 1, 1).reduce_into_fract32()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract128::new(1, u32::MAX as u128 + 1).reduce_into_fract32()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract32::try_from(Fract128::new(2, u32::MAX as u128 * 2))
+        )
+    }
+
+    #[test]
+    fn should_reduce_into_fract64() {
+        assert_eq!(
+            Ok(Fract64::new(1, 2)),
+            Fract128::new(100, 200).reduce_into_fract64()
+        );
+        assert_eq!(
+            Ok(Fract64::new(u64::MAX, 1)),
+            Fract128::new(u64::MAX as u128 * 2, 2).reduce_into_fract64()
+        )
+    }
+
+    #[test]
+    fn should_not_reduce_into_fract64() {
+        assert_eq!(
+            Err(FractError::NumeratorOverflow),
+            Fract128::new(u64::MAX as u128 + 1, 1).reduce_into_fract64()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract128::new(1, u64::MAX as u128 + 1).reduce_into_fract64()
+        );
+        assert_eq!(
+            Err(FractError::DenominatorOverflow),
+            Fract64::try_from(Fract128::new(2, u64::MAX as u128 * 2))
+        )
+    }
+
+    #[test]
+    fn should_find_modular_inverse() {
+        assert_eq!(Some(5), Fract128::new(3, 7).modular_inverse());
+        assert_eq!(Some(3), Fract128::new(7, 10).modular_inverse())
+    }
+
+    #[test]
+    fn should_not_find_modular_inverse() {
+        assert_eq!(None, Fract128::new(4, 8).modular_inverse());
+        assert_eq!(None, Fract128::new(3, 0).modular_inverse())
+    }
+
+    #[test]
+    fn should_round_trip_le_bytes() {
+        let fraction: Fract128 = Fract128::new(3, 250);
+        assert_eq!(fraction, Fract128::from_le_bytes(fraction.to_le_bytes()));
+        assert_eq!(
+            Fract128::new(u128::MAX, 1),
+            Fract128::from_le_bytes(Fract128::new(u128::MAX, 1).to_le_bytes())
+        )
+    }
+
+    #[test]
+    fn should_checked_add_int() {
+        assert_eq!(
+            Some(Fract128::new(3, 2)),
+            Fract128::new(1, 2).checked_add_int(1)
+        );
+        assert_eq!(
+            Some(Fract128::new(7, 1)),
+            Fract128::new(2, 1).checked_add_int(5)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_add_int_on_overflow() {
+        assert_eq!(None, Fract128::new(u128::MAX, 1).checked_add_int(1));
+        assert_eq!(None, Fract128::new(1, 2).checked_add_int(u128::MAX / 2 + 1))
+    }
+
+    #[test]
+    fn should_display_exact_within_bound() {
+        assert_eq!("1/2", Fract128::new(1, 2).display_approx(4));
+        assert_eq!("1/2", Fract128::new(2, 4).display_approx(2))
+    }
+
+    #[test]
+    fn should_display_approx_beyond_bound() {
+        assert_eq!("≈1/2", Fract128::new(127, 255).display_approx(4));
+        assert_eq!("≈1", Fract128::new(99, 100).display_approx(3))
+    }
+
+    #[test]
+    fn should_convert_float_within_iteration_cap() {
+        assert_eq!(
+            Ok(Fract128::new(3, 4)),
+            Fract128::from_float_capped(0.75, 10, 100)
+        );
+        assert_eq!(
+            Ok(Fract128::new(22, 7)),
+            Fract128::from_float_capped(std::f64::consts::PI, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_not_convert_float_past_iteration_cap() {
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract128::from_float_capped(std::f64::consts::PI, 2, 200)
+        );
+        assert_eq!(
+            Err(FractError::IterationLimit),
+            Fract128::from_float_capped(0.5, 1, 10)
+        );
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract128::from_float_capped(-0.5, 10, 10)
+        )
+    }
+
+    #[test]
+    fn should_sum_reduced() {
+        let halves: Vec<Fract128> = vec![Fract128::new(1, 2); 40];
+        assert_eq!(Some(Fract128::new(20, 1)), Fract128::sum_reduced(halves));
+        assert_eq!(
+            Some(Fract128::new(1, 6)),
+            Fract128::sum_reduced(vec![Fract128::new(1, 10), Fract128::new(1, 15)])
+        );
+        assert_eq!(Some(Fract128::new(0, 1)), Fract128::sum_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_sum_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract128::sum_reduced(vec![Fract128::new(u128::MAX, 1), Fract128::new(1, 1)])
+        );
+        assert_eq!(None, Fract128::sum_reduced(vec![Fract128::new(1, 0)]))
+    }
+
+    #[test]
+    fn should_product_reduced() {
+        let pairs: Vec<Fract128> = [Fract128::new(1, 2), Fract128::new(4, 2)].repeat(50);
+        assert_eq!(Some(Fract128::new(1, 1)), Fract128::product_reduced(pairs));
+        assert_eq!(
+            Some(Fract128::new(2, 5)),
+            Fract128::product_reduced(vec![Fract128::new(4, 6), Fract128::new(3, 5)])
+        );
+        assert_eq!(Some(Fract128::new(1, 1)), Fract128::product_reduced(vec![]))
+    }
+
+    #[test]
+    fn should_not_product_reduced_on_overflow() {
+        assert_eq!(
+            None,
+            Fract128::product_reduced(vec![Fract128::new(u128::MAX, 1), Fract128::new(2, 1)])
+        )
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_terminating_fraction() {
+        assert_eq!(1, Fract128::new(1, 8).decimal_digit_at(1));
+        assert_eq!(2, Fract128::new(1, 8).decimal_digit_at(2));
+        assert_eq!(5, Fract128::new(1, 8).decimal_digit_at(3));
+        assert_eq!(0, Fract128::new(1, 8).decimal_digit_at(4));
+        assert_eq!(2, Fract128::new(25, 2).decimal_digit_at(0))
+    }
+
+    #[test]
+    fn should_find_decimal_digit_of_repeating_fraction() {
+        assert_eq!(3, Fract128::new(1, 3).decimal_digit_at(1));
+        assert_eq!(3, Fract128::new(1, 3).decimal_digit_at(50));
+        assert_eq!(7, Fract128::new(1, 7).decimal_digit_at(6));
+        assert_eq!(1, Fract128::new(1, 7).decimal_digit_at(7))
+    }
+
+    #[test]
+    fn should_reduce_checked() {
+        assert_eq!(
+            (Fract128::new(5, 9), true),
+            Fract128::new(10, 18).reduce_checked()
+        );
+        assert_eq!(
+            (Fract128::new(5, 9), false),
+            Fract128::new(5, 9).reduce_checked()
+        );
+        assert_eq!(
+            (Fract128::new(0, 1), true),
+            Fract128::new(0, 5).reduce_checked()
+        )
+    }
+
+    #[test]
+    fn should_create_from_bool() {
+        assert_eq!(Fract128::new(1, 1), Fract128::from(true));
+        assert_eq!(Fract128::new(0, 1), Fract128::from(false))
+    }
+
+    #[test]
+    fn should_scale() {
+        assert_eq!(Some(Fract128::new(3, 6)), Fract128::new(1, 2).scale(3));
+        assert_eq!(Some(Fract128::new(0, 0)), Fract128::new(1, 2).scale(0))
+    }
+
+    #[test]
+    fn should_not_scale_on_overflow() {
+        assert_eq!(None, Fract128::new(1, u128::MAX / 2 + 1).scale(2));
+        assert_eq!(None, Fract128::new(u128::MAX, 1).scale(2))
+    }
+
+    #[test]
+    fn should_format_sig_figs() {
+        assert_eq!("0.3333", Fract128::new(1, 3).to_sig_figs(4));
+        assert_eq!("3.14", Fract128::new(22, 7).to_sig_figs(3));
+        assert_eq!("0.667", Fract128::new(2, 3).to_sig_figs(3));
+        assert_eq!("", Fract128::new(2, 0).to_sig_figs(3))
+    }
+
+    #[test]
+    fn should_debug_fields() {
+        assert_eq!(
+            "Fract128 { numerator: 10, denominator: 18 }",
+            format!("{:?}", Fract128::new(10, 18))
+        )
+    }
+
+    #[test]
+    fn should_debug_alternate_with_reduced_value() {
+        assert_eq!(
+            "Fract128 { numerator: 1, denominator: 2 }",
+            format!("{:?}", Fract128::new(1, 2))
+        );
+        assert_eq!(
+            "Fract128 { numerator: 10, denominator: 20, reduced: 1/2, value: 0.5 }",
+            format!("{:#?}", Fract128::new(10, 20))
+        )
+    }
+
+    #[test]
+    fn should_clamp_to_one() {
+        assert_eq!(Fract128::new(1, 1), Fract128::new(3, 2).clamp01());
+        assert_eq!(Fract128::new(1, 2), Fract128::new(1, 2).clamp01());
+        assert_eq!(Fract128::new(4, 4), Fract128::new(4, 4).clamp01())
+    }
+
+    #[test]
+    fn should_find_period_length() {
+        assert_eq!(Some(0), Fract128::new(1, 2).period_length());
+        assert_eq!(Some(0), Fract128::new(3, 40).period_length());
+        assert_eq!(Some(1), Fract128::new(1, 3).period_length());
+        assert_eq!(Some(6), Fract128::new(1, 7).period_length());
+        assert_eq!(Some(6), Fract128::new(1, 14).period_length());
+        assert_eq!(Some(2), Fract128::new(1, 11).period_length());
+        assert_eq!(Some(0), Fract128::new(7, 7).period_length())
+    }
+
+    #[test]
+    fn should_find_long_period_length() {
+        assert_eq!(
+            Some(1_000_000_006),
+            Fract128::new(1, 1_000_000_007).period_length()
+        );
+        assert_eq!(
+            Some(499_122_178_994_733_056),
+            Fract128::new(3, 1_000_000_007 * 998_244_353 * 8).period_length()
+        )
+    }
+
+    #[test]
+    fn should_not_find_period_length_beyond_u64() {
+        assert_eq!(None, Fract128::new(1, (1 << 89) - 1).period_length())
+    }
+
+    #[test]
+    fn should_fit_in_fract8() {
+        assert!(Fract128::new(100, 200).fits_in_fract8());
+        assert!(Fract128::new(u8::MAX as u128 * 2, 2).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract8() {
+        assert!(!Fract128::new(u8::MAX as u128 + 1, 1).fits_in_fract8());
+        assert!(!Fract128::new(1, u8::MAX as u128 + 1).fits_in_fract8())
+    }
+
+    #[test]
+    fn should_fit_in_fract16() {
+        assert!(Fract128::new(100, 200).fits_in_fract16());
+        assert!(Fract128::new(u16::MAX as u128 * 2, 2).fits_in_fract16())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract16() {
+        assert!(!Fract128::new(u16::MAX as u128 + 1, 1).fits_in_fract16());
+        assert!(!Fract128::new(1, u16::MAX as u128 + 1).fits_in_fract16())
+    }
+
+    #[test]
+    fn should_fit_in_fract32() {
+        assert!(Fract128::new(100, 200).fits_in_fract32());
+        assert!(Fract128::new(u32::MAX as u128 * 2, 2).fits_in_fract32())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract32() {
+        assert!(!Fract128::new(u32::MAX as u128 + 1, 1).fits_in_fract32());
+        assert!(!Fract128::new(1, u32::MAX as u128 + 1).fits_in_fract32())
+    }
+
+    #[test]
+    fn should_fit_in_fract64() {
+        assert!(Fract128::new(100, 200).fits_in_fract64());
+        assert!(Fract128::new(u64::MAX as u128 * 2, 2).fits_in_fract64())
+    }
+
+    #[test]
+    fn should_not_fit_in_fract64() {
+        assert!(!Fract128::new(u64::MAX as u128 + 1, 1).fits_in_fract64());
+        assert!(!Fract128::new(1, u64::MAX as u128 + 1).fits_in_fract64())
+    }
+
+    #[test]
+    fn should_mul_with_cross_cancellation() {
+        assert_eq!(
+            Fract128::new(1, 1),
+            Fract128::new(u128::MAX, 3) * Fract128::new(3, u128::MAX)
+        );
+        assert_eq!(
+            Fract128::new(2, 1),
+            Fract128::new(100, 9) * Fract128::new(9, 50)
+        )
+    }
+
+    #[test]
+    fn should_div_with_cross_cancellation() {
+        assert_eq!(
+            Fract128::new(2, 1),
+            Fract128::new(u128::MAX, 2) / Fract128::new(u128::MAX, 4)
+        );
+        assert_eq!(
+            Fract128::new(2, 3),
+            Fract128::new(200, 3) / Fract128::new(100, 1)
+        );
+        assert_eq!(
+            Fract128::new(1, 4),
+            Fract128::new(4, 6) / Fract128::new(8, 3)
+        )
+    }
+
+    #[test]
+    fn should_validate() {
+        assert_eq!(Ok(()), Fract128::new(1, 2).validate());
+        assert_eq!(Ok(()), Fract128::new(0, 3).validate())
+    }
+
+    #[test]
+    fn should_not_validate_zero_denominator() {
+        let fraction: Fract128 = Fract128 {
+            numerator: 1,
+            denominator: 0,
+        };
+
+        assert_eq!(Err(FractError::ZeroDenominator), fraction.validate())
+    }
+
+    #[test]
+    fn should_find_rounded_decimal_digits() {
+        assert_eq!(
+            vec![3, 3, 3, 3],
+            Fract128::new(1, 3).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![6, 7], Fract128::new(2, 3).decimal_digits_rounded(2));
+        assert_eq!(
+            vec![1, 2, 5, 0],
+            Fract128::new(1, 8).decimal_digits_rounded(4)
+        );
+        assert_eq!(vec![1, 3], Fract128::new(1, 8).decimal_digits_rounded(2))
+    }
+
+    #[test]
+    fn should_drop_carry_of_rounded_decimal_digits() {
+        assert_eq!(
+            vec![0, 0],
+            Fract128::new(199, 200).decimal_digits_rounded(2)
+        );
+        assert_eq!(
+            Vec::<u8>::new(),
+            Fract128::new(2, 3).decimal_digits_rounded(0)
+        )
+    }
+
+    #[test]
+    fn should_reduce_fast_paths() {
+        assert_eq!(Fract128::new(1, 9), Fract128::new(1, 9).reduce());
+        assert_eq!(Fract128::new(9, 1), Fract128::new(9, 1).reduce());
+        assert_eq!(Fract128::new(0, 1), Fract128::new(0, 9).reduce());
+        assert_eq!(Fract128::new(0, 0), Fract128::new(0, 0).reduce());
+        assert_eq!(Fract128::new(1, 0), Fract128::new(1, 0).reduce())
+    }
+
+    #[test]
+    fn should_hash_consistently_across_widths() {
+        fn hash_of<T: Hash>(value: T) -> u64 {
+            let mut hasher: DefaultHasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(hash_of(Fract8::new(1, 2)), hash_of(Fract128::new(1, 2)));
+        assert_eq!(hash_of(Fract128::new(1, 2)), hash_of(Fract128::new(2, 4)));
+        assert_ne!(hash_of(Fract128::new(1, 2)), hash_of(Fract128::new(1, 3)))
+    }
+
+    #[test]
+    fn should_find_minimal_form() {
+        assert_eq!(Fract128::new(1, 2), Fract128::new(50, 100).minimal());
+        assert_eq!(Fract128::new(0, 1), Fract128::new(0, 7).minimal());
+        assert_eq!(Fract128::new(3, 4), Fract128::new(3, 4).minimal())
+    }
+
+    #[test]
+    fn should_checked_shl() {
+        assert_eq!(
+            Some(Fract128::new(3, 1)),
+            Fract128::new(3, 4).checked_shl(2)
+        );
+        assert_eq!(
+            Some(Fract128::new(12, 1)),
+            Fract128::new(3, 1).checked_shl(2)
+        );
+        assert_eq!(
+            Some(Fract128::new(0, 5)),
+            Fract128::new(0, 5).checked_shl(200)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_shl_on_overflow() {
+        assert_eq!(None, Fract128::new(u128::MAX / 2 + 1, 1).checked_shl(1));
+        assert_eq!(None, Fract128::new(1, 1).checked_shl(u128::BITS))
+    }
+
+    #[test]
+    fn should_checked_shr() {
+        assert_eq!(
+            Some(Fract128::new(3, 1)),
+            Fract128::new(12, 1).checked_shr(2)
+        );
+        assert_eq!(
+            Some(Fract128::new(3, 4)),
+            Fract128::new(3, 1).checked_shr(2)
+        )
+    }
+
+    #[test]
+    fn should_not_checked_shr_on_overflow() {
+        assert_eq!(None, Fract128::new(1, u128::MAX / 2 + 1).checked_shr(1))
+    }
+
+    #[test]
+    fn should_reduce_keeping_zero_denominator() {
+        assert_eq!(
+            Fract128::new(0, 6),
+            Fract128::new(0, 6).reduce_keep_denominator()
+        );
+        assert_eq!(
+            Fract128::new(5, 9),
+            Fract128::new(10, 18).reduce_keep_denominator()
+        )
+    }
+
+    #[test]
+    fn should_format_mixed_string() {
+        assert_eq!("3 1/2", Fract128::new(7, 2).to_mixed_string());
+        assert_eq!("1/2", Fract128::new(1, 2).to_mixed_string());
+        assert_eq!("2", Fract128::new(6, 3).to_mixed_string());
+        assert_eq!("1 1/3", Fract128::new(8, 6).to_mixed_string());
+        assert_eq!("0", Fract128::new(0, 4).to_mixed_string())
+    }
+
+    #[test]
+    fn should_find_ratio() {
+        assert_eq!(
+            Some(Fract128::new(3, 2)),
+            Fract128::new(3, 4).ratio_to(&Fract128::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract128::new(1, 1)),
+            Fract128::new(6, 8).ratio_to(&Fract128::new(3, 4))
+        )
+    }
+
+    #[test]
+    fn should_not_find_ratio_to_zero() {
+        assert_eq!(None, Fract128::new(3, 4).ratio_to(&Fract128::new(0, 1)))
+    }
+
+    #[test]
+    fn should_be_between() {
+        assert!(Fract128::new(1, 2).is_between(&Fract128::new(0, 1), &Fract128::new(1, 1)));
+        assert!(Fract128::new(1, 2).is_between(&Fract128::new(1, 1), &Fract128::new(0, 1)));
+        assert!(Fract128::new(2, 4).is_between(&Fract128::new(1, 2), &Fract128::new(3, 4)))
+    }
+
+    #[test]
+    fn should_not_be_between() {
+        assert!(!Fract128::new(1, 2).is_between(&Fract128::new(3, 4), &Fract128::new(1, 1)))
+    }
+
+    #[test]
+    fn should_find_convergents() {
+        let convergents: Vec<Fract128> = Fract128::new(43, 19).convergents();
+        assert_eq!(Fract128::new(2, 1), convergents[0]);
+        assert_eq!(Fract128::new(7, 3), convergents[1]);
+        assert_eq!(
+            vec![
+                Fract128::new(2, 1),
+                Fract128::new(7, 3),
+                Fract128::new(9, 4),
+                Fract128::new(43, 19)
+            ],
+            convergents
+        );
+        assert_eq!(
+            Some(&Fract128::new(43, 19)),
+            Fract128::new(86, 38).convergents().last()
+        )
+    }
+
+    #[test]
+    fn should_find_convergents_of_edge_values() {
+        assert_eq!(
+            vec![Fract128::new(0, 1), Fract128::new(1, 3)],
+            Fract128::new(1, 3).convergents()
+        );
+        assert_eq!(
+            vec![Fract128::new(u128::MAX, 1)],
+            Fract128::new(u128::MAX, 1).convergents()
+        );
+        assert_eq!(Vec::<Fract128>::new(), Fract128::new(1, 0).convergents())
+    }
+
+    #[test]
+    fn should_cmp_with_float_exactly() {
+        assert_eq!(Ordering::Equal, Fract128::new(1, 2).cmp_with_float(0.5));
+        assert_eq!(Ordering::Less, Fract128::new(1, 2).cmp_with_float(0.75));
+        assert_eq!(Ordering::Greater, Fract128::new(3, 2).cmp_with_float(1.25))
+    }
+
+    #[test]
+    fn should_cmp_with_float_next_to_one_third() {
+        let third: Fract128 = Fract128::new(1, 3);
+        let nearest: f64 = 1.0 / 3.0;
+        assert_eq!(nearest, third.to_float());
+        assert_eq!(Ordering::Greater, third.cmp_with_float(nearest))
+    }
+
+    #[test]
+    fn should_split_integer_and_fraction() {
+        assert_eq!(
+            (Fract128::new(3, 1), Fract128::new(1, 2)),
+            Fract128::new(7, 2).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract128::new(2, 1), Fract128::new(0, 1)),
+            Fract128::new(6, 3).integer_and_fraction()
+        );
+        assert_eq!(
+            (Fract128::new(0, 1), Fract128::new(2, 3)),
+            Fract128::new(4, 6).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_split_zero_denominator_into_itself() {
+        assert_eq!(
+            (Fract128::new(1, 0), Fract128::new(0, 1)),
+            Fract128::new(1, 0).integer_and_fraction()
+        )
+    }
+
+    #[test]
+    fn should_snap_to_closest() {
+        let allowed: [Fract128; 3] = [
+            Fract128::new(1, 2),
+            Fract128::new(3, 4),
+            Fract128::new(1, 1),
+        ];
+        assert_eq!(
+            Some(Fract128::new(1, 2)),
+            Fract128::new(9, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract128::new(3, 4)),
+            Fract128::new(11, 16).snap_to(&allowed)
+        );
+        assert_eq!(
+            Some(Fract128::new(1, 1)),
+            Fract128::new(3, 2).snap_to(&allowed)
+        )
+    }
+
+    #[test]
+    fn should_snap_ties_to_first() {
+        let allowed: [Fract128; 2] = [Fract128::new(1, 2), Fract128::new(3, 4)];
+        assert_eq!(
+            Some(Fract128::new(1, 2)),
+            Fract128::new(5, 8).snap_to(&allowed)
+        );
+        let reversed: [Fract128; 2] = [Fract128::new(3, 4), Fract128::new(1, 2)];
+        assert_eq!(
+            Some(Fract128::new(3, 4)),
+            Fract128::new(5, 8).snap_to(&reversed)
+        )
+    }
+
+    #[test]
+    fn should_not_snap_to_empty_slice() {
+        assert_eq!(None, Fract128::new(5, 8).snap_to(&[]))
+    }
+
+    #[test]
+    fn should_try_from_signed_pair() {
+        assert_eq!(Ok(Fract128::new(2, 3)), Fract128::try_from((10i64, 15i64)));
+        assert_eq!(Ok(Fract128::new(0, 1)), Fract128::try_from((0i64, 7i64)))
+    }
+
+    #[test]
+    fn should_not_try_from_negative_pair() {
+        assert_eq!(
+            Err(FractError::NumeratorUnderflow),
+            Fract128::try_from((-1i64, 2i64))
+        );
+        assert_eq!(
+            Err(FractError::DenominatorUnderflow),
+            Fract128::try_from((1i64, -2i64))
+        )
+    }
+
+    #[test]
+    fn should_not_try_from_signed_zero_denominator() {
+        assert_eq!(
+            Err(FractError::ZeroDenominator),
+            Fract128::try_from((1i64, 0i64))
+        )
+    }
+
+    #[test]
+    fn should_render_in_radix() {
+        assert_eq!("0.1", Fract128::new(1, 2).to_string_radix(2, 10));
+        assert_eq!("0.1", Fract128::new(1, 3).to_string_radix(3, 10));
+        assert_eq!("0.0101", Fract128::new(1, 3).to_string_radix(2, 4));
+        assert_eq!("12", Fract128::new(10, 2).to_string_radix(3, 4))
+    }
+
+    #[test]
+    fn should_format_continued_fraction() {
+        assert_eq!(
+            "[2; 3, 1, 4]",
+            Fract128::new(43, 19).continued_fraction_string()
+        );
+        assert_eq!("[0; 2]", Fract128::new(1, 2).continued_fraction_string());
+        assert_eq!("[5]", Fract128::new(5, 1).continued_fraction_string())
+    }
+
+    #[test]
+    fn should_find_reduced_reciprocal() {
+        assert_eq!(
+            Some(Fract128::new(9, 5)),
+            Fract128::new(10, 18).reciprocal_reduced()
+        );
+        assert_eq!(
+            Some(Fract128::new(1, 3)),
+            Fract128::new(3, 1).reciprocal_reduced()
+        )
+    }
+
+    #[test]
+    fn should_not_find_reciprocal_of_zero() {
+        assert_eq!(None, Fract128::new(0, 5).reciprocal_reduced())
+    }
+
+    #[test]
+    fn should_find_cross_difference() {
+        assert_eq!(
+            -1,
+            Fract128::new(1, 2).cross_difference(&Fract128::new(2, 3))
+        );
+        assert_eq!(
+            1,
+            Fract128::new(2, 3).cross_difference(&Fract128::new(1, 2))
+        );
+        assert_eq!(
+            0,
+            Fract128::new(2, 4).cross_difference(&Fract128::new(1, 2))
+        );
+        assert_eq!(
+            i128::MAX,
+            Fract128::new(u128::MAX, 1).cross_difference(&Fract128::new(0, 1))
+        );
+        assert_eq!(
+            i128::MIN,
+            Fract128::new(0, 1).cross_difference(&Fract128::new(u128::MAX, 1))
+        )
+    }
+
+    #[test]
+    fn should_find_simplest_between() {
+        assert_eq!(
+            Some(Fract128::new(2, 5)),
+            Fract128::new(1, 3).simplest_between(&Fract128::new(1, 2))
+        );
+        assert_eq!(
+            Some(Fract128::new(2, 5)),
+            Fract128::new(1, 2).simplest_between(&Fract128::new(1, 3))
+        );
+        assert_eq!(
+            Some(Fract128::new(3, 7)),
+            Fract128::new(5, 12).simplest_between(&Fract128::new(4, 9))
+        )
+    }
+
+    #[test]
+    fn should_find_integer_between() {
+        assert_eq!(
+            Some(Fract128::new(2, 1)),
+            Fract128::new(3, 2).simplest_between(&Fract128::new(7, 2))
+        );
+        assert_eq!(
+            Some(Fract128::new(1, 1)),
+            Fract128::new(0, 1).simplest_between(&Fract128::new(3, 1))
+        )
+    }
+
+    #[test]
+    fn should_return_reduced_value_between_equal_bounds() {
+        assert_eq!(
+            Some(Fract128::new(1, 2)),
+            Fract128::new(2, 4).simplest_between(&Fract128::new(1, 2))
+        )
+    }
+
+    #[test]
+    fn should_not_find_simplest_between_beyond_width() {
+        assert_eq!(
+            None,
+            Fract128::new(1, u128::MAX).simplest_between(&Fract128::new(1, u128::MAX - 1))
+        );
+        assert_eq!(
+            None,
+            Fract128::new(u128::MAX - 1, u128::MAX).simplest_between(&Fract128::new(1, 1))
+        )
+    }
 }
@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+
 #[inline]
 pub fn gcd_u8(first: u8, second: u8) -> u8 {
     let mut a: u8 = first;
@@ -82,3 +85,1156 @@ pub fn gcd_u128(first: u128, second: u128) -> u128 {
 
     a
 }
+
+#[inline]
+pub fn cmp_ratio_u128(
+    first_numerator: u128,
+    first_denominator: u128,
+    second_numerator: u128,
+    second_denominator: u128,
+) -> Ordering {
+    if first_denominator == 0 || second_denominator == 0 {
+        let first: bool = first_numerator != 0 && second_denominator != 0;
+        let second: bool = second_numerator != 0 && first_denominator != 0;
+        return first.cmp(&second);
+    }
+
+    let mut a: (u128, u128) = (first_numerator, first_denominator);
+    let mut b: (u128, u128) = (second_numerator, second_denominator);
+    loop {
+        let a_whole: u128 = a.0 / a.1;
+        let b_whole: u128 = b.0 / b.1;
+        if a_whole != b_whole {
+            return a_whole.cmp(&b_whole);
+        }
+
+        let a_rem: u128 = a.0 % a.1;
+        let b_rem: u128 = b.0 % b.1;
+        if a_rem == 0 || b_rem == 0 {
+            return (a_rem != 0).cmp(&(b_rem != 0));
+        }
+
+        // a_rem/a.1 < b_rem/b.1 exactly when b.1/b_rem < a.1/a_rem
+        let next_a: (u128, u128) = (b.1, b_rem);
+        b = (a.1, a_rem);
+        a = next_a;
+    }
+}
+
+#[inline]
+pub fn is_digits(value: &str) -> bool {
+    value.chars().all(|c| c.is_ascii_digit())
+}
+
+#[inline]
+pub fn is_digits_radix(value: &str, radix: u32) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_digit(radix))
+}
+
+#[inline]
+pub fn push_digits_u64(value: u64, digits: &str) -> Option<u64> {
+    let mut result: u64 = value;
+    for digit in digits.bytes() {
+        result = result.checked_mul(10)?.checked_add((digit - b'0') as u64)?;
+    }
+
+    Some(result)
+}
+
+// Best rational approximation of a non-negative finite `value` whose numerator
+// and denominator stay within the given bounds, walking the continued fraction.
+#[inline]
+pub fn approximate_f64(value: f64, max_numerator: u128, max_denominator: u128) -> (u128, u128) {
+    approximate_f64_capped(value, max_numerator, max_denominator, u32::MAX)
+        .unwrap_or_else(|last| last)
+}
+
+// Like `approximate_f64`, but gives up after `max_iterations` continued fraction terms,
+// returning the last convergent as the error.
+#[inline]
+pub fn approximate_f64_capped(
+    value: f64,
+    max_numerator: u128,
+    max_denominator: u128,
+    max_iterations: u32,
+) -> Result<(u128, u128), (u128, u128)> {
+    let (mut p0, mut q0, mut p1, mut q1): (u128, u128, u128, u128) = (0, 1, 1, 0);
+    let mut x: f64 = value;
+    for _ in 0..max_iterations {
+        let whole: f64 = x.floor();
+        let a: u128 = whole as u128;
+        let next: Option<(u128, u128)> = a
+            .checked_mul(p1)
+            .and_then(|p| p.checked_add(p0))
+            .zip(a.checked_mul(q1).and_then(|q| q.checked_add(q0)));
+
+        match next {
+            Some((p2, q2)) if p2 <= max_numerator && q2 <= max_denominator => {
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+            }
+            _ => {
+                // the largest semiconvergent still inside the bounds may beat the last convergent
+                let k_num: u128 = (max_numerator - p0).checked_div(p1).unwrap_or(u128::MAX);
+                let k_den: u128 = (max_denominator - q0).checked_div(q1).unwrap_or(u128::MAX);
+                let k: u128 = a.min(k_num).min(k_den);
+                if k > 0 {
+                    let p: u128 = k * p1 + p0;
+                    let q: u128 = k * q1 + q0;
+                    let semi_error: f64 = (p as f64 / q as f64 - value).abs();
+                    let error: f64 = (p1 as f64 / q1 as f64 - value).abs();
+                    if semi_error < error {
+                        return Ok((p, q));
+                    }
+                }
+                return Ok((p1, q1));
+            }
+        }
+
+        let fraction: f64 = x - whole;
+        if fraction == 0.0 {
+            return Ok((p1, q1));
+        }
+        x = 1.0 / fraction;
+    }
+
+    Err((p1, q1))
+}
+
+#[inline]
+pub fn lcm_u8(first: u8, second: u8) -> u8 {
+    if first == 0 || second == 0 {
+        return 0;
+    }
+
+    first / gcd_u8(first, second) * second
+}
+
+#[inline]
+pub fn lcm_all_u8(values: &[u8]) -> u8 {
+    values.iter().fold(1, |lcm, value| lcm_u8(lcm, *value))
+}
+
+#[inline]
+pub fn lcm_u16(first: u16, second: u16) -> u16 {
+    if first == 0 || second == 0 {
+        return 0;
+    }
+
+    first / gcd_u16(first, second) * second
+}
+
+#[inline]
+pub fn lcm_all_u16(values: &[u16]) -> u16 {
+    values.iter().fold(1, |lcm, value| lcm_u16(lcm, *value))
+}
+
+#[inline]
+pub fn lcm_u32(first: u32, second: u32) -> u32 {
+    if first == 0 || second == 0 {
+        return 0;
+    }
+
+    first / gcd_u32(first, second) * second
+}
+
+#[inline]
+pub fn lcm_all_u32(values: &[u32]) -> u32 {
+    values.iter().fold(1, |lcm, value| lcm_u32(lcm, *value))
+}
+
+#[inline]
+pub fn lcm_u64(first: u64, second: u64) -> u64 {
+    if first == 0 || second == 0 {
+        return 0;
+    }
+
+    first / gcd_u64(first, second) * second
+}
+
+#[inline]
+pub fn lcm_all_u64(values: &[u64]) -> u64 {
+    values.iter().fold(1, |lcm, value| lcm_u64(lcm, *value))
+}
+
+#[inline]
+pub fn lcm_u128(first: u128, second: u128) -> u128 {
+    if first == 0 || second == 0 {
+        return 0;
+    }
+
+    first / gcd_u128(first, second) * second
+}
+
+#[inline]
+pub fn lcm_all_u128(values: &[u128]) -> u128 {
+    values.iter().fold(1, |lcm, value| lcm_u128(lcm, *value))
+}
+
+#[inline]
+pub fn checked_gcd_u8(first: u8, second: u8) -> Option<u8> {
+    if first == 0 && second == 0 {
+        return None;
+    }
+
+    Some(gcd_u8(first, second))
+}
+
+#[inline]
+pub fn checked_gcd_u16(first: u16, second: u16) -> Option<u16> {
+    if first == 0 && second == 0 {
+        return None;
+    }
+
+    Some(gcd_u16(first, second))
+}
+
+#[inline]
+pub fn checked_gcd_u32(first: u32, second: u32) -> Option<u32> {
+    if first == 0 && second == 0 {
+        return None;
+    }
+
+    Some(gcd_u32(first, second))
+}
+
+#[inline]
+pub fn checked_gcd_u64(first: u64, second: u64) -> Option<u64> {
+    if first == 0 && second == 0 {
+        return None;
+    }
+
+    Some(gcd_u64(first, second))
+}
+
+#[inline]
+pub fn checked_gcd_u128(first: u128, second: u128) -> Option<u128> {
+    if first == 0 && second == 0 {
+        return None;
+    }
+
+    Some(gcd_u128(first, second))
+}
+
+// Best rational approximation of `numerator/denominator` within the bounds, taking the
+// last semiconvergent only when it is strictly closer than the last convergent.
+#[inline]
+pub fn approximate_ratio_u128(
+    numerator: u128,
+    denominator: u128,
+    max_numerator: u128,
+    max_denominator: u128,
+) -> (u128, u128) {
+    let (mut p0, mut q0, mut p1, mut q1): (u128, u128, u128, u128) = (0, 1, 1, 0);
+    let mut n: u128 = numerator;
+    let mut d: u128 = denominator;
+    while d != 0 {
+        let a: u128 = n / d;
+        let next: Option<(u128, u128)> = a
+            .checked_mul(p1)
+            .and_then(|p| p.checked_add(p0))
+            .zip(a.checked_mul(q1).and_then(|q| q.checked_add(q0)));
+
+        match next {
+            Some((p2, q2)) if p2 <= max_numerator && q2 <= max_denominator => {
+                p0 = p1;
+                q0 = q1;
+                p1 = p2;
+                q1 = q2;
+            }
+            _ => {
+                let k_num: u128 = (max_numerator - p0).checked_div(p1).unwrap_or(u128::MAX);
+                let k_den: u128 = (max_denominator - q0).checked_div(q1).unwrap_or(u128::MAX);
+                let k: u128 = a.min(k_num).min(k_den);
+                if k > 0 && (q1 == 0 || k > a - k) {
+                    return (k * p1 + p0, k * q1 + q0);
+                }
+                break;
+            }
+        }
+
+        let remainder: u128 = n % d;
+        n = d;
+        d = remainder;
+    }
+
+    (p1, q1)
+}
+
+// Next digit of `remainder / denominator` in `radix` together with the new remainder,
+// without ever forming `remainder * radix`.
+#[inline]
+pub fn next_digit_u128(remainder: u128, denominator: u128, radix: u32) -> (u32, u128) {
+    let mut digit: u32 = 0;
+    let mut scaled: u128 = 0;
+    for _ in 0..radix {
+        if scaled >= denominator - remainder {
+            scaled -= denominator - remainder;
+            digit += 1;
+        } else {
+            scaled += remainder;
+        }
+    }
+
+    (digit, scaled)
+}
+
+#[inline]
+pub fn checked_lcm_u8(first: u8, second: u8) -> Option<u8> {
+    if first == 0 || second == 0 {
+        return Some(0);
+    }
+
+    (first / gcd_u8(first, second)).checked_mul(second)
+}
+
+#[inline]
+pub fn checked_lcm_u16(first: u16, second: u16) -> Option<u16> {
+    if first == 0 || second == 0 {
+        return Some(0);
+    }
+
+    (first / gcd_u16(first, second)).checked_mul(second)
+}
+
+#[inline]
+pub fn checked_lcm_u32(first: u32, second: u32) -> Option<u32> {
+    if first == 0 || second == 0 {
+        return Some(0);
+    }
+
+    (first / gcd_u32(first, second)).checked_mul(second)
+}
+
+#[inline]
+pub fn checked_lcm_u64(first: u64, second: u64) -> Option<u64> {
+    if first == 0 || second == 0 {
+        return Some(0);
+    }
+
+    (first / gcd_u64(first, second)).checked_mul(second)
+}
+
+#[inline]
+pub fn checked_lcm_u128(first: u128, second: u128) -> Option<u128> {
+    if first == 0 || second == 0 {
+        return Some(0);
+    }
+
+    (first / gcd_u128(first, second)).checked_mul(second)
+}
+
+#[inline]
+fn mul_wide_u128(first: u128, second: u128) -> (u128, u128) {
+    let mask: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (first >> 64, first & mask);
+    let (b_hi, b_lo) = (second >> 64, second & mask);
+
+    let lo: u128 = a_lo * b_lo;
+    let middle: u128 = a_hi * b_lo + (lo >> 64);
+    let carry: u128 = (middle & mask) + a_lo * b_hi;
+
+    (
+        a_hi * b_hi + (middle >> 64) + (carry >> 64),
+        (carry << 64) | (lo & mask),
+    )
+}
+
+// `None` once the shifted value no longer fits 256 bits
+#[inline]
+fn shl_wide_u128(value: (u128, u128), shift: u32) -> Option<(u128, u128)> {
+    let (hi, lo) = value;
+    if hi == 0 && lo == 0 || shift == 0 {
+        return Some(value);
+    }
+
+    let bits: u32 = if hi != 0 {
+        256 - hi.leading_zeros()
+    } else {
+        128 - lo.leading_zeros()
+    };
+    if bits + shift > 256 {
+        None
+    } else if shift >= 128 {
+        Some((lo << (shift - 128), 0))
+    } else {
+        Some(((hi << shift) | (lo >> (128 - shift)), lo << shift))
+    }
+}
+
+// Compares `numerator / denominator` with `mantissa * 2^exponent` exactly.
+#[inline]
+fn cmp_ratio_dyadic(numerator: u128, denominator: u128, mantissa: u128, exponent: i32) -> Ordering {
+    let ratio: Option<(u128, u128)> = shl_wide_u128((0, numerator), (-exponent).max(0) as u32);
+    let dyadic: Option<(u128, u128)> =
+        shl_wide_u128(mul_wide_u128(mantissa, denominator), exponent.max(0) as u32);
+    match (ratio, dyadic) {
+        (Some(ratio), Some(dyadic)) => ratio.cmp(&dyadic),
+        (ratio, dyadic) => dyadic.is_some().cmp(&ratio.is_some()),
+    }
+}
+
+// Steps between the positive float with the given `bits` and the float nearest to
+// `numerator / denominator`, for a layout with `mantissa_bits` explicit mantissa bits.
+#[inline]
+pub fn ulp_error(
+    bits: u64,
+    mantissa_bits: u32,
+    bias: i32,
+    numerator: u128,
+    denominator: u128,
+) -> u64 {
+    let midpoint_above = |bits: u64| -> Ordering {
+        let exponent_field: i32 = (bits >> mantissa_bits) as i32;
+        let fraction: u128 = (bits & ((1 << mantissa_bits) - 1)) as u128;
+        let (mantissa, exponent): (u128, i32) = if exponent_field == 0 {
+            (fraction, 1 - bias)
+        } else {
+            (fraction | 1 << mantissa_bits, exponent_field - bias)
+        };
+
+        cmp_ratio_dyadic(numerator, denominator, 2 * mantissa + 1, exponent - 1)
+    };
+
+    let mut steps: u64 = 0;
+    let mut current: u64 = bits;
+    while midpoint_above(current) == Ordering::Greater {
+        current += 1;
+        steps += 1;
+    }
+    if steps == 0 {
+        while current > 0 && midpoint_above(current - 1) == Ordering::Less {
+            current -= 1;
+            steps += 1;
+        }
+    }
+
+    steps
+}
+
+// Inverse of `value` modulo `modulus` via the extended Euclidean algorithm. Bézout
+// coefficients alternate in sign, so only their magnitudes are tracked, which stay
+// within `modulus` and cannot overflow.
+#[inline]
+pub fn inverse_mod_u128(value: u128, modulus: u128) -> Option<u128> {
+    if modulus == 0 {
+        return None;
+    }
+
+    let (mut r0, mut r1): (u128, u128) = (modulus, value % modulus);
+    let (mut x0, mut x1): (u128, u128) = (0, 1);
+    let mut negative: bool = true;
+    while r1 != 0 {
+        let quotient: u128 = r0 / r1;
+        let next_r: u128 = r0 - quotient * r1;
+        r0 = r1;
+        r1 = next_r;
+        let next_x: u128 = x0 + quotient * x1;
+        x0 = x1;
+        x1 = next_x;
+        negative = !negative;
+    }
+
+    if r0 != 1 {
+        return None;
+    }
+
+    if negative {
+        Some((modulus - x0) % modulus)
+    } else {
+        Some(x0 % modulus)
+    }
+}
+
+// Returns `(gcd, x, y)` with `a * x + b * y == gcd`, or `None` when a part does not fit
+// `i64`, which only happens for the gcd `2^63` of `i64::MIN` and zero.
+#[inline]
+pub fn ext_gcd_i64(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    // the remainders stay within `i128`, so `i64::MIN` neither overflows nor negates badly
+    let (mut r0, mut r1): (i128, i128) = (a as i128, b as i128);
+    let (mut x0, mut x1): (i128, i128) = (1, 0);
+    let (mut y0, mut y1): (i128, i128) = (0, 1);
+    while r1 != 0 {
+        let quotient: i128 = r0 / r1;
+        let next: (i128, i128, i128) = (r0 - quotient * r1, x0 - quotient * x1, y0 - quotient * y1);
+        r0 = r1;
+        x0 = x1;
+        y0 = y1;
+        r1 = next.0;
+        x1 = next.1;
+        y1 = next.2;
+    }
+
+    let (gcd, x, y) = if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    };
+
+    Some((
+        i64::try_from(gcd).ok()?,
+        i64::try_from(x).ok()?,
+        i64::try_from(y).ok()?,
+    ))
+}
+
+// Decimal rendering of `numerator / denominator` rounded half up to `sig_figs`
+// significant digits, using exact long division. The denominator must not be zero.
+pub fn sig_figs_u128(numerator: u128, denominator: u128, sig_figs: usize) -> String {
+    if numerator == 0 {
+        return String::from("0");
+    }
+
+    let sig_figs: usize = sig_figs.max(1);
+    let whole: u128 = numerator / denominator;
+    let mut remainder: u128 = numerator % denominator;
+    let mut digits: Vec<u8> = if whole == 0 {
+        Vec::new()
+    } else {
+        whole
+            .to_string()
+            .bytes()
+            .map(|digit| digit - b'0')
+            .collect()
+    };
+    let mut point: usize = digits.len();
+
+    let mut first: usize = 0;
+    while whole == 0 && digits.last().is_none_or(|digit| *digit == 0) {
+        let (digit, next) = next_digit_u128(remainder, denominator, 10);
+        digits.push(digit as u8);
+        remainder = next;
+        first = digits.len() - 1;
+    }
+    while digits.len() <= first + sig_figs {
+        let (digit, next) = next_digit_u128(remainder, denominator, 10);
+        digits.push(digit as u8);
+        remainder = next;
+    }
+
+    let round_up: bool = digits[first + sig_figs] >= 5;
+    digits.truncate(first + sig_figs);
+    if round_up {
+        let mut index: usize = digits.len();
+        loop {
+            if index == 0 {
+                digits.insert(0, 1);
+                point += 1;
+                break;
+            }
+            index -= 1;
+            if digits[index] == 9 {
+                digits[index] = 0;
+            } else {
+                digits[index] += 1;
+                break;
+            }
+        }
+    }
+
+    // a carry may have added a leading significant digit
+    let first: usize = digits.iter().position(|digit| *digit != 0).unwrap_or(0);
+    while digits.len() - first > sig_figs && digits.len() > point {
+        digits.pop();
+    }
+
+    let mut result: String = String::new();
+    if point == 0 {
+        result.push('0');
+    }
+    for index in 0..point {
+        result.push((b'0' + digits.get(index).copied().unwrap_or(0)) as char);
+    }
+    if digits.len() > point {
+        result.push('.');
+        for digit in &digits[point..] {
+            result.push((b'0' + digit) as char);
+        }
+    }
+
+    result
+}
+
+// Exact ordering of `numerator / denominator` against `value`. A zero denominator counts
+// as infinity and NaN is placed like `f64::total_cmp` does.
+#[inline]
+pub fn cmp_ratio_f64(numerator: u128, denominator: u128, value: f64) -> Ordering {
+    if value.is_nan() {
+        return if value.is_sign_negative() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+    }
+    if denominator == 0 {
+        return if value == f64::INFINITY {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        };
+    }
+    if value == f64::INFINITY {
+        return Ordering::Less;
+    }
+    if value < 0.0 {
+        return Ordering::Greater;
+    }
+
+    let bits: u64 = value.to_bits() & !(1 << 63);
+    let exponent_field: i32 = (bits >> 52) as i32;
+    let fraction: u128 = (bits & ((1 << 52) - 1)) as u128;
+    let (mantissa, exponent): (u128, i32) = if exponent_field == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | 1 << 52, exponent_field - 1075)
+    };
+
+    cmp_ratio_dyadic(numerator, denominator, mantissa, exponent)
+}
+
+// Exact `|first - second|` as a ratio, `None` when the cross products overflow.
+#[inline]
+pub fn distance_u128(
+    first_numerator: u128,
+    first_denominator: u128,
+    second_numerator: u128,
+    second_denominator: u128,
+) -> Option<(u128, u128)> {
+    let lhs: u128 = first_numerator.checked_mul(second_denominator)?;
+    let rhs: u128 = second_numerator.checked_mul(first_denominator)?;
+
+    Some((
+        lhs.max(rhs) - lhs.min(rhs),
+        first_denominator.checked_mul(second_denominator)?,
+    ))
+}
+
+// Positional expansion in `radix`, stopping after `max_digits` fractional digits or once the
+// remainder runs out.
+#[inline]
+pub fn radix_string_u128(
+    numerator: u128,
+    denominator: u128,
+    radix: u32,
+    max_digits: usize,
+) -> String {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+
+    let mut whole: u128 = numerator / denominator;
+    let mut integer: Vec<char> = Vec::new();
+    loop {
+        integer.push(std::char::from_digit((whole % radix as u128) as u32, radix).unwrap());
+        whole /= radix as u128;
+        if whole == 0 {
+            break;
+        }
+    }
+
+    let mut result: String = integer.into_iter().rev().collect();
+    let mut remainder: u128 = numerator % denominator;
+    if remainder != 0 && max_digits > 0 {
+        result.push('.');
+    }
+    for _ in 0..max_digits {
+        if remainder == 0 {
+            break;
+        }
+        let (digit, next) = next_digit_u128(remainder, denominator, radix);
+        result.push(std::char::from_digit(digit, radix).unwrap());
+        remainder = next;
+    }
+
+    result
+}
+
+// `first_numerator * second_denominator - second_numerator * first_denominator`, saturating
+// at the `i128` bounds.
+#[inline]
+pub fn cross_difference_u128(
+    first_numerator: u128,
+    first_denominator: u128,
+    second_numerator: u128,
+    second_denominator: u128,
+) -> i128 {
+    let lhs: (u128, u128) = mul_wide_u128(first_numerator, second_denominator);
+    let rhs: (u128, u128) = mul_wide_u128(second_numerator, first_denominator);
+    let (larger, smaller) = if lhs >= rhs { (lhs, rhs) } else { (rhs, lhs) };
+
+    let borrow: u128 = (larger.1 < smaller.1) as u128;
+    let high: u128 = larger.0 - smaller.0 - borrow;
+    let magnitude: u128 = larger.1.wrapping_sub(smaller.1);
+    if lhs >= rhs {
+        if high != 0 || magnitude > i128::MAX as u128 {
+            i128::MAX
+        } else {
+            magnitude as i128
+        }
+    } else if high != 0 || magnitude > i128::MAX as u128 {
+        i128::MIN
+    } else {
+        -(magnitude as i128)
+    }
+}
+
+// Simplest ratio strictly between `lower` and `upper`, which must be ordered and distinct,
+// or `None` when it overflows `u128`. A zero `upper_denominator` stands for infinity, which
+// the descent produces after an exact integer term.
+#[inline]
+pub fn simplest_between_u128(
+    lower_numerator: u128,
+    lower_denominator: u128,
+    upper_numerator: u128,
+    upper_denominator: u128,
+) -> Option<(u128, u128)> {
+    let (mut a, mut b, mut c, mut d) = (
+        lower_numerator,
+        lower_denominator,
+        upper_numerator,
+        upper_denominator,
+    );
+    let mut coefficients: Vec<u128> = Vec::new();
+    loop {
+        let whole: u128 = a / b;
+        let integer_fits: bool = d == 0
+            || whole
+                .checked_add(1)
+                .and_then(|next| next.checked_mul(d))
+                .is_some_and(|scaled| scaled < c);
+        if integer_fits {
+            coefficients.push(whole + 1);
+            break;
+        }
+
+        coefficients.push(whole);
+        a -= whole * b;
+        c -= whole * d;
+        // both remainders lie in `[0, 1]`, so the reciprocals swap ends
+        (a, b, c, d) = (d, c, b, a);
+    }
+
+    let (mut p0, mut q0, mut p1, mut q1): (u128, u128, u128, u128) = (0, 1, 1, 0);
+    for coefficient in coefficients {
+        let p2: u128 = coefficient.checked_mul(p1)?.checked_add(p0)?;
+        let q2: u128 = coefficient.checked_mul(q1)?.checked_add(q0)?;
+        p0 = p1;
+        q0 = q1;
+        p1 = p2;
+        q1 = q2;
+    }
+
+    Some((p1, q1))
+}
+
+// Quotient and remainder of `value * factor / divisor` for `value < divisor`, without ever
+// forming the full product.
+#[inline]
+pub fn mul_div_rem_u128(value: u128, factor: u128, divisor: u128) -> (u128, u128) {
+    let mut quotient: u128 = 0;
+    let mut remainder: u128 = 0;
+    for bit in (0..u128::BITS).rev() {
+        quotient <<= 1;
+        if remainder >= divisor - remainder {
+            remainder -= divisor - remainder;
+            quotient += 1;
+        } else {
+            remainder += remainder;
+        }
+        if factor >> bit & 1 == 1 {
+            if remainder >= divisor - value {
+                remainder -= divisor - value;
+                quotient += 1;
+            } else {
+                remainder += value;
+            }
+        }
+    }
+
+    (quotient, remainder)
+}
+
+// `first * second mod modulus`, falling back to the wide product beyond 64-bit operands.
+#[inline]
+fn mul_mod_u128(first: u128, second: u128, modulus: u128) -> u128 {
+    if first <= u64::MAX as u128 && second <= u64::MAX as u128 {
+        return first * second % modulus;
+    }
+
+    mul_div_rem_u128(first % modulus, second, modulus).1
+}
+
+#[inline]
+fn pow_mod_u128(base: u128, exponent: u128, modulus: u128) -> u128 {
+    let mut result: u128 = 1 % modulus;
+    let mut base: u128 = base % modulus;
+    let mut exponent: u128 = exponent;
+    while exponent != 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod_u128(result, base, modulus);
+        }
+        base = mul_mod_u128(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+const SMALL_PRIMES: [u128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+// Miller-Rabin over the first twelve primes, deterministic below 3.3 * 10^24.
+fn is_prime_u128(value: u128) -> bool {
+    if value < 2 {
+        return false;
+    }
+    for prime in SMALL_PRIMES {
+        if value.is_multiple_of(prime) {
+            return value == prime;
+        }
+    }
+
+    let shift: u32 = (value - 1).trailing_zeros();
+    let odd: u128 = (value - 1) >> shift;
+    'witness: for base in SMALL_PRIMES {
+        let mut x: u128 = pow_mod_u128(base, odd, value);
+        if x == 1 || x == value - 1 {
+            continue;
+        }
+        for _ in 1..shift {
+            x = mul_mod_u128(x, x, value);
+            if x == value - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+// Nontrivial factor of an odd composite by Brent's variant of Pollard's rho.
+fn pollard_rho_u128(value: u128) -> u128 {
+    for increment in 1..value {
+        let step = |x: u128| {
+            let square: u128 = mul_mod_u128(x, x, value);
+            if square >= value - increment {
+                square - (value - increment)
+            } else {
+                square + increment
+            }
+        };
+
+        let (mut x, mut y, mut saved): (u128, u128, u128) = (0, 2, 2);
+        let (mut product, mut divisor, mut range): (u128, u128, u128) = (1, 1, 1);
+        while divisor == 1 {
+            x = y;
+            for _ in 0..range {
+                y = step(y);
+            }
+            let mut done: u128 = 0;
+            while done < range && divisor == 1 {
+                saved = y;
+                for _ in 0..(range - done).min(128) {
+                    y = step(y);
+                    product = mul_mod_u128(product, x.abs_diff(y), value);
+                }
+                divisor = gcd_u128(product, value);
+                done += 128;
+            }
+            range *= 2;
+        }
+        // the batched product overshot, so retrace the last batch one step at a time
+        if divisor == value {
+            loop {
+                saved = step(saved);
+                divisor = gcd_u128(x.abs_diff(saved), value);
+                if divisor != 1 {
+                    break;
+                }
+            }
+        }
+        if divisor != value {
+            return divisor;
+        }
+    }
+
+    value
+}
+
+fn push_prime_factors_u128(value: u128, factors: &mut Vec<u128>) {
+    if value == 1 {
+        return;
+    }
+    if is_prime_u128(value) {
+        factors.push(value);
+        return;
+    }
+
+    let divisor: u128 = pollard_rho_u128(value);
+    push_prime_factors_u128(divisor, factors);
+    push_prime_factors_u128(value / divisor, factors);
+}
+
+// Prime factors in ascending order, repeated by multiplicity.
+fn prime_factors_u128(value: u128) -> Vec<u128> {
+    let mut factors: Vec<u128> = Vec::new();
+    let mut remaining: u128 = value;
+    let mut divisor: u128 = 2;
+    while divisor < 1000 && divisor * divisor <= remaining {
+        while remaining.is_multiple_of(divisor) {
+            factors.push(divisor);
+            remaining /= divisor;
+        }
+        divisor += if divisor == 2 { 1 } else { 2 };
+    }
+    push_prime_factors_u128(remaining, &mut factors);
+    factors.sort_unstable();
+
+    factors
+}
+
+// Multiplicative order of ten modulo `modulus`, which must be coprime to ten and above one.
+// Starts from the Carmichael function and divides out every prime it can.
+pub fn decimal_period_u128(modulus: u128) -> u128 {
+    let factors: Vec<u128> = prime_factors_u128(modulus);
+    let mut carmichael: u128 = 1;
+    let mut candidates: Vec<u128> = Vec::new();
+    for (index, prime) in factors.iter().enumerate() {
+        if index > 0 && factors[index - 1] == *prime {
+            // every repeat multiplies the prime power's order by the prime itself
+            candidates.push(*prime);
+            continue;
+        }
+
+        let exponent: u32 = factors.iter().filter(|factor| *factor == prime).count() as u32;
+        carmichael = lcm_u128(carmichael, prime.pow(exponent - 1) * (prime - 1));
+        candidates.extend(prime_factors_u128(prime - 1));
+    }
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    let mut order: u128 = carmichael;
+    for prime in candidates {
+        while order.is_multiple_of(prime) && pow_mod_u128(10, order / prime, modulus) == 1 {
+            order /= prime;
+        }
+    }
+
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::utils;
+
+    #[test]
+    fn should_find_lcm_of_all() {
+        assert_eq!(6, utils::lcm_all_u32(&[2, 3, 6]));
+        assert_eq!(12, utils::lcm_all_u32(&[4, 6]));
+        assert_eq!(12, utils::lcm_all_u8(&[4, 6]));
+        assert_eq!(12, utils::lcm_all_u16(&[4, 6]));
+        assert_eq!(12, utils::lcm_all_u64(&[4, 6]));
+        assert_eq!(12, utils::lcm_all_u128(&[4, 6]))
+    }
+
+    #[test]
+    fn should_find_lcm_of_empty() {
+        assert_eq!(1, utils::lcm_all_u32(&[]))
+    }
+
+    #[test]
+    fn should_find_lcm_without_intermediate_overflow() {
+        assert_eq!(u8::MAX, utils::lcm_all_u8(&[u8::MAX, 85, 51]))
+    }
+
+    #[test]
+    fn should_not_find_checked_gcd_of_zeros() {
+        assert_eq!(None, utils::checked_gcd_u8(0, 0));
+        assert_eq!(None, utils::checked_gcd_u128(0, 0))
+    }
+
+    #[test]
+    fn should_find_checked_gcd() {
+        assert_eq!(Some(2), utils::checked_gcd_u8(10, 18));
+        assert_eq!(Some(2), utils::checked_gcd_u16(10, 18));
+        assert_eq!(Some(2), utils::checked_gcd_u32(10, 18));
+        assert_eq!(Some(2), utils::checked_gcd_u64(10, 18));
+        assert_eq!(Some(2), utils::checked_gcd_u128(10, 18));
+        assert_eq!(Some(7), utils::checked_gcd_u32(0, 7))
+    }
+
+    #[test]
+    fn should_find_next_digit_without_overflow() {
+        assert_eq!((3, 1), utils::next_digit_u128(1, 3, 10));
+        assert_eq!((1, 0), utils::next_digit_u128(1, 2, 2));
+        assert_eq!(
+            (9, u128::MAX - 10),
+            utils::next_digit_u128(u128::MAX - 1, u128::MAX, 10)
+        )
+    }
+
+    #[test]
+    fn should_multiply_wide() {
+        assert_eq!((0, 6), utils::mul_wide_u128(2, 3));
+        assert_eq!((1, 0), utils::mul_wide_u128(1 << 64, 1 << 64));
+        assert_eq!(
+            (u128::MAX - 1, 1),
+            utils::mul_wide_u128(u128::MAX, u128::MAX)
+        )
+    }
+
+    #[test]
+    fn should_count_ulp_error_of_tiny_ratio() {
+        let bits: u64 = (1.0 / u128::MAX as f64).to_bits();
+        assert_eq!(0, utils::ulp_error(bits, 52, 1075, 1, u128::MAX));
+        assert_eq!(1, utils::ulp_error(bits - 1, 52, 1075, 1, u128::MAX))
+    }
+
+    #[test]
+    fn should_find_inverse_mod() {
+        assert_eq!(Some(5), utils::inverse_mod_u128(3, 7));
+        assert_eq!(Some(5), utils::inverse_mod_u128(10, 7));
+        assert_eq!(Some(0), utils::inverse_mod_u128(5, 1));
+        assert_eq!(None, utils::inverse_mod_u128(4, 8));
+        assert_eq!(
+            Some(u128::MAX - 1),
+            utils::inverse_mod_u128(u128::MAX - 1, u128::MAX)
+        )
+    }
+
+    #[test]
+    fn should_find_ext_gcd() {
+        let (gcd, x, y) = utils::ext_gcd_i64(240, 46).unwrap();
+        assert_eq!(2, gcd);
+        assert_eq!(gcd, 240 * x + 46 * y);
+
+        let (gcd, x, y) = utils::ext_gcd_i64(-12, 18).unwrap();
+        assert_eq!(6, gcd);
+        assert_eq!(gcd, -12 * x + 18 * y);
+
+        assert_eq!(Some((7, 0, 1)), utils::ext_gcd_i64(0, 7))
+    }
+
+    #[test]
+    fn should_find_ext_gcd_at_i64_min() {
+        let (gcd, x, y) = utils::ext_gcd_i64(i64::MIN, -1).unwrap();
+        assert_eq!(1, gcd);
+        assert_eq!(gcd as i128, i64::MIN as i128 * x as i128 - y as i128);
+
+        let (gcd, x, y) = utils::ext_gcd_i64(i64::MIN, 6).unwrap();
+        assert_eq!(2, gcd);
+        assert_eq!(gcd as i128, i64::MIN as i128 * x as i128 + 6 * y as i128);
+
+        assert_eq!(None, utils::ext_gcd_i64(i64::MIN, 0));
+        assert_eq!(None, utils::ext_gcd_i64(i64::MIN, i64::MIN))
+    }
+
+    #[test]
+    fn should_round_to_sig_figs() {
+        assert_eq!("0.3333", utils::sig_figs_u128(1, 3, 4));
+        assert_eq!("3.14", utils::sig_figs_u128(22, 7, 3));
+        assert_eq!("0.046", utils::sig_figs_u128(456, 10000, 2));
+        assert_eq!("0.10", utils::sig_figs_u128(996, 10000, 2));
+        assert_eq!("1.0", utils::sig_figs_u128(999, 1000, 2));
+        assert_eq!("10", utils::sig_figs_u128(999, 100, 2));
+        assert_eq!("1000", utils::sig_figs_u128(999, 1, 2));
+        assert_eq!("12300", utils::sig_figs_u128(12345, 1, 3));
+        assert_eq!("0.5000", utils::sig_figs_u128(1, 2, 4));
+        assert_eq!("0", utils::sig_figs_u128(0, 7, 3))
+    }
+
+    #[test]
+    fn should_cmp_ratio_with_float() {
+        assert_eq!(Ordering::Equal, utils::cmp_ratio_f64(1, 2, 0.5));
+        assert_eq!(Ordering::Greater, utils::cmp_ratio_f64(1, 3, 1.0 / 3.0));
+        assert_eq!(Ordering::Equal, utils::cmp_ratio_f64(0, 3, -0.0));
+        assert_eq!(Ordering::Greater, utils::cmp_ratio_f64(0, 3, -1.0));
+        assert_eq!(
+            Ordering::Less,
+            utils::cmp_ratio_f64(u128::MAX, 1, f64::INFINITY)
+        );
+        assert_eq!(Ordering::Less, utils::cmp_ratio_f64(1, 1, f64::NAN));
+        assert_eq!(Ordering::Greater, utils::cmp_ratio_f64(1, 0, 1e300));
+        assert_eq!(
+            Ordering::Less,
+            utils::cmp_ratio_f64(1, u128::MAX, 5e-324 * 2f64.powi(970))
+        )
+    }
+
+    #[test]
+    fn should_find_distance() {
+        assert_eq!(Some((2, 16)), utils::distance_u128(5, 8, 1, 2));
+        assert_eq!(Some((4, 32)), utils::distance_u128(5, 8, 3, 4));
+        assert_eq!(None, utils::distance_u128(u128::MAX, 2, 1, 3))
+    }
+
+    #[test]
+    fn should_render_in_radix() {
+        assert_eq!("0.1", utils::radix_string_u128(1, 2, 2, 8));
+        assert_eq!("101.01", utils::radix_string_u128(21, 4, 2, 8));
+        assert_eq!("ff.8", utils::radix_string_u128(511, 2, 16, 8));
+        assert_eq!("0.333", utils::radix_string_u128(1, 3, 10, 3));
+        assert_eq!("7", utils::radix_string_u128(7, 1, 10, 3))
+    }
+
+    #[test]
+    fn should_find_cross_difference() {
+        assert_eq!(-1, utils::cross_difference_u128(1, 2, 2, 3));
+        assert_eq!(1, utils::cross_difference_u128(2, 3, 1, 2));
+        assert_eq!(0, utils::cross_difference_u128(2, 4, 1, 2));
+        assert_eq!(i128::MAX, utils::cross_difference_u128(u128::MAX, 1, 0, 1));
+        assert_eq!(i128::MIN, utils::cross_difference_u128(1, 2, u128::MAX, 3));
+        assert_eq!(i128::MIN, utils::cross_difference_u128(0, 1, 1 << 127, 1))
+    }
+
+    #[test]
+    fn should_find_simplest_between() {
+        assert_eq!(Some((2, 5)), utils::simplest_between_u128(1, 3, 1, 2));
+        assert_eq!(Some((1, 2)), utils::simplest_between_u128(0, 1, 1, 1));
+        assert_eq!(Some((2, 1)), utils::simplest_between_u128(3, 2, 7, 2));
+        assert_eq!(Some((3, 1)), utils::simplest_between_u128(2, 1, 7, 2));
+        assert_eq!(Some((5, 2)), utils::simplest_between_u128(2, 1, 3, 1));
+        assert_eq!(Some((1, 1)), utils::simplest_between_u128(0, 1, 3, 1))
+    }
+
+    #[test]
+    fn should_not_find_simplest_between_beyond_u128() {
+        assert_eq!(
+            None,
+            utils::simplest_between_u128(1, u128::MAX, 1, u128::MAX - 1)
+        );
+        assert_eq!(
+            None,
+            utils::simplest_between_u128(u128::MAX - 1, u128::MAX, 1, 1)
+        )
+    }
+
+    #[test]
+    fn should_mul_div_rem_without_overflow() {
+        assert_eq!((199, 1), utils::mul_div_rem_u128(200, 200, 201));
+        assert_eq!((0, 0), utils::mul_div_rem_u128(0, 7, 3));
+        assert_eq!(
+            (u128::MAX - 2, 1),
+            utils::mul_div_rem_u128(u128::MAX - 1, u128::MAX - 1, u128::MAX)
+        )
+    }
+
+    #[test]
+    fn should_find_decimal_period() {
+        assert_eq!(1, utils::decimal_period_u128(3));
+        assert_eq!(6, utils::decimal_period_u128(7));
+        assert_eq!(42, utils::decimal_period_u128(49));
+        assert_eq!(9, utils::decimal_period_u128(81));
+        assert_eq!(1_000_000_006, utils::decimal_period_u128(1_000_000_007));
+        assert_eq!(
+            499_122_178_994_733_056,
+            utils::decimal_period_u128(1_000_000_007 * 998_244_353)
+        );
+        assert_eq!(
+            103_161_669_940_448_356_241_593_685,
+            utils::decimal_period_u128((1 << 89) - 1)
+        )
+    }
+}
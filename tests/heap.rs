@@ -0,0 +1,63 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use fract::{Fract, Fract32};
+
+#[test]
+fn should_pop_max_heap_in_descending_order() {
+    let mut heap: BinaryHeap<Fract32> = BinaryHeap::new();
+    heap.push(Fract32::new(1, 3));
+    heap.push(Fract32::new(3, 4));
+    heap.push(Fract32::new(1, 2));
+    heap.push(Fract32::new(2, 7));
+
+    let mut popped: Vec<Fract32> = Vec::new();
+    while let Some(value) = heap.pop() {
+        popped.push(value);
+    }
+
+    let expected: Vec<Fract32> = vec![
+        Fract32::new(3, 4),
+        Fract32::new(1, 2),
+        Fract32::new(1, 3),
+        Fract32::new(2, 7),
+    ];
+
+    assert_eq!(expected, popped)
+}
+
+#[test]
+fn should_pop_min_heap_in_ascending_order() {
+    let mut heap: BinaryHeap<Reverse<Fract32>> = BinaryHeap::new();
+    heap.push(Reverse(Fract32::new(1, 3)));
+    heap.push(Reverse(Fract32::new(3, 4)));
+    heap.push(Reverse(Fract32::new(1, 2)));
+    heap.push(Reverse(Fract32::new(2, 7)));
+
+    let mut popped: Vec<Fract32> = Vec::new();
+    while let Some(Reverse(value)) = heap.pop() {
+        popped.push(value);
+    }
+
+    let expected: Vec<Fract32> = vec![
+        Fract32::new(2, 7),
+        Fract32::new(1, 3),
+        Fract32::new(1, 2),
+        Fract32::new(3, 4),
+    ];
+
+    assert_eq!(expected, popped)
+}
+
+#[test]
+fn should_keep_equal_values_apart_in_heap() {
+    let mut heap: BinaryHeap<Fract32> = BinaryHeap::new();
+    heap.push(Fract32::new(2, 4));
+    heap.push(Fract32::new(1, 2));
+    heap.push(Fract32::new(u32::MAX - 1, u32::MAX));
+
+    assert_eq!(Some(Fract32::new(u32::MAX - 1, u32::MAX)), heap.pop());
+    assert_eq!(Some(Fract32::new(2, 4)), heap.pop());
+    assert_eq!(Some(Fract32::new(1, 2)), heap.pop());
+    assert_eq!(None, heap.pop())
+}